@@ -3,7 +3,7 @@
 
 extern crate test;
 
-use num_bigint::{BigInt, BigUint, RandBigInt};
+use num_bigint::{BigInt, BigUint, MontgomeryForm, ProductTree, RandBigInt};
 use num_traits::{FromPrimitive, Num, One, Zero};
 use std::mem::replace;
 use test::Bencher;
@@ -19,6 +19,28 @@ fn multiply_bench(b: &mut Bencher, xbits: u64, ybits: u64) {
     b.iter(|| &x * &y);
 }
 
+/// Schoolbook multiplication from scalar products, as a baseline for the
+/// Karatsuba and Toom-3 paths taken by `BigUint * BigUint`.
+fn schoolbook_multiply_bench(b: &mut Bencher, xbits: u64, ybits: u64) {
+    let mut rng = get_rng();
+    let x = rng.gen_biguint(xbits);
+    let y = rng.gen_biguint(ybits);
+
+    b.iter(|| -> BigUint {
+        y.iter_u32_digits()
+            .enumerate()
+            .map(|(i, d)| (&x * d) << (i * 32))
+            .sum()
+    });
+}
+
+fn square_bench(b: &mut Bencher, xbits: u64) {
+    let mut rng = get_rng();
+    let x = rng.gen_biguint(xbits);
+
+    b.iter(|| x.square());
+}
+
 fn divide_bench(b: &mut Bencher, xbits: u64, ybits: u64) {
     let mut rng = get_rng();
     let x = rng.gen_bigint(xbits);
@@ -97,6 +119,118 @@ fn multiply_5(b: &mut Bencher) {
     multiply_bench(b, 1 << 12, 1 << 14);
 }
 
+// 4 kB operands: the schoolbook baseline versus the automatic selection,
+// which uses Toom-3 at this size and Karatsuba for its sub-products.
+#[bench]
+fn multiply_4kb_schoolbook(b: &mut Bencher) {
+    schoolbook_multiply_bench(b, 1 << 15, 1 << 15);
+}
+
+#[bench]
+fn multiply_4kb(b: &mut Bencher) {
+    multiply_bench(b, 1 << 15, 1 << 15);
+}
+
+/// Sums 64 `bits`-bit numbers into one accumulator, with `+=` by reference if `by_ref`, or
+/// cloning each term otherwise.
+fn sum_assign_bench(b: &mut Bencher, bits: u64, by_ref: bool) {
+    let mut rng = get_rng();
+    let terms: Vec<_> = (0..64).map(|_| rng.gen_biguint(bits)).collect();
+
+    b.iter(|| {
+        let mut sum = BigUint::zero();
+        for term in &terms {
+            if by_ref {
+                sum += term;
+            } else {
+                sum += term.clone();
+            }
+        }
+        sum
+    });
+}
+
+#[bench]
+fn sum_assign_4k_clone(b: &mut Bencher) {
+    sum_assign_bench(b, 1 << 12, false);
+}
+
+#[bench]
+fn sum_assign_4k_ref(b: &mut Bencher) {
+    sum_assign_bench(b, 1 << 12, true);
+}
+
+/// An inner product of 16 pairs of `bits`-bit numbers, accumulating each product into the sum
+/// with `mul_add_assign` if `fused`, or adding a separate product otherwise.
+fn inner_product_bench(b: &mut Bencher, bits: u64, fused: bool) {
+    let mut rng = get_rng();
+    let xs: Vec<_> = (0..16).map(|_| rng.gen_biguint(bits)).collect();
+    let ys: Vec<_> = (0..16).map(|_| rng.gen_biguint(bits)).collect();
+
+    b.iter(|| {
+        let mut sum = BigUint::zero();
+        for (x, y) in xs.iter().zip(&ys) {
+            if fused {
+                sum.mul_add_assign(x, y);
+            } else {
+                sum += x * y;
+            }
+        }
+        sum
+    });
+}
+
+#[bench]
+fn inner_product_1k(b: &mut Bencher) {
+    inner_product_bench(b, 1 << 10, false);
+}
+
+#[bench]
+fn inner_product_1k_mul_add(b: &mut Bencher) {
+    inner_product_bench(b, 1 << 10, true);
+}
+
+// Squaring versus the general multiplication of a number by itself.
+#[bench]
+fn square_512(b: &mut Bencher) {
+    square_bench(b, 512);
+}
+
+#[bench]
+fn square_512_mul(b: &mut Bencher) {
+    multiply_bench(b, 512, 512);
+}
+
+#[bench]
+fn square_2048(b: &mut Bencher) {
+    square_bench(b, 2048);
+}
+
+#[bench]
+fn square_2048_mul(b: &mut Bencher) {
+    multiply_bench(b, 2048, 2048);
+}
+
+#[bench]
+fn square_8192(b: &mut Bencher) {
+    square_bench(b, 8192);
+}
+
+#[bench]
+fn square_8192_mul(b: &mut Bencher) {
+    multiply_bench(b, 8192, 8192);
+}
+
+#[bench]
+fn square_65536(b: &mut Bencher) {
+    square_bench(b, 1 << 16);
+}
+
+#[bench]
+fn square_65536_mul(b: &mut Bencher) {
+    multiply_bench(b, 1 << 16, 1 << 16);
+}
+
 #[bench]
 fn divide_0(b: &mut Bencher) {
     divide_bench(b, 1 << 8, 1 << 6);
@@ -172,6 +306,21 @@ fn fib2_10000(b: &mut Bencher) {
     b.iter(|| fib2(10000));
 }
 
+#[bench]
+fn fib_doubling_1000(b: &mut Bencher) {
+    b.iter(|| BigUint::fibonacci(1000));
+}
+
+#[bench]
+fn fib_doubling_10000(b: &mut Bencher) {
+    b.iter(|| BigUint::fibonacci(10000));
+}
+
+#[bench]
+fn fib_doubling_1000000(b: &mut Bencher) {
+    b.iter(|| BigUint::fibonacci(1_000_000));
+}
+
 #[bench]
 fn fac_to_string(b: &mut Bencher) {
     let fac = factorial(100);
@@ -210,6 +359,59 @@ fn to_str_radix_10_2(b: &mut Bencher) {
     to_str_radix_bench(b, 10, 10009);
 }
 
+/// Decimal conversion by dividing off chunks of about `√n` digits one at a time, as a
+/// baseline for the divide-and-conquer path taken by `to_str_radix(10)`.
+fn to_str_radix_10_chunked_bench(b: &mut Bencher, bits: u64) {
+    let mut rng = get_rng();
+    let x = rng.gen_biguint(bits);
+
+    let target_len = (x.iter_u64_digits().len() as f64).sqrt() as usize;
+    let mut big_base = BigUint::from(10u64.pow(19));
+    let mut big_power = 19;
+    while big_base.iter_u64_digits().len() < target_len {
+        big_base = &big_base * &big_base;
+        big_power *= 2;
+    }
+
+    let chunked = || {
+        let mut chunks = Vec::new();
+        let mut q = x.clone();
+        while q >= big_base {
+            let (div, rem) = q.divmod(&big_base);
+            chunks.push(rem);
+            q = div;
+        }
+        let mut s = q.to_str_radix(10);
+        for chunk in chunks.iter().rev() {
+            s += &format!("{:0>1$}", chunk.to_str_radix(10), big_power);
+        }
+        s
+    };
+    assert_eq!(chunked(), x.to_str_radix(10));
+    b.iter(chunked);
+}
+
+// About 10 000 and 100 000 decimal digits: the chunked baseline versus `to_str_radix`.
+#[bench]
+fn to_str_radix_10_10k_chunked(b: &mut Bencher) {
+    to_str_radix_10_chunked_bench(b, 33_220);
+}
+
+#[bench]
+fn to_str_radix_10_10k(b: &mut Bencher) {
+    to_str_radix_bench(b, 10, 33_220);
+}
+
+#[bench]
+fn to_str_radix_10_100k_chunked(b: &mut Bencher) {
+    to_str_radix_10_chunked_bench(b, 332_193);
+}
+
+#[bench]
+fn to_str_radix_10_100k(b: &mut Bencher) {
+    to_str_radix_bench(b, 10, 332_193);
+}
+
 #[bench]
 fn to_str_radix_16(b: &mut Bencher) {
     to_str_radix_bench(b, 16, 1009);
@@ -406,6 +608,18 @@ fn modpow(b: &mut Bencher) {
     b.iter(|| base.modpow(&e, &m));
 }
 
+/// The same exponentiation as `modpow`, but multiplying with the public `MontgomeryForm`.
+#[bench]
+fn modpow_montgomery_form(b: &mut Bencher) {
+    let mut rng = get_rng();
+    let base = rng.gen_biguint(2048);
+    let e = rng.gen_biguint(2048);
+    let m = BigUint::from_str_radix(RFC3526_2048BIT_MODP_GROUP, 16).unwrap();
+    let form = MontgomeryForm::new(m);
+
+    b.iter(|| form.exit(&form.pow(&form.enter(&base), &e)));
+}
+
 #[bench]
 fn modpow_even(b: &mut Bencher) {
     let mut rng = get_rng();
@@ -417,6 +631,56 @@ fn modpow_even(b: &mut Bencher) {
     b.iter(|| base.modpow(&e, &m));
 }
 
+fn batch_reduce_bench(b: &mut Bencher, count: usize, tree: bool) {
+    let mut rng = get_rng();
+    let moduli: Vec<BigUint> = (0..count).map(|_| rng.gen_biguint(64) + 1u32).collect();
+    let n = rng.gen_biguint(64 * count as u64);
+    let product_tree = ProductTree::new(&moduli);
+
+    if tree {
+        b.iter(|| product_tree.reduce(&n));
+    } else {
+        b.iter(|| moduli.iter().map(|m| &n % m).collect::<Vec<_>>());
+    }
+}
+
+#[bench]
+fn batch_reduce_1k_each(b: &mut Bencher) {
+    batch_reduce_bench(b, 1024, false);
+}
+
+#[bench]
+fn batch_reduce_1k_tree(b: &mut Bencher) {
+    batch_reduce_bench(b, 1024, true);
+}
+
+#[bench]
+#[cfg(feature = "number-theory")]
+fn prime_sieve_1m_biguint(b: &mut Bencher) {
+    let limit = BigUint::from(1_000_000u32);
+    b.iter(|| BigUint::prime_sieve(&limit));
+}
+
+#[bench]
+#[cfg(feature = "number-theory")]
+fn prime_sieve_1m_vec_bool(b: &mut Bencher) {
+    let limit = 1_000_000usize;
+    b.iter(|| {
+        let mut sieve = vec![true; limit + 1];
+        (2..=limit)
+            .filter(|&n| {
+                if sieve[n] {
+                    for m in (n * n..=limit).step_by(n) {
+                        sieve[m] = false;
+                    }
+                }
+                sieve[n]
+            })
+            .map(BigUint::from)
+            .collect::<Vec<_>>()
+    });
+}
+
 #[bench]
 fn to_u32_digits(b: &mut Bencher) {
     let mut rng = get_rng();
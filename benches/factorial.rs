@@ -40,3 +40,23 @@ fn factorial_div_u32(b: &mut Bencher) {
     let n: BigUint = (1u32..1000).fold(BigUint::one(), Mul::mul);
     b.iter(|| (1u32..1000).rev().fold(n.clone(), Div::div));
 }
+
+#[bench]
+fn factorial_product_tree_1000(b: &mut Bencher) {
+    b.iter(|| BigUint::factorial(1000));
+}
+
+#[bench]
+fn factorial_mul_u32_10000(b: &mut Bencher) {
+    b.iter(|| (1u32..=10_000).fold(BigUint::one(), Mul::mul));
+}
+
+#[bench]
+fn factorial_product_tree_10000(b: &mut Bencher) {
+    b.iter(|| BigUint::factorial(10_000));
+}
+
+#[bench]
+fn factorial_product_tree_100000(b: &mut Bencher) {
+    b.iter(|| BigUint::factorial(100_000));
+}
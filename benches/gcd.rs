@@ -5,7 +5,7 @@ extern crate test;
 
 use num_bigint::{BigUint, RandBigInt};
 use num_integer::Integer;
-use num_traits::Zero;
+use std::mem;
 use test::Bencher;
 
 mod rng;
@@ -33,44 +33,133 @@ fn euclid(x: &BigUint, y: &BigUint) -> BigUint {
     n
 }
 
+fn stein(x: &BigUint, y: &BigUint) -> BigUint {
+    // Use Stein's binary algorithm
+    if x.is_zero() {
+        return y.clone();
+    }
+    if y.is_zero() {
+        return x.clone();
+    }
+    let mut m = x.clone();
+    let mut n = y.clone();
+    let twos = |x: &BigUint| x.trailing_zeros().unwrap_or(0);
+
+    let shift = twos(&m).min(twos(&n));
+    n >>= twos(&n);
+    while !m.is_zero() {
+        m >>= twos(&m);
+        if n > m {
+            mem::swap(&mut n, &mut m)
+        }
+        m -= &n;
+    }
+    n << shift
+}
+
 #[bench]
 fn gcd_euclid_0064(b: &mut Bencher) {
     bench(b, 64, euclid);
 }
 
+#[bench]
+fn gcd_euclid_0128(b: &mut Bencher) {
+    bench(b, 128, euclid);
+}
+
 #[bench]
 fn gcd_euclid_0256(b: &mut Bencher) {
     bench(b, 256, euclid);
 }
 
+#[bench]
+fn gcd_euclid_0512(b: &mut Bencher) {
+    bench(b, 512, euclid);
+}
+
 #[bench]
 fn gcd_euclid_1024(b: &mut Bencher) {
     bench(b, 1024, euclid);
 }
 
+#[bench]
+fn gcd_euclid_2048(b: &mut Bencher) {
+    bench(b, 2048, euclid);
+}
+
 #[bench]
 fn gcd_euclid_4096(b: &mut Bencher) {
     bench(b, 4096, euclid);
 }
 
-// Integer for BigUint now uses Stein for gcd
-
 #[bench]
 fn gcd_stein_0064(b: &mut Bencher) {
-    bench(b, 64, BigUint::gcd);
+    bench(b, 64, stein);
+}
+
+#[bench]
+fn gcd_stein_0128(b: &mut Bencher) {
+    bench(b, 128, stein);
 }
 
 #[bench]
 fn gcd_stein_0256(b: &mut Bencher) {
-    bench(b, 256, BigUint::gcd);
+    bench(b, 256, stein);
+}
+
+#[bench]
+fn gcd_stein_0512(b: &mut Bencher) {
+    bench(b, 512, stein);
 }
 
 #[bench]
 fn gcd_stein_1024(b: &mut Bencher) {
-    bench(b, 1024, BigUint::gcd);
+    bench(b, 1024, stein);
+}
+
+#[bench]
+fn gcd_stein_2048(b: &mut Bencher) {
+    bench(b, 2048, stein);
 }
 
 #[bench]
 fn gcd_stein_4096(b: &mut Bencher) {
+    bench(b, 4096, stein);
+}
+
+// Integer for BigUint uses Lehmer for large gcd, and Stein below that
+
+#[bench]
+fn gcd_lehmer_0064(b: &mut Bencher) {
+    bench(b, 64, BigUint::gcd);
+}
+
+#[bench]
+fn gcd_lehmer_0128(b: &mut Bencher) {
+    bench(b, 128, BigUint::gcd);
+}
+
+#[bench]
+fn gcd_lehmer_0256(b: &mut Bencher) {
+    bench(b, 256, BigUint::gcd);
+}
+
+#[bench]
+fn gcd_lehmer_0512(b: &mut Bencher) {
+    bench(b, 512, BigUint::gcd);
+}
+
+#[bench]
+fn gcd_lehmer_1024(b: &mut Bencher) {
+    bench(b, 1024, BigUint::gcd);
+}
+
+#[bench]
+fn gcd_lehmer_2048(b: &mut Bencher) {
+    bench(b, 2048, BigUint::gcd);
+}
+
+#[bench]
+fn gcd_lehmer_4096(b: &mut Bencher) {
     bench(b, 4096, BigUint::gcd);
 }
@@ -193,6 +193,16 @@ fn quickcheck_signed_distributive(a: BigInt, b: BigInt, c: BigInt) -> bool {
     &a * (&b + &c) == &a * b + a * c
 }
 
+#[quickcheck]
+fn quickcheck_unsigned_square(a: BigUint) -> bool {
+    a.square() == &a * &a
+}
+
+#[quickcheck]
+fn quickcheck_signed_square(a: BigInt) -> bool {
+    a.square() == &a * &a
+}
+
 #[quickcheck]
 ///Tests that exactly one of a<b a>b a=b is true
 fn quickcheck_unsigned_ge_le_eq_mut_exclusive(a: BigUint, b: BigUint) -> bool {
@@ -258,6 +268,57 @@ fn quickcheck_signed_cbrt(a: BigInt) -> bool {
     (&a * &a * &a).cbrt() == a
 }
 
+#[quickcheck]
+fn quickcheck_unsigned_nth_root(a: BigUint, n: u8) -> TestResult {
+    let n = u32::from(n % 16);
+    if n == 0 {
+        return TestResult::discard();
+    }
+    let x = a.pow(n);
+    // Just below an exact power, the floor drops to the previous root.
+    let below = x.is_zero() || n == 1 || (&x - 1u32).nth_root(n) == &a - 1u32;
+    TestResult::from_bool(x.nth_root(n) == a && below)
+}
+
+#[quickcheck]
+fn quickcheck_signed_nth_root(a: BigInt, n: u8) -> TestResult {
+    // Even roots are only defined for non-negative values.
+    let n = u32::from(n % 16);
+    if n == 0 || (n % 2 == 0 && a.is_negative()) {
+        return TestResult::discard();
+    }
+    TestResult::from_bool(a.pow(n).nth_root(n) == a)
+}
+
+#[quickcheck]
+fn quickcheck_unsigned_bytes_round_trip(a: BigUint) -> bool {
+    BigUint::from_bytes_be(&a.to_bytes_be()) == a && BigUint::from_bytes_le(&a.to_bytes_le()) == a
+}
+
+#[quickcheck]
+fn quickcheck_unsigned_from_bytes_round_trip(bytes: Vec<u8>) -> bool {
+    // Leading zero bytes are not preserved, except for a single zero byte for zero itself.
+    let be = BigUint::from_bytes_be(&bytes).to_bytes_be();
+    let trimmed = match bytes.iter().position(|&b| b != 0) {
+        Some(i) => &bytes[i..],
+        None => &[0][..],
+    };
+    be == trimmed
+}
+
+#[quickcheck]
+fn quickcheck_signed_bytes_round_trip(a: BigInt) -> bool {
+    let (sign, be) = a.to_bytes_be();
+    let (_, le) = a.to_bytes_le();
+    BigInt::from_bytes_be(sign, &be) == a && BigInt::from_bytes_le(sign, &le) == a
+}
+
+#[quickcheck]
+fn quickcheck_signed_twos_complement_round_trip(a: BigInt) -> bool {
+    BigInt::from_signed_bytes_be(&a.to_signed_bytes_be()) == a
+        && BigInt::from_signed_bytes_le(&a.to_signed_bytes_le()) == a
+}
+
 #[quickcheck]
 fn quickcheck_unsigned_conversion(a: BigUint, radix: u8) -> TestResult {
     let radix = radix as u32;
@@ -358,6 +419,82 @@ fn quickcheck_modpow() {
     qc.quickcheck(test_modpow as fn(i128, u128, i128) -> TestResult);
 }
 
+#[quickcheck]
+fn quickcheck_unsigned_modpow(base: BigUint, exponent: u64, modulus: BigUint) -> TestResult {
+    if modulus.is_zero() {
+        return TestResult::discard();
+    }
+
+    let mut expected = BigUint::one() % &modulus;
+    let mut square = &base % &modulus;
+    let mut e = exponent;
+    while e != 0 {
+        if e & 1 == 1 {
+            expected = expected * &square % &modulus;
+        }
+        square = &square * &square % &modulus;
+        e >>= 1;
+    }
+
+    TestResult::from_bool(base.modpow(&BigUint::from(exponent), &modulus) == expected)
+}
+
+#[quickcheck]
+fn quickcheck_modpow_ct(base: BigUint, exponent: u128, modulus: BigUint) -> TestResult {
+    if modulus.is_zero() {
+        return TestResult::discard();
+    }
+    let exponent = BigUint::from(exponent);
+    TestResult::from_bool(base.modpow_ct(&exponent, &modulus) == base.modpow(&exponent, &modulus))
+}
+
+#[quickcheck]
+fn quickcheck_gcd(a: BigUint, b: BigUint, c: BigUint) -> bool {
+    fn euclid(a: &BigUint, b: &BigUint) -> BigUint {
+        let mut m = a.clone();
+        let mut n = b.clone();
+        while !m.is_zero() {
+            let temp = m;
+            m = n % &temp;
+            n = temp;
+        }
+        n
+    }
+
+    // A common factor makes the inputs large enough to take the Lehmer path.
+    let (a, b) = (&a * &c, &b * &c);
+    a.gcd(&b) == euclid(&a, &b) && a.gcd(&BigUint::zero()) == a && BigUint::zero().gcd(&b) == b
+}
+
+#[quickcheck]
+fn quickcheck_jacobi_multiplicative(a: BigInt, p: BigUint, q: BigUint) -> bool {
+    let (p, q) = (p | BigUint::one(), q | BigUint::one());
+    let pq = &p * &q;
+    a.jacobi_symbol(&pq) == a.jacobi_symbol(&p) * a.jacobi_symbol(&q)
+        && a.jacobi_symbol(&pq) == a.mod_floor(&BigInt::from(pq.clone())).jacobi_symbol(&pq)
+        && (pq.is_one() || BigInt::zero().jacobi_symbol(&pq) == 0)
+}
+
+#[quickcheck]
+fn quickcheck_jacobi_euler_criterion(a: BigUint) -> bool {
+    let primes = [
+        (BigUint::one() << 61u32) - 1u32,
+        (BigUint::one() << 127u32) - 1u32,
+        (BigUint::one() << 255u32) - 19u32,
+    ];
+    primes.iter().all(|p| {
+        let euler = a.modpow(&(p >> 1u32), p);
+        let expected = if euler.is_zero() {
+            0
+        } else if euler.is_one() {
+            1
+        } else {
+            -1
+        };
+        a.jacobi_symbol(p) == expected
+    })
+}
+
 #[test]
 fn quickcheck_modinv() {
     let gen = Gen::new(usize::MAX);
@@ -431,3 +568,56 @@ fn quickcheck_to_float_equals_i128_cast() {
     qc.quickcheck(to_f32_equals_i128_cast as fn(i128) -> bool);
     qc.quickcheck(to_f64_equals_i128_cast as fn(i128) -> bool);
 }
+
+#[quickcheck]
+fn quickcheck_gcd_lcm(a: BigUint, b: BigUint, c: BigUint) -> bool {
+    let (a, b) = (&a * &c, &b * &c);
+    let (gcd, lcm) = a.gcd_lcm(&b);
+    gcd == a.gcd(&b) && lcm == a.lcm(&b) && &gcd * &lcm == &a * &b && b.gcd_lcm(&a) == (gcd, lcm)
+}
+
+#[quickcheck]
+fn quickcheck_gcd_lcm_signed(a: BigInt, b: BigInt) -> bool {
+    let (gcd, lcm) = a.gcd_lcm(&b);
+    !gcd.is_negative() && !lcm.is_negative() && &gcd * &lcm == (&a * &b).abs()
+}
+
+#[quickcheck]
+fn quickcheck_modinv_biguint(a: BigUint, m: BigUint) -> TestResult {
+    if m.is_zero() {
+        return TestResult::discard();
+    }
+    let result = match a.modinv(&m) {
+        Some(x) => x < m && (&a * &x % &m) == BigUint::one() % &m && x == a.modinv_unchecked(&m),
+        None => !a.gcd(&m).is_one(),
+    };
+    TestResult::from_bool(result)
+}
+
+#[quickcheck]
+fn quickcheck_binomial_pascal(n: u16, k: u16) -> bool {
+    let n = u64::from(n % 1000) + 1;
+    let k = u64::from(k % 1000) + 1;
+    let c = BigUint::binomial(n, k);
+    c == BigUint::binomial(n - 1, k - 1) + BigUint::binomial(n - 1, k)
+        && (k > n || c == BigUint::binomial(n, n - k))
+}
+
+#[quickcheck]
+fn quickcheck_fibonacci(n: u16) -> bool {
+    let n = u64::from(n);
+    let (a, b) = BigUint::fibonacci_pair(n);
+    a == BigUint::fibonacci(n)
+        && b == BigUint::fibonacci(n + 1)
+        && &a + &b == BigUint::fibonacci(n + 2)
+}
+
+#[quickcheck]
+fn quickcheck_from_str_lax(bytes: Vec<u8>, radix: u8) -> bool {
+    let s = String::from_utf8_lossy(&bytes);
+    let radix = 2 + u32::from(radix) % 35;
+    match BigUint::from_str_lax(&s, radix) {
+        Ok(n) => Ok(n) == BigUint::from_str_radix(s.trim(), radix),
+        Err(e) => e.position() <= s.len(),
+    }
+}
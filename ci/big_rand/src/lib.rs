@@ -10,7 +10,7 @@ mod torture;
 
 mod biguint {
     use num_bigint::{BigUint, RandBigInt, RandomBits};
-    use num_traits::Zero;
+    use num_traits::{ToPrimitive, Zero};
     use rand::distributions::{Distribution, Uniform};
     use rand::thread_rng;
     use rand::{Rng, SeedableRng};
@@ -32,6 +32,83 @@ mod biguint {
         assert!(z.is_zero());
     }
 
+    #[test]
+    fn test_random_bits() {
+        let mut rng = thread_rng();
+        assert!(BigUint::random_bits(0, &mut rng).is_zero());
+        assert_eq!(BigUint::random_bits(1, &mut rng), BigUint::from(1u32));
+        for bits in (2..200).chain([1000, 1024, 1025]) {
+            let n = BigUint::random_bits(bits, &mut rng);
+            assert_eq!(n.bits(), bits);
+        }
+
+        // Both values of the low bit turn up.
+        let mut seen = [false; 2];
+        for _ in 0..100 {
+            let n = BigUint::random_bits(64, &mut rng);
+            seen[n.bit(0) as usize] = true;
+        }
+        assert_eq!(seen, [true, true]);
+    }
+
+    #[test]
+    fn test_random_prime() {
+        fn is_prime(n: u64) -> bool {
+            n >= 2 && (2..).take_while(|d| d * d <= n).all(|d| n % d != 0)
+        }
+
+        let mut rng = thread_rng();
+        for bits in 2..=24 {
+            let p = BigUint::random_prime(bits, &mut rng);
+            assert_eq!(p.bits(), bits);
+            assert!(is_prime(p.to_u64().unwrap()), "{}", p);
+        }
+        for &bits in &[63, 64, 65, 128, 256, 512] {
+            let p = BigUint::random_prime(bits, &mut rng);
+            assert_eq!(p.bits(), bits);
+            assert!(p.is_prime(), "{}", p);
+        }
+    }
+
+    #[test]
+    fn test_random_safe_prime() {
+        let mut rng = thread_rng();
+        for &bits in [3, 4, 5, 8, 9, 10, 16, 32, 64, 65, 128, 256].iter() {
+            let p = BigUint::random_safe_prime(bits, &mut rng);
+            assert_eq!(p.bits(), bits);
+            assert!(p.is_prime(), "{}", p);
+            let q: BigUint = (p - 1u32) >> 1;
+            assert!(q.is_prime());
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_random_prime_too_small() {
+        BigUint::random_prime(1, &mut thread_rng());
+    }
+
+    #[test]
+    fn test_gen_range() {
+        let mut rng = thread_rng();
+        let l = BigUint::from(403469000u32 + 2352);
+        let u = BigUint::from(403469000u32 + 3513);
+        for _ in 0..1000 {
+            let n: BigUint = rng.gen_range(l.clone()..=u.clone());
+            assert!(l <= n && n <= u);
+
+            let n: BigUint = rng.gen_range(l.clone()..u.clone());
+            assert!(l <= n && n < u);
+        }
+
+        // The upper bound of an inclusive range turns up.
+        let u = &l + 3u32;
+        assert!((0..1000).any(|_| rng.gen_range(l.clone()..=u.clone()) == u));
+
+        let one = BigUint::from(7u32);
+        assert_eq!(rng.gen_range(one.clone()..=one.clone()), one);
+    }
+
     #[test]
     fn test_rand_range() {
         let mut rng = thread_rng();
@@ -164,6 +241,88 @@ mod biguint {
         seeded_value_stability::<XorShiftRng>(EXPECTED);
     }
 
+    #[test]
+    fn test_is_prime_with_rng() {
+        let mut rng = thread_rng();
+        let m127: BigUint = (BigUint::from(1u32) << 127u32) - 1u32;
+        assert!(m127.is_prime_miller_rabin_with_rng(20, &mut rng));
+        assert!(!(&m127 * &m127).is_prime_miller_rabin_with_rng(20, &mut rng));
+
+        for _ in 0..100 {
+            let n = rng.gen_biguint(200);
+            assert_eq!(
+                n.is_prime_miller_rabin_with_rng(20, &mut rng),
+                n.is_prime_miller_rabin(20)
+            );
+        }
+    }
+
+    #[test]
+    fn test_factorize() {
+        let mut rng = thread_rng();
+        let m61 = (BigUint::from(1u32) << 61u32) - 1u32;
+        let p = BigUint::from(4_294_967_311u64);
+        let q = BigUint::from(1_000_000_007u32);
+        let cases: Vec<Vec<BigUint>> = vec![
+            vec![],
+            vec![BigUint::from(2u32)],
+            vec![BigUint::from(997u32), BigUint::from(997u32)],
+            vec![BigUint::from(1009u32), BigUint::from(1013u32)],
+            vec![q.clone(), p.clone()],
+            vec![q.clone(), q.clone(), q.clone()],
+            vec![
+                BigUint::from(2u32),
+                BigUint::from(3u32),
+                p.clone(),
+                m61.clone(),
+            ],
+            vec![p.clone(), m61.clone(), m61.clone()],
+        ];
+        for factors in cases {
+            let n: BigUint = factors.iter().product();
+            assert_eq!(n.factorize(&mut rng), factors, "{}", n);
+        }
+
+        for _ in 0..20 {
+            let n = rng.gen_biguint(60) + 1u32;
+            let factors = n.factorize(&mut rng);
+            assert_eq!(factors.iter().product::<BigUint>(), n);
+            assert!(factors.iter().all(BigUint::is_prime));
+            assert!(factors.windows(2).all(|w| w[0] <= w[1]));
+        }
+    }
+
+    #[test]
+    fn test_factor_pollard_rho() {
+        let mut rng = thread_rng();
+        let m61 = (BigUint::from(1u32) << 61u32) - 1u32;
+        for n in [
+            BigUint::zero(),
+            BigUint::from(1u32),
+            BigUint::from(2u32),
+            m61.clone(),
+        ] {
+            assert_eq!(n.factor_pollard_rho(&mut rng), None);
+        }
+
+        assert_eq!(
+            BigUint::from(3u32 * 5 * 1009).factor_pollard_rho(&mut rng),
+            Some(BigUint::from(3u32))
+        );
+        let p = BigUint::from(4_294_967_311u64);
+        for n in [&p * &m61, &p * &p, &m61 * &m61 * &m61] {
+            let d = n.factor_pollard_rho(&mut rng).unwrap();
+            assert!(d > BigUint::from(1u32) && d < n);
+            assert!((&n % &d).is_zero());
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "zero has no prime factorization")]
+    fn test_factorize_zero() {
+        BigUint::zero().factorize(&mut thread_rng());
+    }
+
     #[test]
     fn test_roots_rand() {
         fn check<T: Into<BigUint>>(x: T, n: u32) {
@@ -205,7 +364,6 @@ mod biguint {
 
 mod bigint {
     use num_bigint::{BigInt, RandBigInt, RandomBits};
-    use num_traits::Zero;
     use rand::distributions::Uniform;
     use rand::thread_rng;
     use rand::{Rng, SeedableRng};
@@ -227,6 +385,27 @@ mod bigint {
         assert!(z.is_zero());
     }
 
+    #[test]
+    fn test_gen_range() {
+        let mut rng = thread_rng();
+        fn check(l: BigInt, u: BigInt) {
+            let mut rng = thread_rng();
+            for _ in 0..1000 {
+                let n: BigInt = rng.gen_range(l.clone()..=u.clone());
+                assert!(l <= n && n <= u);
+            }
+        }
+        let l: BigInt = BigInt::from(403469000 + 2352);
+        let u: BigInt = BigInt::from(403469000 + 3513);
+        check(l.clone(), u.clone());
+        check(-l.clone(), u.clone());
+        check(-u, -l);
+
+        // The upper bound of an inclusive range turns up.
+        let (l, u) = (BigInt::from(-2), BigInt::from(1));
+        assert!((0..1000).any(|_| rng.gen_range(l.clone()..=u.clone()) == u));
+    }
+
     #[test]
     fn test_rand_range() {
         let mut rng = thread_rng();
@@ -364,6 +543,27 @@ mod bigint {
         seeded_value_stability::<XorShiftRng>(EXPECTED);
     }
 
+    #[test]
+    fn test_signed_bytes_round_trip() {
+        let mut rng = thread_rng();
+        for _ in 0..1000 {
+            let n = rng.gen_bigint(1000);
+            let be = n.to_signed_bytes_be();
+            let le = n.to_signed_bytes_le();
+            assert_eq!(BigInt::from_signed_bytes_be(&be), n);
+            assert_eq!(BigInt::from_signed_bytes_le(&le), n);
+            assert_eq!(
+                be.iter().rev().collect::<Vec<_>>(),
+                le.iter().collect::<Vec<_>>()
+            );
+
+            // The encoding is minimal: dropping the top byte must change the value.
+            if be.len() > 1 {
+                assert_ne!(BigInt::from_signed_bytes_be(&be[1..]), n);
+            }
+        }
+    }
+
     #[test]
     fn test_random_shr() {
         use rand::distributions::Standard;
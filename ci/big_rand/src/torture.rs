@@ -1,5 +1,4 @@
-use num_bigint::RandBigInt;
-use num_traits::Zero;
+use num_bigint::{BigUint, RandBigInt};
 use rand::prelude::*;
 use rand_xorshift::XorShiftRng;
 
@@ -68,3 +67,40 @@ fn test_factored_mul_torture() {
 fn test_factored_mul_torture_long() {
     test_factored_mul_torture_count(1_000);
 }
+
+/// Schoolbook multiplication built from scalar products, independent of the
+/// Karatsuba and Toom-3 paths taken by `BigUint * BigUint`.
+fn naive_mul(x: &BigUint, y: &BigUint) -> BigUint {
+    y.iter_u32_digits()
+        .enumerate()
+        .map(|(i, d)| (x * d) << (i * 32))
+        .sum()
+}
+
+fn test_naive_mul_torture_count(count: usize) {
+    // Spans long multiplication, Karatsuba and Toom-3, in balanced and
+    // unbalanced shapes.
+    let bits_max = 1 << 15;
+    let mut rng = get_rng();
+
+    for _ in 0..count {
+        let xbits = rng.gen_range(0..bits_max);
+        let ybits = rng.gen_range(0..bits_max);
+
+        let x = rng.gen_biguint(xbits);
+        let y = rng.gen_biguint(ybits);
+
+        assert_eq!(&x * &y, naive_mul(&x, &y));
+    }
+}
+
+#[test]
+fn test_naive_mul_torture() {
+    test_naive_mul_torture_count(100);
+}
+
+#[test]
+#[ignore]
+fn test_naive_mul_torture_long() {
+    test_naive_mul_torture_count(1_000);
+}
@@ -161,3 +161,346 @@ fn bad_size_hint<T: Debug + DeserializeOwned + One + PartialEq + Serialize>(
     catch_unwind(|| assert_ser_tokens(&T::one(), &tokens)).unwrap_err();
     assert_de_tokens(&T::one(), &tokens);
 }
+
+mod with_str {
+    use num_bigint::{BigInt, BigUint};
+    use num_traits::{One, Zero};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use serde_test::{assert_de_tokens, assert_de_tokens_error, assert_tokens, Configure, Token};
+
+    // A newtype that serializes its field as `#[serde(with = "module")]` would.
+    macro_rules! wrapper {
+        ($name:ident, $t:ty, $module:path) => {
+            #[derive(Debug, PartialEq)]
+            struct $name($t);
+
+            impl Serialize for $name {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    use $module as module;
+                    module::serialize(&self.0, serializer)
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $name {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    use $module as module;
+                    module::deserialize(deserializer).map($name)
+                }
+            }
+        };
+    }
+
+    wrapper!(HexUint, BigUint, num_bigint::serde::hex_str);
+    wrapper!(HexInt, BigInt, num_bigint::serde::hex_str);
+    wrapper!(DecUint, BigUint, num_bigint::serde::decimal_str);
+    wrapper!(DecInt, BigInt, num_bigint::serde::decimal_str);
+    wrapper!(ReadableUint, BigUint, num_bigint::serde::human_readable);
+    wrapper!(ReadableInt, BigInt, num_bigint::serde::human_readable);
+    wrapper!(U64Uint, BigUint, num_bigint::serde::u64_digits);
+    wrapper!(CompactUint, BigUint, num_bigint::serde::compact);
+    wrapper!(CompactInt, BigInt, num_bigint::serde::compact);
+
+    #[test]
+    fn hex_str() {
+        assert_tokens(&HexUint(BigUint::zero()), &[Token::Str("0x0")]);
+        assert_tokens(
+            &HexUint(BigUint::from(0x1a2b3cu32)),
+            &[Token::Str("0x1a2b3c")],
+        );
+        assert_tokens(&HexInt(BigInt::from(-255)), &[Token::Str("-0xff")]);
+        assert_tokens(
+            &HexUint(BigUint::one() << 100u32),
+            &[Token::Str("0x10000000000000000000000000")],
+        );
+
+        // The prefix is optional, and either case is accepted.
+        assert_de_tokens(&HexUint(BigUint::from(0xabcu32)), &[Token::Str("abc")]);
+        assert_de_tokens(&HexUint(BigUint::from(0xabcu32)), &[Token::Str("0XABC")]);
+        assert_de_tokens(&HexInt(BigInt::from(-0xabc)), &[Token::Str("-ABC")]);
+        assert_de_tokens(&HexUint(BigUint::zero()), &[Token::Str("-0x0")]);
+    }
+
+    #[test]
+    fn hex_str_errors() {
+        for s in [
+            "", "0x", "-", "0x-1", "+0x1", "0x+1", "-0x1", "0xg", "0x_1", "1 ",
+        ] {
+            assert_de_tokens_error::<HexUint>(
+                &[Token::Str(s)],
+                &format!(
+                    "invalid value: string {:?}, expected a hexadecimal integer string",
+                    s
+                ),
+            );
+        }
+        assert_de_tokens_error::<HexInt>(
+            &[Token::Str("--1")],
+            "invalid value: string \"--1\", expected a hexadecimal integer string",
+        );
+        assert_de_tokens_error::<HexInt>(
+            &[Token::U64(1)],
+            "invalid type: integer `1`, expected a hexadecimal integer string",
+        );
+    }
+
+    #[test]
+    fn decimal_str() {
+        assert_tokens(&DecUint(BigUint::zero()), &[Token::Str("0")]);
+        assert_tokens(&DecInt(BigInt::from(-255)), &[Token::Str("-255")]);
+        assert_tokens(
+            &DecUint(BigUint::one() << 100u32),
+            &[Token::Str("1267650600228229401496703205376")],
+        );
+
+        for s in ["", "-1", "0x1", "+1", "1a"] {
+            assert_de_tokens_error::<DecUint>(
+                &[Token::Str(s)],
+                &format!(
+                    "invalid value: string {:?}, expected a decimal integer string",
+                    s
+                ),
+            );
+        }
+        assert_de_tokens(&DecInt(BigInt::from(-12)), &[Token::Str("-12")]);
+    }
+
+    #[test]
+    fn human_readable() {
+        let n = (BigUint::one() << 32u32) + 5u32;
+        assert_tokens(
+            &ReadableUint(n.clone()).readable(),
+            &[Token::Str("4294967301")],
+        );
+        assert_tokens(
+            &ReadableUint(n.clone()).compact(),
+            &[
+                Token::Seq { len: Some(2) },
+                Token::U32(5),
+                Token::U32(1),
+                Token::SeqEnd,
+            ],
+        );
+        assert_tokens(
+            &ReadableInt(BigInt::from(-12)).readable(),
+            &[Token::Str("-12")],
+        );
+        assert_tokens(
+            &ReadableInt(BigInt::from(-12)).compact(),
+            &[
+                Token::Tuple { len: 2 },
+                Token::I8(-1),
+                Token::Seq { len: Some(1) },
+                Token::U32(12),
+                Token::SeqEnd,
+                Token::TupleEnd,
+            ],
+        );
+
+        // Human-readable formats still accept the default format.
+        assert_de_tokens(
+            &ReadableUint(n).readable(),
+            &[
+                Token::Seq { len: Some(2) },
+                Token::U32(5),
+                Token::U32(1),
+                Token::SeqEnd,
+            ],
+        );
+        assert_de_tokens(
+            &ReadableInt(BigInt::from(-12)).readable(),
+            &[
+                Token::Tuple { len: 2 },
+                Token::I8(-1),
+                Token::Seq { len: Some(1) },
+                Token::U32(12),
+                Token::SeqEnd,
+                Token::TupleEnd,
+            ],
+        );
+
+        assert_de_tokens_error::<serde_test::Readable<ReadableUint>>(
+            &[Token::Str("-1")],
+            "invalid value: string \"-1\", expected a decimal integer string or a sequence",
+        );
+        assert_de_tokens_error::<serde_test::Compact<ReadableUint>>(
+            &[Token::Str("1")],
+            "invalid type: string \"1\", expected a sequence of unsigned 32-bit numbers",
+        );
+    }
+
+    #[test]
+    fn u64_digits() {
+        assert_tokens(
+            &U64Uint(BigUint::zero()),
+            &[Token::Seq { len: Some(0) }, Token::SeqEnd],
+        );
+        assert_tokens(
+            &U64Uint(BigUint::from(u64::MAX)),
+            &[
+                Token::Seq { len: Some(1) },
+                Token::U64(u64::MAX),
+                Token::SeqEnd,
+            ],
+        );
+        assert_tokens(
+            &U64Uint((BigUint::one() << 128u32) + 5u32),
+            &[
+                Token::Seq { len: Some(3) },
+                Token::U64(5),
+                Token::U64(0),
+                Token::U64(1),
+                Token::SeqEnd,
+            ],
+        );
+
+        assert_de_tokens_error::<U64Uint>(
+            &[
+                Token::Seq { len: Some(2) },
+                Token::U64(1),
+                Token::U64(0),
+                Token::SeqEnd,
+            ],
+            "invalid value: integer `0`, expected a sequence of unsigned 64-bit numbers with a \
+             nonzero last element",
+        );
+        assert_de_tokens_error::<U64Uint>(
+            &[Token::Str("1")],
+            "invalid type: string \"1\", expected a sequence of unsigned 64-bit numbers with a \
+             nonzero last element",
+        );
+    }
+
+    #[test]
+    fn compact() {
+        assert_tokens(
+            &CompactUint(BigUint::zero()),
+            &[Token::Bytes(&[0, 0, 0, 0])],
+        );
+        assert_tokens(
+            &CompactUint(BigUint::one()),
+            &[Token::Bytes(&[0, 0, 0, 1, 1])],
+        );
+        assert_tokens(
+            &CompactUint(BigUint::from(0x0102_0304_0506_0708_090a_u128)),
+            &[Token::Bytes(&[0, 0, 0, 10, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10])],
+        );
+
+        let n = BigUint::factorial(100);
+        let mut bytes = vec![0, 0, 0, 66];
+        bytes.extend(n.to_bytes_be());
+        assert_eq!(bytes.len(), 70);
+        assert_tokens(&CompactUint(n), &[Token::Bytes(Box::leak(bytes.into()))]);
+
+        assert_tokens(
+            &CompactInt(BigInt::zero()),
+            &[Token::Bytes(&[0, 0, 0, 0, 0])],
+        );
+        assert_tokens(
+            &CompactInt(BigInt::one()),
+            &[Token::Bytes(&[1, 0, 0, 0, 1, 1])],
+        );
+        assert_tokens(
+            &CompactInt(BigInt::from(-0x1234)),
+            &[Token::Bytes(&[0xff, 0, 0, 0, 2, 0x12, 0x34])],
+        );
+
+        // Formats without bytes may write a sequence.
+        assert_de_tokens(
+            &CompactUint(BigUint::from(0x1234u32)),
+            &[
+                Token::Seq { len: Some(6) },
+                Token::U8(0),
+                Token::U8(0),
+                Token::U8(0),
+                Token::U8(2),
+                Token::U8(0x12),
+                Token::U8(0x34),
+                Token::SeqEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn compact_errors() {
+        // Leading zeros, and lengths that don't match
+        for bytes in [
+            &[0, 0, 0, 1, 0][..],
+            &[0, 0, 0, 2, 1],
+            &[0, 0, 0, 0, 1],
+            &[0, 0, 1],
+        ] {
+            assert_de_tokens_error::<CompactUint>(
+                &[Token::Bytes(bytes)],
+                "invalid value: byte array, expected a big-endian length and magnitude in bytes",
+            );
+        }
+
+        // Signs that don't match the magnitude, or aren't signs at all
+        for bytes in [
+            &[0, 0, 0, 0, 1, 1][..],
+            &[1, 0, 0, 0, 0],
+            &[2, 0, 0, 0, 1, 1],
+            &[],
+        ] {
+            assert_de_tokens_error::<CompactInt>(
+                &[Token::Bytes(bytes)],
+                "invalid value: byte array, expected a big-endian length and magnitude in bytes",
+            );
+        }
+    }
+
+    // The compact bytes that `upgrade_serde_v1_to_v2` makes from the default format.
+    macro_rules! upgraded {
+        ($name:ident, $t:ty) => {
+            #[derive(Debug, PartialEq)]
+            struct $name(Vec<u8>);
+
+            impl<'de> Deserialize<'de> for $name {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    num_bigint::serde::upgrade_serde_v1_to_v2::<$t, D>(deserializer).map($name)
+                }
+            }
+        };
+    }
+
+    upgraded!(UpgradedUint, BigUint);
+    upgraded!(UpgradedInt, BigInt);
+
+    #[test]
+    fn upgrade_serde_v1_to_v2() {
+        assert_de_tokens(
+            &UpgradedUint(vec![0, 0, 0, 0]),
+            &[Token::Seq { len: Some(0) }, Token::SeqEnd],
+        );
+        assert_de_tokens(
+            &UpgradedUint(vec![0, 0, 0, 5, 1, 5, 6, 7, 8]),
+            &[
+                Token::Seq { len: Some(2) },
+                Token::U32(0x0506_0708),
+                Token::U32(1),
+                Token::SeqEnd,
+            ],
+        );
+        assert_de_tokens(
+            &UpgradedInt(vec![0, 0, 0, 0, 0]),
+            &[
+                Token::Tuple { len: 2 },
+                Token::I8(0),
+                Token::Seq { len: Some(0) },
+                Token::SeqEnd,
+                Token::TupleEnd,
+            ],
+        );
+        assert_de_tokens(
+            &UpgradedInt(vec![0xff, 0, 0, 0, 1, 1]),
+            &[
+                Token::Tuple { len: 2 },
+                Token::I8(-1),
+                Token::Seq { len: Some(1) },
+                Token::U32(1),
+                Token::SeqEnd,
+                Token::TupleEnd,
+            ],
+        );
+    }
+}
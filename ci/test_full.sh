@@ -27,8 +27,13 @@ if ! check_version $MSRV ; then
   exit 1
 fi
 
-STD_FEATURES=(arbitrary quickcheck rand serde)
-NO_STD_FEATURES=(serde rand)
+STD_FEATURES=(arbitrary decimal ntt-mul number-theory quickcheck rand serde zeroize)
+NO_STD_FEATURES=(decimal ntt-mul number-theory serde rand zeroize)
+# allocator-api uses the unstable `allocator_api`
+if rustc --version | grep -q nightly; then
+  STD_FEATURES+=(allocator-api)
+  NO_STD_FEATURES+=(allocator-api)
+fi
 echo "Testing supported features: ${STD_FEATURES[*]}"
 if [ -n "${NO_STD_FEATURES[*]}" ]; then
   echo " no_std supported features: ${NO_STD_FEATURES[*]}"
@@ -37,6 +42,9 @@ fi
 # arbitrary 1.1.4 started using array::from_fn
 check_version 1.63.0 || cargo update -p arbitrary --precise 1.1.3
 
+# zeroize 1.8 started requiring rustc 1.72, and 1.9 rustc 1.85
+check_version 1.85.0 || cargo update -p zeroize --precise 1.7.0
+
 set -x
 
 # test the default with std
@@ -17,19 +17,29 @@ use self::Sign::{Minus, NoSign, Plus};
 
 use crate::big_digit::BigDigit;
 use crate::biguint::to_str_radix_reversed;
-use crate::biguint::{BigUint, IntDigits, U32Digits, U64Digits};
+use crate::biguint::{BigUint, IntDigits, SeparatedDisplay, U32Digits, U64Digits};
+use crate::ParseBigIntError;
 
 mod addition;
 mod division;
 mod multiplication;
 mod subtraction;
 
+mod allocator;
 mod arbitrary;
 mod bits;
 mod convert;
+mod der;
+mod io;
 mod power;
 mod serde;
 mod shift;
+mod zeroize;
+
+#[cfg(feature = "allocator-api")]
+pub use self::allocator::BigIntIn;
+#[cfg(feature = "zeroize")]
+pub use self::zeroize::ZeroizingBigInt;
 
 /// A `Sign` is a [`BigInt`]'s composing element.
 #[derive(PartialEq, PartialOrd, Eq, Ord, Copy, Clone, Debug, Hash)]
@@ -546,7 +556,7 @@ impl IntDigits for BigInt {
     }
     #[inline]
     fn capacity(&self) -> usize {
-        self.data.capacity()
+        IntDigits::capacity(&self.data)
     }
     #[inline]
     fn len(&self) -> usize {
@@ -591,6 +601,29 @@ impl BigInt {
         BigInt { sign, data }
     }
 
+    /// Creates a [`BigInt`] from its [`Sign`] and [`BigUint`] magnitude, the reverse of
+    /// [`BigInt::into_parts()`].
+    ///
+    /// This is the same as [`BigInt::from_biguint()`]: a zero magnitude gives zero whatever the
+    /// sign, and [`Sign::NoSign`] gives zero whatever the magnitude.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::{BigInt, BigUint, Sign};
+    ///
+    /// let n = BigInt::from_parts(Sign::Minus, BigUint::from(4321u32));
+    /// assert_eq!(n, BigInt::from(-4321));
+    /// assert_eq!(BigInt::from_parts(Sign::Minus, BigUint::ZERO).sign(), Sign::NoSign);
+    ///
+    /// let (sign, magnitude) = n.clone().into_parts();
+    /// assert_eq!(BigInt::from_parts(sign, magnitude), n);
+    /// ```
+    #[inline]
+    pub fn from_parts(sign: Sign, magnitude: BigUint) -> BigInt {
+        BigInt::from_biguint(sign, magnitude)
+    }
+
     /// Creates and initializes a [`BigInt`].
     ///
     /// The base 2<sup>32</sup> digits are ordered least significant digit first.
@@ -677,6 +710,85 @@ impl BigInt {
         BigInt::from_str_radix(s, radix).ok()
     }
 
+    /// Creates and initializes a [`BigInt`] from a string with an optional sign and radix
+    /// prefix: `0x` for hexadecimal, `0o` for octal, `0b` for binary, or none for decimal.
+    ///
+    /// Underscores are not allowed; see [`BigInt::from_str_prefixed_with_underscores`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// assert_eq!(BigInt::from_str_prefixed("-0xff"), Ok(BigInt::from(-255)));
+    /// assert_eq!(BigInt::from_str_prefixed("0b101"), Ok(BigInt::from(5)));
+    /// assert_eq!(BigInt::from_str_prefixed("-0042"), Ok(BigInt::from(-42)));
+    /// assert!(BigInt::from_str_prefixed("-0o").is_err());
+    /// ```
+    pub fn from_str_prefixed(s: &str) -> Result<BigInt, ParseBigIntError> {
+        convert::from_str_prefixed(s, false)
+    }
+
+    /// Creates and initializes a [`BigInt`] like [`BigInt::from_str_prefixed`], but also
+    /// allows underscores between the digits and after a radix prefix, as in Rust literals.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// assert_eq!(BigInt::from_str_prefixed_with_underscores("-1_000"), Ok(BigInt::from(-1000)));
+    /// assert_eq!(BigInt::from_str_prefixed_with_underscores("0o_7_7"), Ok(BigInt::from(0o77)));
+    /// ```
+    pub fn from_str_prefixed_with_underscores(s: &str) -> Result<BigInt, ParseBigIntError> {
+        convert::from_str_prefixed(s, true)
+    }
+
+    /// Creates and initializes a [`BigInt`] from an optional sign and hexadecimal digits, as for
+    /// [`BigUint::from_str_hex`]. The `0x` or `0X` prefix may follow the sign.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// assert_eq!(BigInt::from_str_hex("-0xff"), Ok(BigInt::from(-255)));
+    /// assert_eq!(BigInt::from_str_hex("7f"), Ok(BigInt::from(127)));
+    /// ```
+    pub fn from_str_hex(s: &str) -> Result<BigInt, ParseBigIntError> {
+        convert::from_str_signed(s, BigUint::from_str_hex)
+    }
+
+    /// Creates and initializes a [`BigInt`] from an optional sign and octal digits, as for
+    /// [`BigUint::from_str_octal`]. The `0o` or `0O` prefix may follow the sign.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// assert_eq!(BigInt::from_str_octal("-0o17"), Ok(BigInt::from(-15)));
+    /// assert_eq!(BigInt::from_str_octal("+755"), Ok(BigInt::from(0o755)));
+    /// ```
+    pub fn from_str_octal(s: &str) -> Result<BigInt, ParseBigIntError> {
+        convert::from_str_signed(s, BigUint::from_str_octal)
+    }
+
+    /// Creates and initializes a [`BigInt`] from an optional sign and binary digits, as for
+    /// [`BigUint::from_str_binary`]. The `0b` or `0B` prefix may follow the sign.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// assert_eq!(BigInt::from_str_binary("-0b101"), Ok(BigInt::from(-5)));
+    /// assert_eq!(BigInt::from_str_binary("0B1_1"), Ok(BigInt::from(3)));
+    /// ```
+    pub fn from_str_binary(s: &str) -> Result<BigInt, ParseBigIntError> {
+        convert::from_str_signed(s, BigUint::from_str_binary)
+    }
+
     /// Creates and initializes a [`BigInt`]. Each `u8` of the input slice is
     /// interpreted as one digit of the number
     /// and must therefore be less than `radix`.
@@ -880,6 +992,22 @@ impl BigInt {
         unsafe { String::from_utf8_unchecked(v) }
     }
 
+    /// Returns a wrapper that formats `self` in decimal, with `separator` inserted between every
+    /// group of `group` digits, counting from the least significant digit.
+    ///
+    /// Panics if `group` is zero.
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// let n = BigInt::from(-1234567);
+    /// assert_eq!(n.with_separator('_', 3).to_string(), "-1_234_567");
+    /// assert_eq!(format!("{:>12}", n.with_separator(',', 3)), "  -1,234,567");
+    /// ```
+    pub fn with_separator(&self, separator: char, group: usize) -> SeparatedDisplay<'_> {
+        SeparatedDisplay::new(&self.data, self.is_negative(), separator, group)
+    }
+
     /// Returns the integer in the requested base in big-endian digit order.
     /// The output is not given in a human readable alphabet but as a zero
     /// based `u8` number.
@@ -968,6 +1096,40 @@ impl BigInt {
         (self.sign, self.data)
     }
 
+    /// Returns the absolute value of the [`BigInt`] as a [`BigUint`], consuming it, like
+    /// [`i32::unsigned_abs`]. This is its magnitude, which doesn't need to be copied.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::{BigInt, BigUint};
+    ///
+    /// let diff = BigInt::from(3) - BigInt::from(10);
+    /// let size: BigUint = diff.unsigned_abs();
+    /// assert_eq!(size, BigUint::from(7u32));
+    /// ```
+    #[inline]
+    pub fn unsigned_abs(self) -> BigUint {
+        self.data
+    }
+
+    /// Returns the absolute value of the [`BigInt`] as a [`BigUint`], by copying its
+    /// magnitude. Use [`BigInt::magnitude()`] to borrow it instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::{BigInt, BigUint};
+    ///
+    /// let n = BigInt::from(-4321);
+    /// assert_eq!(n.unsigned_abs_ref(), BigUint::from(4321u32));
+    /// assert_eq!(n, BigInt::from(-4321));
+    /// ```
+    #[inline]
+    pub fn unsigned_abs_ref(&self) -> BigUint {
+        self.data.clone()
+    }
+
     /// Determines the fewest bits necessary to express the [`BigInt`],
     /// not including the sign.
     #[inline]
@@ -1008,6 +1170,108 @@ impl BigInt {
         Some(self / v)
     }
 
+    /// Returns `self` restricted to the interval `[min, max]`.
+    ///
+    /// This is the same as [`Ord::clamp`], but borrows its arguments and clones only the bound
+    /// or value that is returned. Panics if `min > max`.
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// let (min, max) = (BigInt::from(-10), BigInt::from(10));
+    /// assert_eq!(BigInt::from(-15).clamp_ref(&min, &max), min);
+    /// assert_eq!(BigInt::from(15).clamp_ref(&min, &max), max);
+    ///
+    /// let x = &BigInt::from(3);
+    /// assert_eq!(x.clamp_ref(&min, &max), *x);
+    /// ```
+    pub fn clamp_ref(&self, min: &Self, max: &Self) -> Self {
+        assert!(min <= max);
+        if self < min {
+            min.clone()
+        } else if self > max {
+            max.clone()
+        } else {
+            self.clone()
+        }
+    }
+
+    /// Converts `self` to a `u64`, saturating at `0` for negative values and at `u64::MAX` for
+    /// values that are too large.
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// assert_eq!(BigInt::from(42).clamp_to_u64(), 42);
+    /// assert_eq!(BigInt::from(-42).clamp_to_u64(), 0);
+    /// assert_eq!(BigInt::from(i128::MAX).clamp_to_u64(), u64::MAX);
+    /// ```
+    pub fn clamp_to_u64(&self) -> u64 {
+        match self.sign {
+            Minus => 0,
+            NoSign | Plus => self.data.clamp_to_u64(),
+        }
+    }
+
+    /// Compares `self` with a primitive value, without converting either side.
+    ///
+    /// Smaller integer types can be widened with `i128::from`.
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    /// use std::cmp::Ordering;
+    ///
+    /// let x = BigInt::from(-1000);
+    /// assert_eq!(x.cmp_i128(-1000), Ordering::Equal);
+    /// assert_eq!(x.cmp_i128(0), Ordering::Less);
+    /// assert_eq!((x << 128u32).cmp_i128(i128::MIN), Ordering::Less);
+    /// ```
+    pub fn cmp_i128(&self, v: i128) -> Ordering {
+        let v_sign = match v.cmp(&0) {
+            Ordering::Less => Minus,
+            Ordering::Equal => NoSign,
+            Ordering::Greater => Plus,
+        };
+        match self.sign.cmp(&v_sign) {
+            Equal => match self.sign {
+                NoSign => Equal,
+                Plus => self.data.cmp_u128(v.unsigned_abs()),
+                Minus => self.data.cmp_u128(v.unsigned_abs()).reverse(),
+            },
+            other => other,
+        }
+    }
+
+    /// Returns the quotient and remainder of `self / rhs`, computed with a single division.
+    ///
+    /// This is the same as [`Integer::div_rem`]: the quotient is truncated toward zero, like `/`,
+    /// and the remainder has the sign of `self`, like `%`. Panics if `rhs` is zero.
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// let a = BigInt::from(-1234);
+    /// let (q, r) = a.divmod(&BigInt::from(100));
+    /// assert_eq!(q, BigInt::from(-12));
+    /// assert_eq!(r, BigInt::from(-34));
+    /// ```
+    #[inline]
+    pub fn divmod(&self, rhs: &Self) -> (Self, Self) {
+        Integer::div_rem(self, rhs)
+    }
+
+    /// Returns `self * self`, using [`BigUint::square`] on the magnitude.
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// let a = BigInt::from(-12345);
+    /// assert_eq!(a.square(), BigInt::from(152399025));
+    /// ```
+    pub fn square(&self) -> Self {
+        BigInt::from(self.data.square())
+    }
+
     /// Returns `self ^ exponent`.
     pub fn pow(&self, exponent: u32) -> Self {
         Pow::pow(self, exponent)
@@ -1083,6 +1347,98 @@ impl BigInt {
         Some(BigInt::from_biguint(sign, mag))
     }
 
+    /// Returns the Jacobi symbol `(self/n)`, which is -1, 0 or 1. Panics if `n` is even.
+    ///
+    /// See [`BigUint::jacobi_symbol`]. For a negative `self`, this uses
+    /// `(-1/n) = (-1)^((n-1)/2)`.
+    ///
+    /// ```
+    /// use num_bigint::{BigInt, BigUint};
+    ///
+    /// let n = BigUint::from(11u32);
+    /// assert_eq!(BigInt::from(5).jacobi_symbol(&n), 1);
+    /// assert_eq!(BigInt::from(-5).jacobi_symbol(&n), -1);
+    /// ```
+    pub fn jacobi_symbol(&self, n: &BigUint) -> i8 {
+        let t = self.data.jacobi_symbol(n);
+        // -1 is a quadratic residue of a prime p exactly when p = 1 (mod 4).
+        if self.sign == Minus && n.bit(1) {
+            -t
+        } else {
+            t
+        }
+    }
+
+    /// Returns the Kronecker symbol `(self/n)`, which is -1, 0 or 1, for any integer `n`.
+    ///
+    /// This extends [`BigInt::jacobi_symbol`] to be multiplicative in any nonzero `n`, with
+    /// `(a/2)` being 0 for even `a`, 1 for `a = ±1 (mod 8)` and -1 for `a = ±3 (mod 8)`, `(a/-1)`
+    /// being -1 for negative `a` and 1 otherwise, and `(a/0)` being 1 for `a = ±1` and 0
+    /// otherwise.
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// assert_eq!(BigInt::from(5).kronecker_symbol(&BigInt::from(22)), -1);
+    /// assert_eq!(BigInt::from(3).kronecker_symbol(&BigInt::from(2)), -1);
+    /// assert_eq!(BigInt::from(-3).kronecker_symbol(&BigInt::from(-11)), 1);
+    /// assert_eq!(BigInt::from(4).kronecker_symbol(&BigInt::from(6)), 0);
+    /// ```
+    pub fn kronecker_symbol(&self, n: &BigInt) -> i8 {
+        if n.is_zero() {
+            return if self.data.is_one() { 1 } else { 0 };
+        }
+
+        let mut t = if n.sign == Minus && self.sign == Minus {
+            -1
+        } else {
+            1
+        };
+        let twos = n.data.trailing_zeros().unwrap();
+        if twos > 0 {
+            if self.data.is_even() {
+                return 0;
+            }
+            // (a/2) = -1 exactly when a = 3 or 5 (mod 8), which doesn't depend on the sign.
+            let low = self.data.iter_u32_digits().next().unwrap_or(0) & 7;
+            if twos % 2 == 1 && matches!(low, 3 | 5) {
+                t = -t;
+            }
+        }
+        t * self.jacobi_symbol(&(&n.data >> twos))
+    }
+
+    /// Returns `true` if `self` is zero -- see [`num_traits::Zero::is_zero()`].
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.sign == NoSign
+    }
+
+    /// Returns `true` if `self` is one -- see [`num_traits::One::is_one()`].
+    #[inline]
+    pub fn is_one(&self) -> bool {
+        self.sign == Plus && self.data.is_one()
+    }
+
+    /// Returns `true` if `self` is divisible by two -- see [`num_integer::Integer::is_even()`].
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// assert!(BigInt::from(-4).is_even());
+    /// assert!(BigInt::from(-3).is_odd());
+    /// ```
+    #[inline]
+    pub fn is_even(&self) -> bool {
+        self.data.is_even()
+    }
+
+    /// Returns `true` if `self` is not divisible by two -- see [`num_integer::Integer::is_odd()`].
+    #[inline]
+    pub fn is_odd(&self) -> bool {
+        self.data.is_odd()
+    }
+
     /// Returns the truncated principal square root of `self` --
     /// see [`num_integer::Roots::sqrt()`].
     pub fn sqrt(&self) -> Self {
@@ -1107,6 +1463,89 @@ impl BigInt {
         self.data.trailing_zeros()
     }
 
+    /// Returns the number of one bits, using the two's complement for negative numbers.
+    ///
+    /// A negative number has infinitely many leading ones, so only its shortest two's complement
+    /// representation is counted, which has a single leading one as the sign bit.
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// assert_eq!(BigInt::from(0b1011).count_ones(), 3);
+    /// // -5 is ...11011, so its shortest form is 1011
+    /// assert_eq!(BigInt::from(-5).count_ones(), 3);
+    /// assert_eq!(BigInt::from(-1).count_ones(), 1);
+    /// ```
+    pub fn count_ones(&self) -> u64 {
+        if self.is_negative() {
+            self.negative_counts().0
+        } else {
+            self.data.count_ones()
+        }
+    }
+
+    /// Returns the number of zero bits, using the two's complement for negative numbers.
+    ///
+    /// For non-negative numbers, this counts zeros below the most significant one bit. A negative
+    /// number has finitely many zero bits, and they are all counted.
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// assert_eq!(BigInt::from(0b1011).count_zeros(), 1);
+    /// // -5 is ...11011
+    /// assert_eq!(BigInt::from(-5).count_zeros(), 1);
+    /// assert_eq!(BigInt::from(-1).count_zeros(), 0);
+    /// ```
+    pub fn count_zeros(&self) -> u64 {
+        if self.is_negative() {
+            self.negative_counts().1
+        } else {
+            self.data.count_zeros()
+        }
+    }
+
+    /// Returns the Hamming distance between `self` and `other`, the number of bit positions where
+    /// their two's complements differ, or `None` if that is infinite.
+    ///
+    /// Non-negative numbers are compared as by [`BigUint::hamming_distance`]. Numbers of the same
+    /// sign agree on all their high bits, zeros or ones, so the distance is
+    /// finite. A negative and a non-negative number differ in all their high bits, so this
+    /// returns `None` for them.
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// assert_eq!(BigInt::from(0b1011).hamming_distance(&BigInt::from(0b0110)), Some(3));
+    /// // -5 is ...11011 and -8 is ...11000
+    /// assert_eq!(BigInt::from(-5).hamming_distance(&BigInt::from(-8)), Some(2));
+    /// assert_eq!(BigInt::from(-5).hamming_distance(&BigInt::from(5)), None);
+    /// ```
+    pub fn hamming_distance(&self, other: &BigInt) -> Option<u64> {
+        match (self.is_negative(), other.is_negative()) {
+            (false, false) => Some(self.data.hamming_distance(&other.data)),
+            // The two's complement of -x is !(x - 1), and the complements cancel in the xor.
+            (true, true) => Some((&self.data - 1u32).hamming_distance(&(&other.data - 1u32))),
+            _ => None,
+        }
+    }
+
+    /// Counts the ones and zeros in the shortest two's complement of a negative number,
+    /// without allocating.
+    fn negative_counts(&self) -> (u64, u64) {
+        // The two's complement of -x is !(x - 1), and x - 1 flips the trailing zeros of x and
+        // the one just above them.
+        let trailing_zeros = self.data.trailing_zeros().unwrap();
+        let zeros = self.data.count_ones() - 1 + trailing_zeros;
+        let mut width = self.data.bits();
+        if self.data.count_ones() == 1 {
+            // x - 1 has one bit fewer than x
+            width -= 1;
+        }
+        // ... then one more bit for the sign.
+        (width - zeros + 1, zeros)
+    }
+
     /// Returns whether the bit in position `bit` is set,
     /// using the two's complement for negative numbers
     pub fn bit(&self, bit: u64) -> bool {
@@ -0,0 +1,153 @@
+#![cfg(feature = "allocator-api")]
+#![cfg_attr(docsrs, doc(cfg(feature = "allocator-api")))]
+
+use super::{BigInt, Sign};
+use crate::BigUintIn;
+
+use alloc::alloc::{Allocator, Global};
+use core::cmp::Ordering;
+use core::fmt;
+
+/// A [`BigInt`] whose magnitude is stored by a custom [`Allocator`], as a [`BigUintIn`].
+///
+/// Like [`BigUintIn`], this is a storage type: values are moved in with
+/// [`BigIntIn::from_bigint`], and out with [`BigIntIn::into_bigint`] or [`BigIntIn::to_bigint`]
+/// to compute with them.
+///
+/// ```
+/// #![feature(allocator_api)]
+/// use num_bigint::{BigInt, BigIntIn, Sign};
+/// use std::alloc::System;
+///
+/// let n = BigInt::from(-12345);
+/// let stored = BigIntIn::from_bigint(n.clone(), System);
+/// assert_eq!(stored.sign(), Sign::Minus);
+/// assert_eq!(stored.to_string(), "-12345");
+/// assert_eq!(stored.into_bigint(), n);
+/// ```
+pub struct BigIntIn<A: Allocator = Global> {
+    sign: Sign,
+    data: BigUintIn<A>,
+}
+
+impl<A: Allocator> BigIntIn<A> {
+    /// Returns zero stored in `alloc`, without allocating.
+    #[inline]
+    pub fn new_in(alloc: A) -> Self {
+        BigIntIn {
+            sign: Sign::NoSign,
+            data: BigUintIn::new_in(alloc),
+        }
+    }
+
+    /// Moves the value of `n` into a new allocation from `alloc`.
+    pub fn from_bigint(n: BigInt, alloc: A) -> Self {
+        BigIntIn {
+            sign: n.sign,
+            data: BigUintIn::from_biguint(n.data, alloc),
+        }
+    }
+
+    /// Returns a copy of the value with its magnitude in the global allocator.
+    pub fn to_bigint(&self) -> BigInt {
+        BigInt {
+            sign: self.sign,
+            data: self.data.to_biguint(),
+        }
+    }
+
+    /// Moves the value into the global allocator, freeing its storage in this one.
+    #[inline]
+    pub fn into_bigint(self) -> BigInt {
+        self.to_bigint()
+    }
+
+    /// Returns the sign of the value.
+    #[inline]
+    pub fn sign(&self) -> Sign {
+        self.sign
+    }
+
+    /// Returns the magnitude of the value.
+    #[inline]
+    pub fn magnitude(&self) -> &BigUintIn<A> {
+        &self.data
+    }
+
+    /// Returns the allocator that stores the magnitude.
+    #[inline]
+    pub fn allocator(&self) -> &A {
+        self.data.allocator()
+    }
+
+    /// Returns `true` if the value is zero.
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.sign == Sign::NoSign
+    }
+}
+
+impl<A: Allocator + Clone> Clone for BigIntIn<A> {
+    #[inline]
+    fn clone(&self) -> Self {
+        BigIntIn {
+            sign: self.sign,
+            data: self.data.clone(),
+        }
+    }
+}
+
+impl<A: Allocator + Default> Default for BigIntIn<A> {
+    #[inline]
+    fn default() -> Self {
+        Self::new_in(A::default())
+    }
+}
+
+impl<A: Allocator> PartialEq for BigIntIn<A> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.sign == other.sign && self.data == other.data
+    }
+}
+
+impl<A: Allocator> Eq for BigIntIn<A> {}
+
+impl<A: Allocator> PartialEq<BigInt> for BigIntIn<A> {
+    #[inline]
+    fn eq(&self, other: &BigInt) -> bool {
+        self.sign == other.sign && self.data == other.data
+    }
+}
+
+impl<A: Allocator> PartialOrd for BigIntIn<A> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<A: Allocator> Ord for BigIntIn<A> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match self.sign.cmp(&other.sign) {
+            Ordering::Equal => match self.sign {
+                Sign::NoSign => Ordering::Equal,
+                Sign::Plus => self.data.cmp(&other.data),
+                Sign::Minus => other.data.cmp(&self.data),
+            },
+            other => other,
+        }
+    }
+}
+
+impl<A: Allocator> fmt::Debug for BigIntIn<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl<A: Allocator> fmt::Display for BigIntIn<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.to_bigint(), f)
+    }
+}
@@ -25,19 +25,49 @@ impl Num for BigInt {
     /// Creates and initializes a [`BigInt`].
     #[inline]
     fn from_str_radix(mut s: &str, radix: u32) -> Result<BigInt, ParseBigIntError> {
+        let mut start = 0;
         let sign = if let Some(tail) = s.strip_prefix('-') {
             if !tail.starts_with('+') {
-                s = tail
+                s = tail;
+                start = 1;
             }
             Minus
         } else {
             Plus
         };
-        let bu = BigUint::from_str_radix(s, radix)?;
+        let bu = BigUint::from_str_radix(s, radix).map_err(|e| e.shifted(start))?;
         Ok(BigInt::from_biguint(sign, bu))
     }
 }
 
+/// Parses an optional sign, then the rest as for `BigUint::from_str_prefixed`.
+pub(super) fn from_str_prefixed(s: &str, underscores: bool) -> Result<BigInt, ParseBigIntError> {
+    if underscores {
+        from_str_signed(s, BigUint::from_str_prefixed_with_underscores)
+    } else {
+        from_str_signed(s, BigUint::from_str_prefixed)
+    }
+}
+
+/// Parses an optional `-` sign, then the magnitude with `parse`.
+pub(super) fn from_str_signed(
+    mut s: &str,
+    parse: fn(&str) -> Result<BigUint, ParseBigIntError>,
+) -> Result<BigInt, ParseBigIntError> {
+    let mut start = 0;
+    let sign = if let Some(tail) = s.strip_prefix('-') {
+        if !tail.starts_with('+') {
+            s = tail;
+            start = 1;
+        }
+        Minus
+    } else {
+        Plus
+    };
+    let bu = parse(s).map_err(|e| e.shifted(start))?;
+    Ok(BigInt::from_biguint(sign, bu))
+}
+
 impl ToPrimitive for BigInt {
     #[inline]
     fn to_i64(&self) -> Option<i64> {
@@ -0,0 +1,35 @@
+use super::BigInt;
+use crate::biguint::{decode_der_integer, encode_der_integer};
+use crate::DerError;
+
+use alloc::vec::Vec;
+
+impl BigInt {
+    /// Returns the ASN.1 DER encoding of `self` as an `INTEGER`: the tag `0x02`, the length of
+    /// the contents, and the contents, which are the minimal two's-complement big-endian bytes.
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// assert_eq!(BigInt::from(128).to_der_integer(), [0x02, 0x02, 0x00, 0x80]);
+    /// assert_eq!(BigInt::from(-128).to_der_integer(), [0x02, 0x01, 0x80]);
+    /// assert_eq!(BigInt::from(-129).to_der_integer(), [0x02, 0x02, 0xff, 0x7f]);
+    /// ```
+    pub fn to_der_integer(&self) -> Vec<u8> {
+        encode_der_integer(&self.to_signed_bytes_be())
+    }
+
+    /// Decodes an ASN.1 DER `INTEGER`, which must make up all of `der`.
+    ///
+    /// Only the distinguished encoding is accepted, so the length and contents must be minimal.
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// assert_eq!(BigInt::from_der_integer(&[0x02, 0x01, 0xff]), Ok(BigInt::from(-1)));
+    /// assert!(BigInt::from_der_integer(&[0x02, 0x02, 0xff, 0xff]).is_err());
+    /// ```
+    pub fn from_der_integer(der: &[u8]) -> Result<BigInt, DerError> {
+        decode_der_integer(der).map(BigInt::from_signed_bytes_be)
+    }
+}
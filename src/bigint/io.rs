@@ -0,0 +1,52 @@
+#![cfg(feature = "std")]
+#![cfg_attr(docsrs, doc(cfg(feature = "std")))]
+
+use super::BigInt;
+
+use alloc::vec::Vec;
+use std::io::{self, Read};
+
+impl BigInt {
+    /// Writes the little-endian two's complement bytes of `self`, as by
+    /// [`BigInt::to_signed_bytes_le`], returning the number of bytes written.
+    ///
+    /// The bytes are not prefixed by their length, so a format that doesn't fix the length must
+    /// record the returned count to read the value back with [`BigInt::read_from_le_bytes`].
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// let mut buf = Vec::new();
+    /// assert_eq!(BigInt::from(-129).write_to_le_bytes(&mut buf).unwrap(), 2);
+    /// assert_eq!(buf, [0x7f, 0xff]);
+    /// ```
+    pub fn write_to_le_bytes<W: io::Write>(&self, w: &mut W) -> io::Result<usize> {
+        let bytes = self.to_signed_bytes_le();
+        w.write_all(&bytes)?;
+        Ok(bytes.len())
+    }
+
+    /// Reads an integer from exactly `byte_len` little-endian two's complement bytes, as by
+    /// [`BigInt::from_signed_bytes_le`]. The value may be sign-extended, as in a fixed-width
+    /// field, and no bytes reads as zero.
+    ///
+    /// If the reader ends first, this fails with [`io::ErrorKind::UnexpectedEof`].
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// let mut r: &[u8] = &[0x7f, 0xff, 0xff, 0xff, 0x7f];
+    /// assert_eq!(BigInt::read_from_le_bytes(&mut r, 4).unwrap(), BigInt::from(-129));
+    /// assert_eq!(BigInt::read_from_le_bytes(&mut r, 1).unwrap(), BigInt::from(127));
+    /// assert!(BigInt::read_from_le_bytes(&mut r, 1).is_err());
+    /// ```
+    pub fn read_from_le_bytes<R: io::Read>(r: &mut R, byte_len: usize) -> io::Result<BigInt> {
+        // Read through `take`, so a huge `byte_len` isn't allocated before the data arrives.
+        let mut bytes = Vec::new();
+        r.take(byte_len as u64).read_to_end(&mut bytes)?;
+        if bytes.len() < byte_len {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+        Ok(BigInt::from_signed_bytes_le(&bytes))
+    }
+}
@@ -4,7 +4,7 @@ use super::Sign::{self, Minus, Plus};
 use crate::BigUint;
 
 use num_integer::Integer;
-use num_traits::{Pow, Signed, Zero};
+use num_traits::{Pow, Signed};
 
 /// Help function for pow
 ///
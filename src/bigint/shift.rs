@@ -2,7 +2,7 @@ use super::BigInt;
 use super::Sign::NoSign;
 
 use core::ops::{Shl, ShlAssign, Shr, ShrAssign};
-use num_traits::{PrimInt, Signed, Zero};
+use num_traits::{PrimInt, Signed};
 
 macro_rules! impl_shift {
     (@ref $Shx:ident :: $shx:ident, $ShxAssign:ident :: $shx_assign:ident, $rhs:ty) => {
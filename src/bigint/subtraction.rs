@@ -2,7 +2,7 @@ use super::CheckedUnsignedAbs::{Negative, Positive};
 use super::Sign::{Minus, NoSign, Plus};
 use super::{BigInt, UnsignedAbs};
 
-use crate::{IsizePromotion, UsizePromotion};
+use crate::{BigUint, IsizePromotion, UsizePromotion};
 
 use core::cmp::Ordering::{Equal, Greater, Less};
 use core::mem;
@@ -292,6 +292,28 @@ impl Sub<BigInt> for i128 {
     }
 }
 
+impl BigInt {
+    /// Returns the absolute difference `|self - other|` as a [`BigUint`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::{BigInt, BigUint};
+    ///
+    /// let a = BigInt::from(-3);
+    /// let b = BigInt::from(10);
+    /// assert_eq!(a.abs_diff(&b), BigUint::from(13u32));
+    /// assert_eq!(b.abs_diff(&BigInt::from(4)), BigUint::from(6u32));
+    /// ```
+    pub fn abs_diff(&self, other: &BigInt) -> BigUint {
+        if (self.sign == Minus) == (other.sign == Minus) {
+            self.data.abs_diff(&other.data)
+        } else {
+            &self.data + &other.data
+        }
+    }
+}
+
 impl CheckedSub for BigInt {
     #[inline]
     fn checked_sub(&self, v: &BigInt) -> Option<BigInt> {
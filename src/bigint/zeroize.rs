@@ -0,0 +1,28 @@
+#![cfg(feature = "zeroize")]
+#![cfg_attr(docsrs, doc(cfg(feature = "zeroize")))]
+
+use super::{BigInt, Sign};
+
+use zeroize::{Zeroize, Zeroizing};
+
+/// A [`BigInt`] that is zeroized when it's dropped, for secrets like private keys.
+///
+/// This is [`Zeroizing`], which dereferences to the [`BigInt`] inside.
+///
+/// ```
+/// use num_bigint::{BigInt, ZeroizingBigInt};
+///
+/// let secret = ZeroizingBigInt::new(BigInt::from(-0xdead_beef_i64));
+/// assert_eq!(secret.bits(), 32);
+/// ```
+pub type ZeroizingBigInt = Zeroizing<BigInt>;
+
+/// Overwrites the magnitude with zeros, as the [`BigUint`][crate::BigUint] implementation does,
+/// and clears the sign, which leaves the value zero.
+impl Zeroize for BigInt {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.data.zeroize();
+        self.sign = Sign::NoSign;
+    }
+}
@@ -9,7 +9,7 @@ use crate::BigInt;
 use crate::BigUint;
 use crate::Sign::*;
 
-use crate::biguint::biguint_from_vec;
+use crate::biguint::{biguint_from_vec, probably_prime, search_prime};
 
 use num_integer::Integer;
 use num_traits::{ToPrimitive, Zero};
@@ -143,6 +143,93 @@ impl<R: Rng + ?Sized> RandBigInt for R {
     }
 }
 
+impl BigUint {
+    /// Generates a random [`BigUint`] with exactly `bits` significant bits, that is with the
+    /// most significant bit set, and the rest uniformly random. Returns zero if `bits` is zero.
+    ///
+    /// ```rust,ignore
+    /// use num_bigint::BigUint;
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// assert_eq!(BigUint::random_bits(100, &mut rng).bits(), 100);
+    /// ```
+    pub fn random_bits<R: Rng + ?Sized>(bits: u64, rng: &mut R) -> BigUint {
+        if bits == 0 {
+            return BigUint::zero();
+        }
+        let mut n = rng.gen_biguint(bits - 1);
+        n.set_bit(bits - 1, true);
+        n
+    }
+
+    /// Generates a random prime with exactly `bits` significant bits. Panics if `bits < 2`.
+    ///
+    /// This searches upwards from a random odd number with its most significant bit set,
+    /// skipping numbers with small factors, and tests the rest with 64 rounds of Miller-Rabin
+    /// using witnesses from `rng`, for a chance of at most `2^-128` that the result is composite.
+    /// The result is always odd, so this never returns 2.
+    ///
+    /// The time taken grows faster than the cube of `bits`, as the number of candidates and the
+    /// cost of testing each one both grow, and it varies widely with the luck of the draw.
+    ///
+    /// ```rust,ignore
+    /// use num_bigint::BigUint;
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let p = BigUint::random_prime(256, &mut rng);
+    /// assert_eq!(p.bits(), 256);
+    /// assert!(p.is_prime());
+    /// ```
+    pub fn random_prime<R: Rng + ?Sized>(bits: u64, rng: &mut R) -> BigUint {
+        assert!(bits >= 2, "there are no primes with fewer than 2 bits");
+        loop {
+            let mut start = BigUint::random_bits(bits, rng);
+            start.set_bit(0, true);
+            let found = search_prime(start, false, 64, |n, rounds| {
+                n.is_prime_miller_rabin_with_rng(rounds, rng)
+            });
+            if let Some(p) = found {
+                return p;
+            }
+        }
+    }
+
+    /// Generates a random safe prime with exactly `bits` significant bits, that is a prime `p`
+    /// where `(p - 1) / 2` is also prime. Panics if `bits < 3`.
+    ///
+    /// Both primes are tested as in [`BigUint::random_prime`]. The result is always greater
+    /// than 5, which is the only safe prime `p` where `(p - 1) / 2` is even.
+    ///
+    /// Safe primes are much rarer than primes, so this takes tens of times as long as
+    /// [`BigUint::random_prime`] for the same size.
+    pub fn random_safe_prime<R: Rng + ?Sized>(bits: u64, rng: &mut R) -> BigUint {
+        assert!(bits >= 3, "there are no safe primes with fewer than 3 bits");
+        loop {
+            let mut start = BigUint::random_bits(bits - 1, rng);
+            start.set_bit(0, true);
+            let found = search_prime(start, true, 64, |n, rounds| {
+                n.is_prime_miller_rabin_with_rng(rounds, rng)
+            });
+            if let Some(p) = found {
+                return p;
+            }
+        }
+    }
+
+    /// Returns `true` if `self` is probably prime, using the Miller-Rabin test with `rounds`
+    /// random witnesses drawn from `rng`.
+    ///
+    /// This behaves like [`BigUint::is_prime_miller_rabin`], with the same `4^-rounds` bound on
+    /// false positives, but the witnesses can't be predicted from the input.
+    pub fn is_prime_miller_rabin_with_rng<R: Rng + ?Sized>(
+        &self,
+        rounds: usize,
+        rng: &mut R,
+    ) -> bool {
+        probably_prime(self, rounds, |bound| rng.gen_biguint_below(bound))
+    }
+}
+
 /// The back-end implementing rand's [`UniformSampler`] for [`BigUint`].
 #[derive(Clone, Debug)]
 pub struct UniformBigUint {
@@ -1,12 +1,14 @@
 use crate::big_digit::{self, BigDigit};
+use crate::BigInt;
+use crate::ParseBigIntError;
 
 use alloc::string::String;
 use alloc::vec::Vec;
-use core::cmp;
 use core::cmp::Ordering;
 use core::default::Default;
 use core::fmt;
 use core::hash;
+use core::iter::Rev;
 use core::mem;
 use core::str;
 
@@ -18,17 +20,61 @@ mod division;
 mod multiplication;
 mod subtraction;
 
+mod allocator;
 mod arbitrary;
+mod barrett;
 mod bits;
+mod builder;
+mod continued_fraction;
 mod convert;
+mod crt;
+mod der;
+mod digits;
+mod display;
+mod factorial;
+mod fibonacci;
+mod gcd;
+mod io;
 mod iter;
+mod jacobi;
+mod leb128;
+mod modsqrt;
+mod modular;
 mod monty;
+mod ntt;
+mod number_theory;
 mod power;
+mod prime;
+mod product_tree;
+mod range;
 mod serde;
 mod shift;
-
+mod sieve;
+mod square;
+mod wrapping;
+mod zeroize;
+
+#[cfg(feature = "allocator-api")]
+pub use self::allocator::BigUintIn;
+pub use self::barrett::BarrettReducer;
+pub use self::builder::BigUintBuilder;
+pub use self::continued_fraction::ConvergentIter;
+use self::convert::digit_count;
 pub(crate) use self::convert::to_str_radix_reversed;
-pub use self::iter::{U32Digits, U64Digits};
+pub(crate) use self::der::{decode_der_integer, encode_der_integer};
+pub use self::digits::{Digit, DigitsMut};
+pub use self::display::SeparatedDisplay;
+pub use self::factorial::CatalanIter;
+pub use self::iter::{Bits, U32Digits, U64Digits};
+pub use self::modular::ModularArithmetic;
+pub use self::monty::{MontgomeryForm, MontgomeryInt};
+#[cfg(feature = "rand")]
+pub(crate) use self::prime::{probably_prime, search_prime};
+pub use self::product_tree::ProductTree;
+pub use self::range::{BigUintRange, BigUintStepRange};
+pub use self::wrapping::WrappingBigUint;
+#[cfg(feature = "zeroize")]
+pub use self::zeroize::ZeroizingBigUint;
 
 /// A big unsigned integer type.
 pub struct BigUint {
@@ -51,11 +97,38 @@ impl Clone for BigUint {
     }
 }
 
+/// A `BigUint` that fits in a `u64` hashes exactly like that `u64`, and one that fits in a `u128`
+/// but not a `u64` hashes like that `u128`, so values can be looked up by either type in the same
+/// map. Larger values hash their digits.
+///
+/// Narrower primitives like `u32` write fewer bytes to the hasher, so they don't hash like the
+/// equal `BigUint`; widen them to `u64` first.
+///
+/// ```
+/// use num_bigint::BigUint;
+/// use std::collections::hash_map::DefaultHasher;
+/// use std::hash::{Hash, Hasher};
+///
+/// fn hash<T: Hash>(x: T) -> u64 {
+///     let mut hasher = DefaultHasher::new();
+///     x.hash(&mut hasher);
+///     hasher.finish()
+/// }
+///
+/// assert_eq!(hash(BigUint::from(42u32)), hash(42u64));
+/// assert_eq!(hash(BigUint::from(u128::MAX)), hash(u128::MAX));
+/// ```
 impl hash::Hash for BigUint {
     #[inline]
     fn hash<H: hash::Hasher>(&self, state: &mut H) {
         debug_assert!(self.data.last() != Some(&0));
-        self.data.hash(state);
+        if let Some(x) = self.to_u64() {
+            state.write_u64(x);
+        } else if let Some(x) = self.to_u128() {
+            state.write_u128(x);
+        } else {
+            self.data.hash(state);
+        }
     }
 }
 
@@ -101,6 +174,14 @@ impl Default for BigUint {
     }
 }
 
+fn to_str_radix_with_prefix(u: &BigUint, radix: u32, prefix: &str) -> String {
+    let digits = to_str_radix_reversed(u, radix);
+    let mut s = String::with_capacity(prefix.len() + digits.len());
+    s.push_str(prefix);
+    s.extend(digits.iter().rev().map(|&d| char::from(d)));
+    s
+}
+
 impl fmt::Debug for BigUint {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt::Display::fmt(self, f)
@@ -219,47 +300,13 @@ impl Integer for BigUint {
     /// The result is always positive.
     #[inline]
     fn gcd(&self, other: &Self) -> Self {
-        #[inline]
-        fn twos(x: &BigUint) -> u64 {
-            x.trailing_zeros().unwrap_or(0)
-        }
-
-        // Stein's algorithm
-        if self.is_zero() {
-            return other.clone();
-        }
-        if other.is_zero() {
-            return self.clone();
-        }
-        let mut m = self.clone();
-        let mut n = other.clone();
-
-        // find common factors of 2
-        let shift = cmp::min(twos(&n), twos(&m));
-
-        // divide m and n by 2 until odd
-        // m inside loop
-        n >>= twos(&n);
-
-        while !m.is_zero() {
-            m >>= twos(&m);
-            if n > m {
-                mem::swap(&mut n, &mut m)
-            }
-            m -= &n;
-        }
-
-        n << shift
+        gcd::gcd(self, other)
     }
 
     /// Calculates the Lowest Common Multiple (LCM) of the number and `other`.
     #[inline]
     fn lcm(&self, other: &BigUint) -> BigUint {
-        if self.is_zero() && other.is_zero() {
-            Self::ZERO
-        } else {
-            self / self.gcd(other) * other
-        }
+        self.gcd_lcm(other).1
     }
 
     /// Calculates the Greatest Common Divisor (GCD) and
@@ -269,8 +316,11 @@ impl Integer for BigUint {
         let gcd = self.gcd(other);
         let lcm = if gcd.is_zero() {
             Self::ZERO
-        } else {
+        } else if self.data.len() <= other.data.len() {
+            // Dividing the shorter operand is cheaper.
             self / &gcd * other
+        } else {
+            other / &gcd * self
         };
         (gcd, lcm)
     }
@@ -360,6 +410,33 @@ where
     x
 }
 
+/// Approximates `log2(x)` in fixed point with 32 fractional bits, rounding down.
+/// The input must be non-zero.
+fn log2_q32(x: &BigUint) -> u64 {
+    let ilog2 = x.bits() - 1;
+
+    // The top 64 bits, as a fixed-point value in [1, 2) with 63 fractional bits
+    let mut y = if ilog2 >= 63 {
+        (x >> (ilog2 - 63)).to_u64().unwrap()
+    } else {
+        x.to_u64().unwrap() << (63 - ilog2)
+    };
+
+    // Each squaring reveals the next fractional bit of the logarithm.
+    let mut frac = 0;
+    for _ in 0..32 {
+        let sq = (u128::from(y) * u128::from(y)) >> 63;
+        frac <<= 1;
+        if sq >> 64 != 0 {
+            frac |= 1;
+            y = (sq >> 1) as u64;
+        } else {
+            y = sq as u64;
+        }
+    }
+    (ilog2 << 32) | frac
+}
+
 impl Roots for BigUint {
     // nth_root, sqrt and cbrt use Newton's method to compute
     // principal root of a given degree for a given integer.
@@ -559,6 +636,50 @@ impl BigUint {
         big
     }
 
+    /// Creates a [`BigUint`] from base 2<sup>32</sup> digits, the inverse of
+    /// [`Self::to_u32_digits`].
+    ///
+    /// The digits are ordered least significant digit first, and any zeros in the most
+    /// significant positions are stripped.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let x = BigUint::from_u32_digits(&[830850304, 26, 0]);
+    /// assert_eq!(x, BigUint::from(112500000000u64));
+    /// assert_eq!(x.to_u32_digits(), vec![830850304, 26]);
+    /// ```
+    #[inline]
+    pub fn from_u32_digits(digits: &[u32]) -> BigUint {
+        Self::from_slice(digits)
+    }
+
+    /// Creates a [`BigUint`] from base 2<sup>64</sup> digits, the inverse of
+    /// [`Self::to_u64_digits`].
+    ///
+    /// The digits are ordered least significant digit first, and any zeros in the most
+    /// significant positions are stripped.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let x = BigUint::from_u64_digits(&[0, 1, 0]);
+    /// assert_eq!(x, BigUint::from(1u128 << 64));
+    /// assert_eq!(x.to_u64_digits(), vec![0, 1]);
+    /// ```
+    pub fn from_u64_digits(digits: &[u64]) -> BigUint {
+        let mut big = Self::ZERO;
+
+        cfg_digit_expr!(
+            big.data
+                .extend(digits.iter().flat_map(|&d| [d as u32, (d >> 32) as u32])),
+            big.data.extend_from_slice(digits)
+        );
+
+        big.normalize();
+        big
+    }
+
     /// Assign a value to a [`BigUint`].
     ///
     /// The base 2<sup>32</sup> digits are ordered least significant digit first.
@@ -574,6 +695,70 @@ impl BigUint {
         self.normalize();
     }
 
+    /// Creates a zero [`BigUint`] with room for a value of at least `bits` bits without
+    /// reallocating.
+    ///
+    /// Capacities are counted in bits, since the size of the internal digits depends on the
+    /// target. Arithmetic may still reallocate, and it releases excess capacity when a result is
+    /// much shorter, just like other values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let n = BigUint::with_capacity(1000);
+    /// assert_eq!(n, BigUint::ZERO);
+    /// assert!(n.capacity() >= 1000);
+    /// ```
+    #[inline]
+    pub fn with_capacity(bits: u64) -> BigUint {
+        BigUint {
+            data: Vec::with_capacity(bits_to_digits(bits)),
+        }
+    }
+
+    /// Returns the number of bits that the [`BigUint`] can hold without reallocating.
+    #[inline]
+    pub fn capacity(&self) -> u64 {
+        self.data.capacity() as u64 * u64::from(big_digit::BITS)
+    }
+
+    /// Reserves room for at least `additional_bits` more bits than the [`BigUint`] holds now,
+    /// as by [`Vec::reserve`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let mut n = BigUint::from(u64::MAX);
+    /// n.reserve(200);
+    /// assert!(n.capacity() >= 264);
+    /// ```
+    #[inline]
+    pub fn reserve(&mut self, additional_bits: u64) {
+        self.data.reserve(bits_to_digits(additional_bits));
+    }
+
+    /// Shrinks the capacity of the [`BigUint`] as much as possible, as by
+    /// [`Vec::shrink_to_fit`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let mut n = BigUint::with_capacity(1000);
+    /// n += 1u32;
+    /// n.shrink_to_fit();
+    /// assert!(n.capacity() < 1000);
+    /// ```
+    #[inline]
+    pub fn shrink_to_fit(&mut self) {
+        self.data.shrink_to_fit();
+    }
+
     /// Creates and initializes a [`BigUint`].
     ///
     /// The bytes are in big-endian byte order.
@@ -637,9 +822,124 @@ impl BigUint {
         BigUint::from_str_radix(s, radix).ok()
     }
 
+    /// Creates and initializes a [`BigUint`] from a string with an optional radix prefix:
+    /// `0x` for hexadecimal, `0o` for octal, `0b` for binary, or none for decimal. The prefix
+    /// letter may be upper or lower case, and may follow a `+` sign.
+    ///
+    /// Underscores are not allowed; see [`BigUint::from_str_prefixed_with_underscores`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::from_str_prefixed("0xff"), Ok(BigUint::from(255u32)));
+    /// assert_eq!(BigUint::from_str_prefixed("0O17"), Ok(BigUint::from(15u32)));
+    /// assert_eq!(BigUint::from_str_prefixed("+0b101"), Ok(BigUint::from(5u32)));
+    /// assert_eq!(BigUint::from_str_prefixed("0042"), Ok(BigUint::from(42u32)));
+    /// assert!(BigUint::from_str_prefixed("0x").is_err());
+    /// assert!(BigUint::from_str_prefixed("1_000").is_err());
+    /// ```
+    pub fn from_str_prefixed(s: &str) -> Result<BigUint, ParseBigIntError> {
+        convert::from_str_prefixed(s, false)
+    }
+
+    /// Creates and initializes a [`BigUint`] like [`BigUint::from_str_prefixed`], but also
+    /// allows underscores between the digits and after a radix prefix, as in Rust literals.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::from_str_prefixed_with_underscores("1_000"), Ok(BigUint::from(1000u32)));
+    /// assert_eq!(BigUint::from_str_prefixed_with_underscores("0x_ff_ff"), Ok(BigUint::from(0xffffu32)));
+    /// assert!(BigUint::from_str_prefixed_with_underscores("_1").is_err());
+    /// assert!(BigUint::from_str_prefixed_with_underscores("0b__").is_err());
+    /// ```
+    pub fn from_str_prefixed_with_underscores(s: &str) -> Result<BigUint, ParseBigIntError> {
+        convert::from_str_prefixed(s, true)
+    }
+
+    /// Creates and initializes a [`BigUint`] from hexadecimal digits, with an optional `0x` or
+    /// `0X` prefix that may follow a `+` sign. Otherwise the digits are parsed as for
+    /// `from_str_radix(s, 16)`, so underscores are allowed between them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::from_str_hex("0xff"), Ok(BigUint::from(255u32)));
+    /// assert_eq!(BigUint::from_str_hex("DEAD_beef"), Ok(BigUint::from(0xdeadbeefu32)));
+    /// assert!(BigUint::from_str_hex("0x").is_err());
+    /// ```
+    pub fn from_str_hex(s: &str) -> Result<BigUint, ParseBigIntError> {
+        convert::from_str_radix_prefix(s, 16, b'x')
+    }
+
+    /// Creates and initializes a [`BigUint`] from octal digits, with an optional `0o` or `0O`
+    /// prefix that may follow a `+` sign. Otherwise the digits are parsed as for
+    /// `from_str_radix(s, 8)`, so underscores are allowed between them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::from_str_octal("0o755"), Ok(BigUint::from(0o755u32)));
+    /// assert_eq!(BigUint::from_str_octal("17"), Ok(BigUint::from(15u32)));
+    /// assert!(BigUint::from_str_octal("0o8").is_err());
+    /// ```
+    pub fn from_str_octal(s: &str) -> Result<BigUint, ParseBigIntError> {
+        convert::from_str_radix_prefix(s, 8, b'o')
+    }
+
+    /// Creates and initializes a [`BigUint`] from binary digits, with an optional `0b` or `0B`
+    /// prefix that may follow a `+` sign. Otherwise the digits are parsed as for
+    /// `from_str_radix(s, 2)`, so underscores are allowed between them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::from_str_binary("0b1010"), Ok(BigUint::from(10u32)));
+    /// assert_eq!(BigUint::from_str_binary("1111_0000"), Ok(BigUint::from(0xf0u32)));
+    /// assert!(BigUint::from_str_binary("0b2").is_err());
+    /// ```
+    pub fn from_str_binary(s: &str) -> Result<BigUint, ParseBigIntError> {
+        convert::from_str_radix_prefix(s, 2, b'b')
+    }
+
+    /// Creates and initializes a [`BigUint`] from user input in the given radix, ignoring
+    /// whitespace around the number.
+    ///
+    /// Otherwise the number is parsed as for `from_str_radix`: it may have a `+` sign, leading
+    /// zeros are insignificant in every radix rather than marking octal, and underscores are
+    /// allowed as separators after the first digit, as in `1_000_000`. Error positions are
+    /// byte offsets into `s` itself, including the whitespace.
+    ///
+    /// `radix` must be in the range `2...36`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::from_str_lax("  1_000_000\n", 10), Ok(BigUint::from(1_000_000u32)));
+    /// assert_eq!(BigUint::from_str_lax("\t+0017 ", 10), Ok(BigUint::from(17u32)));
+    /// assert_eq!(BigUint::from_str_lax(" ff_ff ", 16), Ok(BigUint::from(0xffffu32)));
+    /// assert!(BigUint::from_str_lax("1 000", 10).is_err());
+    /// assert!(BigUint::from_str_lax("   ", 10).is_err());
+    /// ```
+    pub fn from_str_lax(s: &str, radix: u32) -> Result<BigUint, ParseBigIntError> {
+        convert::from_str_lax(s, radix)
+    }
+
     /// Creates and initializes a [`BigUint`]. Each `u8` of the input slice is
     /// interpreted as one digit of the number
-    /// and must therefore be less than `radix`.
+    /// and must therefore be less than `radix`, or else this returns `None`.
     ///
     /// The bytes are in big-endian byte order.
     /// `radix` must be in the range `2...256`.
@@ -652,6 +952,7 @@ impl BigUint {
     /// let inbase190 = &[15, 33, 125, 12, 14];
     /// let a = BigUint::from_radix_be(inbase190, 190).unwrap();
     /// assert_eq!(a.to_radix_be(190), inbase190);
+    /// assert_eq!(BigUint::from_radix_be(&[190, 14], 190), None);
     /// ```
     pub fn from_radix_be(buf: &[u8], radix: u32) -> Option<BigUint> {
         convert::from_radix_be(buf, radix)
@@ -659,7 +960,7 @@ impl BigUint {
 
     /// Creates and initializes a [`BigUint`]. Each `u8` of the input slice is
     /// interpreted as one digit of the number
-    /// and must therefore be less than `radix`.
+    /// and must therefore be less than `radix`, or else this returns `None`.
     ///
     /// The bytes are in little-endian byte order.
     /// `radix` must be in the range `2...256`.
@@ -670,8 +971,9 @@ impl BigUint {
     /// use num_bigint::{BigUint};
     ///
     /// let inbase190 = &[14, 12, 125, 33, 15];
-    /// let a = BigUint::from_radix_be(inbase190, 190).unwrap();
-    /// assert_eq!(a.to_radix_be(190), inbase190);
+    /// let a = BigUint::from_radix_le(inbase190, 190).unwrap();
+    /// assert_eq!(a.to_radix_le(190), inbase190);
+    /// assert_eq!(BigUint::from_radix_le(&[14, 190], 190), None);
     /// ```
     pub fn from_radix_le(buf: &[u8], radix: u32) -> Option<BigUint> {
         convert::from_radix_le(buf, radix)
@@ -787,6 +1089,40 @@ impl BigUint {
         U64Digits::new(self.data.as_slice())
     }
 
+    /// Returns an iterator of the bits of the [`BigUint`] ordered least significant bit first.
+    /// It stops after the most significant set bit, so it yields [`BigUint::bits`] items in
+    /// all, and none for zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let bits: Vec<bool> = BigUint::from(6u32).iter_bits().collect();
+    /// assert_eq!(bits, vec![false, true, true]);
+    /// assert_eq!(BigUint::from(0u32).iter_bits().next(), None);
+    /// ```
+    #[inline]
+    pub fn iter_bits(&self) -> Bits<'_> {
+        Bits::new(self.data.as_slice(), self.bits())
+    }
+
+    /// Returns an iterator of the bits of the [`BigUint`] ordered most significant bit first,
+    /// starting from the most significant set bit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let bits: Vec<bool> = BigUint::from(6u32).iter_bits_be().collect();
+    /// assert_eq!(bits, vec![true, true, false]);
+    /// ```
+    #[inline]
+    pub fn iter_bits_be(&self) -> Rev<Bits<'_>> {
+        self.iter_bits().rev()
+    }
+
     /// Returns the integer formatted as a string in the given radix.
     /// `radix` must be in the range `2...36`.
     ///
@@ -805,96 +1141,792 @@ impl BigUint {
         unsafe { String::from_utf8_unchecked(v) }
     }
 
-    /// Returns the integer in the requested base in big-endian digit order.
-    /// The output is not given in a human readable alphabet but as a zero
-    /// based `u8` number.
-    /// `radix` must be in the range `2...256`.
+    /// Returns the integer formatted as a string in the given radix, with uppercase letters if
+    /// `uppercase` is set. If `separator` is `Some((c, n))`, the character `c` is inserted
+    /// between every group of `n` digits, counting from the least significant digit.
+    /// `radix` must be in the range `2...36`, and `n` must not be zero.
     ///
     /// # Examples
     ///
     /// ```
     /// use num_bigint::BigUint;
     ///
-    /// assert_eq!(BigUint::from(0xFFFFu64).to_radix_be(159),
-    ///            vec![2, 94, 27]);
-    /// // 0xFFFF = 65535 = 2*(159^2) + 94*159 + 27
+    /// let i = BigUint::parse_bytes(b"1234567890abcdef", 16).unwrap();
+    /// assert_eq!(i.to_str_radix_options(16, true, None), "1234567890ABCDEF");
+    /// assert_eq!(i.to_str_radix_options(16, false, Some(('_', 8))), "12345678_90abcdef");
+    /// assert_eq!(i.to_str_radix_options(10, false, Some((',', 3))), "1,311,768,467,294,899,695");
     /// ```
-    #[inline]
-    pub fn to_radix_be(&self, radix: u32) -> Vec<u8> {
-        let mut v = convert::to_radix_le(self, radix);
-        v.reverse();
-        v
+    pub fn to_str_radix_options(
+        &self,
+        radix: u32,
+        uppercase: bool,
+        separator: Option<(char, usize)>,
+    ) -> String {
+        if let Some((_, n)) = separator {
+            assert!(n > 0, "The separator group size must not be zero");
+        }
+        let digits = to_str_radix_reversed(self, radix);
+        let separators = separator.map_or(0, |(c, n)| (digits.len() - 1) / n * c.len_utf8());
+        let mut s = String::with_capacity(digits.len() + separators);
+        for (i, &d) in digits.iter().enumerate().rev() {
+            s.push(char::from(if uppercase {
+                d.to_ascii_uppercase()
+            } else {
+                d
+            }));
+            match separator {
+                Some((c, n)) if i > 0 && i % n == 0 => s.push(c),
+                _ => {}
+            }
+        }
+        s
     }
 
-    /// Returns the integer in the requested base in little-endian digit order.
-    /// The output is not given in a human readable alphabet but as a zero
-    /// based u8 number.
-    /// `radix` must be in the range `2...256`.
+    /// Returns the integer formatted in lowercase hexadecimal, without a prefix.
     ///
-    /// # Examples
+    /// This is the same as `self.to_str_radix(16)`.
     ///
     /// ```
     /// use num_bigint::BigUint;
     ///
-    /// assert_eq!(BigUint::from(0xFFFFu64).to_radix_le(159),
-    ///            vec![27, 94, 2]);
-    /// // 0xFFFF = 65535 = 27 + 94*159 + 2*(159^2)
+    /// assert_eq!(BigUint::from(0xbeefu32).to_hex_str(), "beef");
     /// ```
     #[inline]
-    pub fn to_radix_le(&self, radix: u32) -> Vec<u8> {
-        convert::to_radix_le(self, radix)
+    pub fn to_hex_str(&self) -> String {
+        self.to_str_radix(16)
     }
 
-    /// Determines the fewest bits necessary to express the [`BigUint`].
+    /// Returns the integer formatted in uppercase hexadecimal, without a prefix.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::from(0xbeefu32).to_hex_str_upper(), "BEEF");
+    /// ```
     #[inline]
-    pub fn bits(&self) -> u64 {
-        if self.is_zero() {
-            return 0;
-        }
-        let zeros: u64 = self.data.last().unwrap().leading_zeros().into();
-        self.data.len() as u64 * u64::from(big_digit::BITS) - zeros
+    pub fn to_hex_str_upper(&self) -> String {
+        self.to_str_radix_options(16, true, None)
     }
 
-    /// Strips off trailing zero bigdigits - comparisons require the last element in the vector to
-    /// be nonzero.
+    /// Returns the integer formatted in octal, without a prefix.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::from(0o755u32).to_octal_str(), "755");
+    /// ```
     #[inline]
-    fn normalize(&mut self) {
-        if let Some(&0) = self.data.last() {
-            let len = self.data.iter().rposition(|&d| d != 0).map_or(0, |i| i + 1);
-            self.data.truncate(len);
-        }
-        if self.data.len() < self.data.capacity() / 4 {
-            self.data.shrink_to_fit();
-        }
+    pub fn to_octal_str(&self) -> String {
+        self.to_str_radix(8)
     }
 
-    /// Returns a normalized [`BigUint`].
+    /// Returns the integer formatted in binary, without a prefix.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::from(10u32).to_binary_str(), "1010");
+    /// ```
     #[inline]
-    fn normalized(mut self) -> BigUint {
-        self.normalize();
-        self
+    pub fn to_binary_str(&self) -> String {
+        self.to_str_radix(2)
     }
 
-    /// Returns `self ^ exponent`.
-    pub fn pow(&self, exponent: u32) -> Self {
-        Pow::pow(self, exponent)
+    /// Returns the integer formatted in lowercase hexadecimal, with a `0x` prefix.
+    ///
+    /// This is the same as `format!("{:#x}", self)`, and can be parsed again by
+    /// [`BigUint::from_str_hex`] or [`BigUint::from_str_prefixed`].
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::from(0xbeefu32).to_hex_str_prefixed(), "0xbeef");
+    /// ```
+    pub fn to_hex_str_prefixed(&self) -> String {
+        to_str_radix_with_prefix(self, 16, "0x")
     }
 
-    /// Returns `(self ^ exponent) % modulus`.
+    /// Returns the integer formatted in octal, with a `0o` prefix.
     ///
-    /// Panics if the modulus is zero.
-    pub fn modpow(&self, exponent: &Self, modulus: &Self) -> Self {
-        power::modpow(self, exponent, modulus)
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::from(0o755u32).to_octal_str_prefixed(), "0o755");
+    /// ```
+    pub fn to_octal_str_prefixed(&self) -> String {
+        to_str_radix_with_prefix(self, 8, "0o")
     }
 
-    /// Returns the modular multiplicative inverse if it exists, otherwise `None`.
-    ///
-    /// This solves for `x` in the interval `[0, modulus)` such that `self * x ≡ 1 (mod modulus)`.
-    /// The solution exists if and only if `gcd(self, modulus) == 1`.
+    /// Returns the integer formatted in binary, with a `0b` prefix.
     ///
     /// ```
     /// use num_bigint::BigUint;
-    /// use num_traits::{One, Zero};
+    ///
+    /// assert_eq!(BigUint::from(10u32).to_binary_str_prefixed(), "0b1010");
+    /// ```
+    pub fn to_binary_str_prefixed(&self) -> String {
+        to_str_radix_with_prefix(self, 2, "0b")
+    }
+
+    /// Returns the number of digits of the integer in the given radix, the same as
+    /// `self.to_str_radix(radix).len()` but without formatting the string.
+    /// `radix` must be in the range `2...36`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::from(0u32).digit_count(10), 1);
+    /// assert_eq!(BigUint::from(255u32).digit_count(16), 2);
+    /// assert_eq!(BigUint::from(256u32).digit_count(16), 3);
+    /// assert_eq!(BigUint::from(u64::MAX).digit_count(36), 13);
+    /// ```
+    pub fn digit_count(&self, radix: u32) -> usize {
+        digit_count(self, radix)
+    }
+
+    /// Returns the number of decimal digits of the integer, the same as `self.digit_count(10)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::from(999u32).decimal_digits(), 3);
+    /// assert_eq!(BigUint::from(1000u32).decimal_digits(), 4);
+    /// assert_eq!(BigUint::factorial(100).decimal_digits(), 158);
+    /// ```
+    #[inline]
+    pub fn decimal_digits(&self) -> usize {
+        self.digit_count(10)
+    }
+
+    /// Returns the digital root of the integer in the given base, found by repeatedly summing its
+    /// digits in that base until a single digit remains. The digital root of zero is zero.
+    /// `base` must be at least 2.
+    ///
+    /// Integers that fit in a `u64` are summed digit by digit, as in the definition. Each digit
+    /// sum leaves the value unchanged modulo `base - 1`, so larger integers skip straight to the
+    /// remainder by `base - 1`, with `base - 1` in place of zero for nonzero integers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::from(493u32).digital_root(10), BigUint::from(7u32));
+    /// assert_eq!(BigUint::from(0u32).digital_root(10), BigUint::from(0u32));
+    /// assert_eq!(BigUint::from(0xffu32).digital_root(16), BigUint::from(15u32));
+    /// assert_eq!(BigUint::from(6u32).digital_root(2), BigUint::from(1u32));
+    /// ```
+    pub fn digital_root(&self, base: u32) -> BigUint {
+        assert!(base >= 2, "The base must be at least 2");
+        if let Some(mut n) = self.to_u64() {
+            // The digit sum of a `u64` is at most `64 * (base - 1)`, so it can't overflow.
+            let base = u64::from(base);
+            while n >= base {
+                let mut sum = 0;
+                while n != 0 {
+                    sum += n % base;
+                    n /= base;
+                }
+                n = sum;
+            }
+            return BigUint::from(n);
+        }
+        let r = self % (base - 1);
+        if r.is_zero() {
+            BigUint::from(base - 1)
+        } else {
+            r
+        }
+    }
+
+    /// Returns the sum of the digits of the integer in the given base. The digit sum of zero is
+    /// zero. `base` must be at least 2.
+    ///
+    /// The digits are found as by [`BigUint::to_radix_le`], or for bases above 256 by dividing by
+    /// powers of the base. Only the sum modulo `base - 1` is known without them, since it is
+    /// congruent to the integer itself, as for [`BigUint::digital_root`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::from(493u32).digit_sum(10), BigUint::from(16u32));
+    /// assert_eq!(BigUint::from(0xffu32).digit_sum(16), BigUint::from(30u32));
+    /// assert_eq!(BigUint::from(6u32).digit_sum(2), BigUint::from(2u32));
+    ///
+    /// // The digit sum of 2^1000 is 1366, which is 2^1000 modulo 9.
+    /// let n = BigUint::from(2u32).pow(1000u32);
+    /// assert_eq!(n.digit_sum(10), BigUint::from(1366u32));
+    /// assert_eq!(n.digit_sum(10) % 9u32, n % 9u32);
+    /// ```
+    pub fn digit_sum(&self, base: u32) -> BigUint {
+        assert!(base >= 2, "The base must be at least 2");
+        let digits = convert::to_u32_radix_le(self, base);
+        let sum: u128 = digits.iter().map(|&d| u128::from(d)).sum();
+        BigUint::from(sum)
+    }
+
+    /// Returns the product of the digits of the integer in the given base, which is zero if any
+    /// digit is zero, including for zero itself. `base` must be at least 2.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::from(493u32).digit_product(10), BigUint::from(108u32));
+    /// assert_eq!(BigUint::from(405u32).digit_product(10), BigUint::from(0u32));
+    /// assert_eq!(BigUint::from(0xffu32).digit_product(16), BigUint::from(225u32));
+    /// ```
+    pub fn digit_product(&self, base: u32) -> BigUint {
+        assert!(base >= 2, "The base must be at least 2");
+        let digits = convert::to_u32_radix_le(self, base);
+        if digits.contains(&0) {
+            return BigUint::ZERO;
+        }
+
+        // Gather digits in a `u64` while they fit, to save on big multiplications.
+        let mut product = BigUint::one();
+        let mut word = 1u64;
+        for d in digits {
+            match word.checked_mul(u64::from(d)) {
+                Some(w) => word = w,
+                None => {
+                    product *= word;
+                    word = u64::from(d);
+                }
+            }
+        }
+        product * word
+    }
+
+    /// Returns the integer in the requested base in big-endian digit order.
+    /// The output is not given in a human readable alphabet but as a zero
+    /// based `u8` number.
+    /// `radix` must be in the range `2...256`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::from(0xFFFFu64).to_radix_be(159),
+    ///            vec![2, 94, 27]);
+    /// // 0xFFFF = 65535 = 2*(159^2) + 94*159 + 27
+    /// ```
+    #[inline]
+    pub fn to_radix_be(&self, radix: u32) -> Vec<u8> {
+        let mut v = convert::to_radix_le(self, radix);
+        v.reverse();
+        v
+    }
+
+    /// Returns the integer in the requested base in little-endian digit order.
+    /// The output is not given in a human readable alphabet but as a zero
+    /// based u8 number.
+    /// `radix` must be in the range `2...256`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::from(0xFFFFu64).to_radix_le(159),
+    ///            vec![27, 94, 2]);
+    /// // 0xFFFF = 65535 = 27 + 94*159 + 2*(159^2)
+    /// ```
+    #[inline]
+    pub fn to_radix_le(&self, radix: u32) -> Vec<u8> {
+        convert::to_radix_le(self, radix)
+    }
+
+    /// Determines the fewest bits necessary to express the [`BigUint`].
+    ///
+    /// This is `floor(log2(n)) + 1` for nonzero `n`, and 0 for zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::from(0u32).bits(), 0);
+    /// assert_eq!(BigUint::from(255u32).bits(), 8);
+    /// assert_eq!(BigUint::from(256u32).bits(), 9);
+    /// ```
+    #[inline]
+    pub fn bits(&self) -> u64 {
+        if self.is_zero() {
+            return 0;
+        }
+        let zeros: u64 = self.data.last().unwrap().leading_zeros().into();
+        self.data.len() as u64 * u64::from(big_digit::BITS) - zeros
+    }
+
+    /// Returns the number of bits in the binary representation of `self`, which is
+    /// `floor(log2(self)) + 1`, or 0 for zero. This is the same as [`BigUint::bits`].
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::from(0u32).bit_count(), 0);
+    /// assert_eq!(BigUint::from(255u32).bit_count(), 8);
+    /// assert_eq!(BigUint::from(256u32).bit_count(), 9);
+    /// ```
+    #[inline]
+    pub fn bit_count(&self) -> u64 {
+        self.bits()
+    }
+
+    /// Determines the fewest bytes necessary to express the [`BigUint`], which is
+    /// [`BigUint::bits`] divided by 8 and rounded up.
+    ///
+    /// This is the length of [`BigUint::to_bytes_be`] and [`BigUint::to_bytes_le`], except for
+    /// zero: this is 0, while they return a single zero byte.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::from(0u32).byte_count(), 0);
+    /// assert_eq!(BigUint::from(255u32).byte_count(), 1);
+    /// assert_eq!(BigUint::from(256u32).byte_count(), 2);
+    ///
+    /// let n = BigUint::from(0x1234_5678u32);
+    /// let mut buf = vec![0; n.byte_count()];
+    /// buf.copy_from_slice(&n.to_bytes_be());
+    /// ```
+    #[inline]
+    pub fn byte_count(&self) -> usize {
+        // A value stored in memory has a byte length that fits in a `usize`.
+        Integer::div_ceil(&self.bits(), &8) as usize
+    }
+
+    /// Returns the base 2 logarithm of `self`, rounded down.
+    ///
+    /// This is one less than [`Self::bits`].
+    ///
+    /// Panics if `self` is zero.
+    #[inline]
+    pub fn ilog2(&self) -> u64 {
+        self.checked_ilog2()
+            .expect("argument of integer logarithm must be positive")
+    }
+
+    /// Returns the base 2 logarithm of `self`, rounded down, or `None` if `self` is zero.
+    #[inline]
+    pub fn checked_ilog2(&self) -> Option<u64> {
+        self.bits().checked_sub(1)
+    }
+
+    /// Returns the base 10 logarithm of `self`, rounded down.
+    ///
+    /// This is one less than the number of decimal digits in `self`.
+    ///
+    /// Panics if `self` is zero.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::from(999u32).ilog10(), 2);
+    /// assert_eq!(BigUint::from(1000u32).ilog10(), 3);
+    /// ```
+    #[inline]
+    pub fn ilog10(&self) -> u64 {
+        self.checked_ilog10()
+            .expect("argument of integer logarithm must be positive")
+    }
+
+    /// Returns the base 10 logarithm of `self`, rounded down, or `None` if `self` is zero.
+    #[inline]
+    pub fn checked_ilog10(&self) -> Option<u64> {
+        self.checked_ilog(&BigUint::from(10u8))
+    }
+
+    /// Returns the logarithm of `self` with respect to `base`, rounded down.
+    ///
+    /// Panics if `self` is zero, or if `base` is less than 2.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let x = BigUint::from(3u32).pow(100);
+    /// assert_eq!(x.ilog(&BigUint::from(3u32)), 100);
+    /// assert_eq!((x - 1u32).ilog(&BigUint::from(3u32)), 99);
+    /// ```
+    #[inline]
+    pub fn ilog(&self, base: &BigUint) -> u64 {
+        assert!(
+            *base >= BigUint::from(2u8),
+            "base of integer logarithm must be at least 2"
+        );
+        self.checked_ilog(base)
+            .expect("argument of integer logarithm must be positive")
+    }
+
+    /// Returns the logarithm of `self` with respect to `base`, rounded down, or `None` if `self`
+    /// is zero or `base` is less than 2.
+    pub fn checked_ilog(&self, base: &BigUint) -> Option<u64> {
+        if self.is_zero() || *base < BigUint::from(2u8) {
+            return None;
+        }
+        if self < base {
+            return Some(0);
+        }
+        if base.count_ones() == 1 {
+            return Some(self.ilog2() / base.ilog2());
+        }
+
+        // Estimate from the binary logarithms, then correct the rounding error.
+        let mut log = log2_q32(self) / log2_q32(base);
+        let mut pow = Pow::pow(base, log);
+        while pow > *self {
+            pow /= base;
+            log -= 1;
+        }
+        loop {
+            pow *= base;
+            if pow > *self {
+                return Some(log);
+            }
+            log += 1;
+        }
+    }
+
+    /// Strips off trailing zero bigdigits - comparisons require the last element in the vector to
+    /// be nonzero.
+    #[inline]
+    fn normalize(&mut self) {
+        if let Some(&0) = self.data.last() {
+            let len = self.data.iter().rposition(|&d| d != 0).map_or(0, |i| i + 1);
+            self.data.truncate(len);
+        }
+        if self.data.len() < self.data.capacity() / 4 {
+            self.data.shrink_to_fit();
+        }
+    }
+
+    /// Returns a normalized [`BigUint`].
+    #[inline]
+    fn normalized(mut self) -> BigUint {
+        self.normalize();
+        self
+    }
+
+    /// Returns `self` restricted to the interval `[min, max]`.
+    ///
+    /// This is the same as [`Ord::clamp`], but borrows its arguments and clones only the bound
+    /// or value that is returned. Panics if `min > max`.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let (min, max) = (BigUint::from(10u32), BigUint::from(20u32));
+    /// assert_eq!(BigUint::from(5u32).clamp_ref(&min, &max), min);
+    /// assert_eq!(BigUint::from(25u32).clamp_ref(&min, &max), max);
+    ///
+    /// let x = &BigUint::from(15u32);
+    /// assert_eq!(x.clamp_ref(&min, &max), *x);
+    /// ```
+    pub fn clamp_ref(&self, min: &Self, max: &Self) -> Self {
+        assert!(min <= max);
+        if self < min {
+            min.clone()
+        } else if self > max {
+            max.clone()
+        } else {
+            self.clone()
+        }
+    }
+
+    /// Converts `self` to a `u64`, saturating at `u64::MAX` if it doesn't fit.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::from(42u32).clamp_to_u64(), 42);
+    /// assert_eq!(BigUint::from(u128::MAX).clamp_to_u64(), u64::MAX);
+    /// ```
+    pub fn clamp_to_u64(&self) -> u64 {
+        self.to_u64().unwrap_or(u64::MAX)
+    }
+
+    /// Compares `self` with a primitive value, without converting either side.
+    ///
+    /// Smaller unsigned types can be widened with `u128::from`.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    /// use std::cmp::Ordering;
+    ///
+    /// let x = BigUint::from(1000u32);
+    /// assert_eq!(x.cmp_u128(1000), Ordering::Equal);
+    /// assert_eq!(x.cmp_u128(u128::from(u64::MAX)), Ordering::Less);
+    /// assert_eq!((x << 128u32).cmp_u128(u128::MAX), Ordering::Greater);
+    /// ```
+    pub fn cmp_u128(&self, v: u128) -> Ordering {
+        if self.data.len() > 128 / usize::from(big_digit::BITS) {
+            Ordering::Greater
+        } else {
+            let mut x: u128 = 0;
+            for (i, &digit) in self.data.iter().enumerate() {
+                x |= u128::from(digit) << (i * usize::from(big_digit::BITS));
+            }
+            x.cmp(&v)
+        }
+    }
+
+    /// Returns the quotient and remainder of `self / rhs`, computed with a single division.
+    ///
+    /// This is the same as [`Integer::div_rem`], and panics if `rhs` is zero just like `/`.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let a = BigUint::from(1234u32);
+    /// let (q, r) = a.divmod(&BigUint::from(100u32));
+    /// assert_eq!(q, BigUint::from(12u32));
+    /// assert_eq!(r, BigUint::from(34u32));
+    /// ```
+    #[inline]
+    pub fn divmod(&self, rhs: &Self) -> (Self, Self) {
+        division::div_rem_ref(self, rhs)
+    }
+
+    /// Returns `self + rhs`, or `None` if the sum doesn't fit in `bits` bits.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let a = BigUint::from(200u32);
+    /// assert_eq!(a.checked_add_bounded(&BigUint::from(55u32), 8), Some(BigUint::from(255u32)));
+    /// assert_eq!(a.checked_add_bounded(&BigUint::from(56u32), 8), None);
+    /// ```
+    pub fn checked_add_bounded(&self, rhs: &Self, bits: u64) -> Option<Self> {
+        if self.bits() > bits || rhs.bits() > bits {
+            return None;
+        }
+        let sum = self + rhs;
+        if sum.bits() > bits {
+            None
+        } else {
+            Some(sum)
+        }
+    }
+
+    /// Returns `self * rhs`, or `None` if the product doesn't fit in `bits` bits.
+    ///
+    /// Products that are sure to be too large are rejected without multiplying.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let a = BigUint::from(u64::MAX);
+    /// assert_eq!(a.checked_mul_bounded(&a, 128), Some(&a * &a));
+    /// assert_eq!(a.checked_mul_bounded(&a, 127), None);
+    /// ```
+    pub fn checked_mul_bounded(&self, rhs: &Self, bits: u64) -> Option<Self> {
+        if self.is_zero() || rhs.is_zero() {
+            return Some(Self::ZERO);
+        }
+        // The product of an `m`-bit and an `n`-bit number has `m + n - 1` or `m + n` bits.
+        let min_bits = self.bits() + rhs.bits() - 1;
+        if min_bits > bits {
+            return None;
+        }
+        let product = self * rhs;
+        if product.bits() > bits {
+            None
+        } else {
+            Some(product)
+        }
+    }
+
+    /// Returns `self * self`.
+    ///
+    /// This is faster than multiplying `self` by itself, as a square only needs about half of the
+    /// digit products of a general multiplication.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let a = BigUint::from(u64::MAX);
+    /// assert_eq!(a.square(), &a * &a);
+    /// ```
+    pub fn square(&self) -> Self {
+        match *self.data {
+            [] => Self::ZERO,
+            [digit] => self * digit,
+            ref x => multiplication::square(x),
+        }
+    }
+
+    /// Returns `self ^ exponent`.
+    ///
+    /// [`Pow`] is also implemented for every unsigned primitive exponent, including `u64` and
+    /// `u128`, and for `BigUint` exponents. Powers that would have more than `u64::MAX` bits
+    /// panic before trying to compute them.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    /// use num_traits::Pow;
+    ///
+    /// let ten = BigUint::from(10u32);
+    /// assert_eq!(BigUint::pow(&ten, 20), BigUint::from(10u128.pow(20)));
+    /// assert_eq!(Pow::pow(&ten, 20u64), BigUint::pow(&ten, 20));
+    /// assert_eq!(Pow::pow(BigUint::from(1u32), u128::MAX), BigUint::from(1u32));
+    /// ```
+    pub fn pow(&self, exponent: u32) -> Self {
+        Pow::pow(self, exponent)
+    }
+
+    /// Returns `self ^ exponent`, for an exponent that may not fit in a `u32`.
+    ///
+    /// This uses binary exponentiation, so the time is dominated by the final squaring, but the
+    /// result of any base above 1 has at least as many bits as the exponent, so huge exponents
+    /// won't finish or fit in memory. It panics if the exponent needs more than 128 bits, or the
+    /// result would need more than `u64::MAX` bits. Use
+    /// [`BigUint::checked_pow_big`] to refuse results that are too large first.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let exponent = BigUint::from(100u32);
+    /// assert_eq!(BigUint::from(2u32).pow_big(&exponent), BigUint::from(1u128 << 100));
+    /// assert_eq!(BigUint::from(1u32).pow_big(&(BigUint::from(1u32) << 1000u32)), BigUint::from(1u32));
+    /// ```
+    pub fn pow_big(&self, exponent: &Self) -> Self {
+        Pow::pow(self, exponent)
+    }
+
+    /// Returns `self ^ exponent`, or `None` if the result doesn't fit in `max_bits` bits.
+    ///
+    /// Results that are sure to be too large are rejected without multiplying, however large
+    /// the exponent.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let two = BigUint::from(2u32);
+    /// let exponent = BigUint::from(100u32);
+    /// assert_eq!(two.checked_pow_big(&exponent, 101), Some(BigUint::from(1u128 << 100)));
+    /// assert_eq!(two.checked_pow_big(&exponent, 100), None);
+    /// assert_eq!(two.checked_pow_big(&(BigUint::from(1u32) << 1000u32), 1 << 40), None);
+    /// ```
+    pub fn checked_pow_big(&self, exponent: &Self, max_bits: u64) -> Option<Self> {
+        if self.is_zero() || self.is_one() || exponent.is_zero() {
+            let result = Pow::pow(self, exponent);
+            return if result.bits() > max_bits {
+                None
+            } else {
+                Some(result)
+            };
+        }
+        // The power of an `n`-bit number has at least `(n - 1) * exponent + 1` bits.
+        let exponent = exponent.to_u64()?;
+        let min_bits = (self.bits() - 1).checked_mul(exponent)?.checked_add(1)?;
+        if min_bits > max_bits {
+            return None;
+        }
+        let result = Pow::pow(self, exponent);
+        if result.bits() > max_bits {
+            None
+        } else {
+            Some(result)
+        }
+    }
+
+    /// Returns `(self ^ exponent) % modulus`.
+    ///
+    /// Panics if the modulus is zero.
+    pub fn modpow(&self, exponent: &Self, modulus: &Self) -> Self {
+        power::modpow(self, exponent, modulus)
+    }
+
+    /// Returns `(self ^ exponent) % modulus`, for a primitive exponent.
+    ///
+    /// Panics if the modulus is zero.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let base = BigUint::from(4u32);
+    /// let modulus = BigUint::from(497u32);
+    /// assert_eq!(base.modpow_u64(13, &modulus), BigUint::from(445u32));
+    /// assert_eq!(base.modpow_u64(u64::MAX, &modulus), base.modpow(&u64::MAX.into(), &modulus));
+    /// ```
+    pub fn modpow_u64(&self, exponent: u64, modulus: &Self) -> Self {
+        power::modpow(self, &BigUint::from(exponent), modulus)
+    }
+
+    /// Returns `(self ^ exponent) % modulus`, using a Montgomery ladder so the sequence of
+    /// operations doesn't depend on the bits of the exponent.
+    ///
+    /// Every bit does one multiplication and one squaring, exchanging the operands with
+    /// [`Self::conditional_swap`] instead of branching. This is meant for secret exponents, as
+    /// in RSA, but it is only constant-time at the level of this algorithm: the time still
+    /// depends on the length of the exponent in digits, and the underlying multiplication and
+    /// division may take different times for different values on real hardware.
+    ///
+    /// Panics if the modulus is zero.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let base = BigUint::from(4u32);
+    /// let exponent = BigUint::from(13u32);
+    /// let modulus = BigUint::from(497u32);
+    /// assert_eq!(base.modpow_ct(&exponent, &modulus), BigUint::from(445u32));
+    /// ```
+    pub fn modpow_ct(&self, exponent: &Self, modulus: &Self) -> Self {
+        power::modpow_ct(self, exponent, modulus)
+    }
+
+    /// Swaps the values of `self` and `other` if `condition` is true, with the same memory
+    /// accesses either way.
+    ///
+    /// Both values are first extended to the same number of digits, so this only hides the
+    /// condition, not the lengths of the values.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let mut a = BigUint::from(1u32);
+    /// let mut b = BigUint::from(2u32);
+    /// a.conditional_swap(&mut b, false);
+    /// assert_eq!((&a, &b), (&BigUint::from(1u32), &BigUint::from(2u32)));
+    /// a.conditional_swap(&mut b, true);
+    /// assert_eq!((&a, &b), (&BigUint::from(2u32), &BigUint::from(1u32)));
+    /// ```
+    pub fn conditional_swap(&mut self, other: &mut Self, condition: bool) {
+        let len = Ord::max(self.data.len(), other.data.len());
+        self.data.resize(len, 0);
+        other.data.resize(len, 0);
+
+        let mask = BigDigit::from(condition).wrapping_neg();
+        for (a, b) in self.data.iter_mut().zip(other.data.iter_mut()) {
+            let t = (*a ^ *b) & mask;
+            *a ^= t;
+            *b ^= t;
+        }
+
+        self.normalize();
+        other.normalize();
+    }
+
+    /// Returns the modular multiplicative inverse if it exists, otherwise `None`.
+    ///
+    /// This solves for `x` in the interval `[0, modulus)` such that `self * x ≡ 1 (mod modulus)`.
+    /// The solution exists if and only if `gcd(self, modulus) == 1`.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    /// use num_traits::{One, Zero};
     ///
     /// let m = BigUint::from(383_u32);
     ///
@@ -967,6 +1999,157 @@ impl BigUint {
         }
     }
 
+    /// Returns the modular multiplicative inverse of `self`, like [`BigUint::modinv`], but
+    /// panics if it does not exist, because `gcd(self, modulus) != 1`.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let m = BigUint::from(383_u32);
+    /// assert_eq!(BigUint::from(271_u32).modinv_unchecked(&m), BigUint::from(106_u32));
+    /// ```
+    pub fn modinv_unchecked(&self, modulus: &Self) -> Self {
+        self.modinv(modulus)
+            .expect("attempt to invert a value that is not coprime to the modulus")
+    }
+
+    /// Returns the greatest common divisor `g` of `self` and `other`, along with Bézout
+    /// coefficients `x` and `y` such that `self * x + other * y == g`.
+    ///
+    /// The coefficients may be negative, so they are returned as [`BigInt`].
+    ///
+    /// ```
+    /// use num_bigint::{BigInt, BigUint};
+    ///
+    /// let a = BigUint::from(240u32);
+    /// let b = BigUint::from(46u32);
+    /// let (g, x, y) = a.extended_gcd(&b);
+    /// assert_eq!(g, BigUint::from(2u32));
+    /// assert_eq!((x.clone(), y.clone()), (BigInt::from(-9), BigInt::from(47)));
+    /// assert_eq!(BigInt::from(a) * x + BigInt::from(b) * y, BigInt::from(g));
+    /// ```
+    pub fn extended_gcd(&self, other: &Self) -> (BigUint, BigInt, BigInt) {
+        let (mut r0, mut r1) = (self.clone(), other.clone());
+        let (mut s0, mut s1) = (BigInt::one(), BigInt::zero());
+        let (mut t0, mut t1) = (BigInt::zero(), BigInt::one());
+
+        while !r1.is_zero() {
+            let (q, r2) = r0.div_rem(&r1);
+            let q = BigInt::from(q);
+            let s2 = &s0 - &q * &s1;
+            let t2 = &t0 - q * &t1;
+
+            r0 = mem::replace(&mut r1, r2);
+            s0 = mem::replace(&mut s1, s2);
+            t0 = mem::replace(&mut t1, t2);
+        }
+
+        (r0, s0, t0)
+    }
+
+    /// Returns the Jacobi symbol `(self/n)`, which is -1, 0 or 1. Panics if `n` is even.
+    ///
+    /// The symbol is 0 exactly when `self` and `n` have a common factor, and is multiplicative
+    /// in both arguments. When `n` is an odd prime, it is the Legendre symbol, which is 1 if
+    /// `self` is a nonzero square modulo `n` and -1 if it is not a square. For composite `n`,
+    /// a symbol of 1 does not imply that `self` is a square.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let p = BigUint::from(11u32);
+    /// // 5 = 4^2 (mod 11), but 2 is not a square.
+    /// assert_eq!(BigUint::from(5u32).jacobi_symbol(&p), 1);
+    /// assert_eq!(BigUint::from(2u32).jacobi_symbol(&p), -1);
+    /// assert_eq!(BigUint::from(22u32).jacobi_symbol(&p), 0);
+    /// ```
+    pub fn jacobi_symbol(&self, n: &Self) -> i8 {
+        jacobi::jacobi(self, n)
+    }
+
+    /// Returns a square root of `self` modulo the prime `p`, or `None` if `self` is not a square
+    /// modulo `p`. Panics if `p` is even, unless it is 2.
+    ///
+    /// The root `x` is in `0..p`, and the other root is `p - x`. Quadratic residues are detected
+    /// by the Jacobi symbol. When `p = 3 (mod 4)`, the root is found with a single modular
+    /// exponentiation, and otherwise by the Tonelli-Shanks algorithm. If `p` is not prime, the
+    /// result is meaningless.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let p = BigUint::from(13u32);
+    /// let x = BigUint::from(10u32).sqrt_mod_prime(&p).unwrap();
+    /// assert!(x == BigUint::from(6u32) || x == BigUint::from(7u32));
+    /// assert_eq!(BigUint::from(5u32).sqrt_mod_prime(&p), None);
+    /// ```
+    pub fn sqrt_mod_prime(&self, p: &Self) -> Option<Self> {
+        if p.data == [2] {
+            return Some(self % 2u32);
+        }
+        modsqrt::sqrt_mod_prime(self, p)
+    }
+
+    /// Returns `true` if `self` is probably prime, using the Miller-Rabin test.
+    ///
+    /// Values that fit in a `u64` are tested deterministically, with no false positives.
+    /// Larger values are first checked by trial division with small primes, then tested with
+    /// `rounds` witnesses. A composite number passes each round with probability at most 1/4, so
+    /// the chance of a false positive is at most `4^-rounds`. A result of `false` is always
+    /// correct.
+    ///
+    /// The witnesses are drawn from a fixed pseudo-random sequence, so the result is reproducible.
+    /// If the input may be chosen by an adversary, use `is_prime_miller_rabin_with_rng` with the
+    /// `rand` feature instead.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let m127 = BigUint::from(2u32).pow(127) - 1u32;
+    /// assert!(m127.is_prime_miller_rabin(20));
+    /// assert!(!(m127 + 2u32).is_prime_miller_rabin(20));
+    /// ```
+    pub fn is_prime_miller_rabin(&self, rounds: usize) -> bool {
+        prime::is_prime_miller_rabin(self, rounds)
+    }
+
+    /// Returns `true` if `self` is probably prime, using 64 rounds of [`Self::is_prime_miller_rabin`].
+    pub fn is_prime(&self) -> bool {
+        self.is_prime_miller_rabin(64)
+    }
+
+    /// Returns `true` if `self` is zero -- see [`num_traits::Zero::is_zero()`].
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Returns `true` if `self` is one -- see [`num_traits::One::is_one()`].
+    #[inline]
+    pub fn is_one(&self) -> bool {
+        self.data[..] == [1]
+    }
+
+    /// Returns `true` if `self` is divisible by two -- see [`num_integer::Integer::is_even()`].
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert!(BigUint::from(0u32).is_even());
+    /// assert!((BigUint::from(3u32) << 100u32).is_even());
+    /// assert!(BigUint::from(u64::MAX).is_odd());
+    /// ```
+    #[inline]
+    pub fn is_even(&self) -> bool {
+        self.data.first().map_or(true, |&d| d & 1 == 0)
+    }
+
+    /// Returns `true` if `self` is not divisible by two -- see [`num_integer::Integer::is_odd()`].
+    #[inline]
+    pub fn is_odd(&self) -> bool {
+        !self.is_even()
+    }
+
     /// Returns the truncated principal square root of `self` --
     /// see [Roots::sqrt](https://docs.rs/num-integer/0.1/num_integer/trait.Roots.html#method.sqrt)
     pub fn sqrt(&self) -> Self {
@@ -985,6 +2168,71 @@ impl BigUint {
         Roots::nth_root(self, n)
     }
 
+    /// Returns the `n`th root of `self` if it is an integer, or `None` otherwise. Panics if `n`
+    /// is zero.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let x = BigUint::from(3u32).pow(40);
+    /// assert_eq!(x.nth_root_exact(5), Some(BigUint::from(3u32).pow(8)));
+    /// assert_eq!(x.nth_root_exact(3), None);
+    /// assert_eq!((x + 1u32).nth_root_exact(2), None);
+    /// ```
+    pub fn nth_root_exact(&self, n: u32) -> Option<Self> {
+        let root = self.nth_root(n);
+        if Pow::pow(&root, n) == *self {
+            Some(root)
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if `self` is the square of an integer.
+    ///
+    /// This is faster than comparing `self.sqrt().pow(2)` with `self`, because most non-squares
+    /// are ruled out by their residues modulo a few small numbers, without taking the square
+    /// root.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let x = BigUint::from(u64::MAX).pow(2);
+    /// assert!(x.is_perfect_square());
+    /// assert!(!(x + 1u32).is_perfect_square());
+    /// assert!(BigUint::from(0u32).is_perfect_square());
+    /// ```
+    pub fn is_perfect_square(&self) -> bool {
+        square::is_perfect_square(self)
+    }
+
+    /// If `self` is `a^k` for integers `a > 1` and `k > 1`, returns `Some((a, k))` with the
+    /// largest such `k`, and otherwise `None`.
+    ///
+    /// Each exponent `k` from `log2(self)` down to 2 is tried with [`BigUint::nth_root_exact`],
+    /// skipping those that can't divide the number of trailing zeros.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::from(64u32).is_perfect_power(), Some((BigUint::from(2u32), 6)));
+    /// assert_eq!(BigUint::from(36u32).is_perfect_power(), Some((BigUint::from(6u32), 2)));
+    /// assert_eq!(BigUint::from(72u32).is_perfect_power(), None);
+    /// assert_eq!(BigUint::from(1u32).is_perfect_power(), None);
+    /// ```
+    pub fn is_perfect_power(&self) -> Option<(Self, u32)> {
+        // `a^k` has `k` times as many trailing zeros as `a`.
+        let twos = self.trailing_zeros()?;
+        let max = self.bits().checked_sub(1)?;
+        (2..=max).rev().find_map(|k| {
+            if twos % k != 0 {
+                return None;
+            }
+            let k = u32::try_from(k).ok()?;
+            self.nth_root_exact(k).map(|a| (a, k))
+        })
+    }
+
     /// Returns the number of least-significant bits that are zero,
     /// or `None` if the entire number is zero.
     pub fn trailing_zeros(&self) -> Option<u64> {
@@ -993,6 +2241,31 @@ impl BigUint {
         Some(i as u64 * u64::from(big_digit::BITS) + zeros)
     }
 
+    /// Returns the number of leading zero bits in the most significant internal digit, or zero
+    /// if `self` is zero.
+    ///
+    /// The internal digits are 64 bits on most 64-bit targets and 32 bits otherwise, so the
+    /// result depends on the platform. Shifting left by this amount normalizes the top digit.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let zeros = BigUint::from(1u32).leading_zeros();
+    /// assert!(zeros == 31 || zeros == 63);
+    /// assert_eq!(BigUint::from(u64::MAX).leading_zeros() % 32, 0);
+    /// ```
+    pub fn leading_zeros(&self) -> u64 {
+        self.data
+            .last()
+            .map_or(0, |&digit| digit.leading_zeros().into())
+    }
+
+    /// Returns the number of bits needed to represent `self`, the same as [`Self::bits`].
+    #[inline]
+    pub fn bit_len(&self) -> u64 {
+        self.bits()
+    }
+
     /// Returns the number of least-significant bits that are ones.
     pub fn trailing_ones(&self) -> u64 {
         if let Some(i) = self.data.iter().position(|&digit| !digit != 0) {
@@ -1008,6 +2281,52 @@ impl BigUint {
         self.data.iter().map(|&d| u64::from(d.count_ones())).sum()
     }
 
+    /// Returns the number of zero bits below the most significant one bit.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::from(0b10100u32).count_zeros(), 3);
+    /// ```
+    pub fn count_zeros(&self) -> u64 {
+        self.bits() - self.count_ones()
+    }
+
+    /// Returns `true` if exactly one bit is set, so `self` is `2^k` for some `k`.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert!(BigUint::from(1024u32).is_power_of_two());
+    /// assert!(!BigUint::from(1025u32).is_power_of_two());
+    /// assert!(!BigUint::from(0u32).is_power_of_two());
+    /// ```
+    pub fn is_power_of_two(&self) -> bool {
+        match self.data.split_last() {
+            Some((last, rest)) => last.is_power_of_two() && rest.iter().all(|&d| d == 0),
+            None => false,
+        }
+    }
+
+    /// Returns the smallest power of two greater than or equal to `self`. Like the primitive
+    /// method, this is 1 for zero.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::from(1024u32).next_power_of_two(), BigUint::from(1024u32));
+    /// assert_eq!(BigUint::from(1025u32).next_power_of_two(), BigUint::from(2048u32));
+    /// assert_eq!(BigUint::from(0u32).next_power_of_two(), BigUint::from(1u32));
+    /// ```
+    pub fn next_power_of_two(&self) -> BigUint {
+        if self.is_power_of_two() {
+            return self.clone();
+        }
+        let mut result = BigUint::ZERO;
+        result.set_bit(self.bits(), true);
+        result
+    }
+
     /// Returns whether the bit in the given position is set
     pub fn bit(&self, bit: u64) -> bool {
         let bits_per_digit = u64::from(big_digit::BITS);
@@ -1042,6 +2361,38 @@ impl BigUint {
             self.normalize();
         }
     }
+
+    /// Returns whether the bit in the given position is set, the same as [`Self::bit`].
+    #[inline]
+    pub fn test_bit(&self, bit: u64) -> bool {
+        self.bit(bit)
+    }
+
+    /// Clears the bit in the given position, the same as `set_bit(bit, false)`.
+    #[inline]
+    pub fn clear_bit(&mut self, bit: u64) {
+        self.set_bit(bit, false);
+    }
+
+    /// Flips the bit in the given position.
+    ///
+    /// Note that flipping a bit greater than the current bit length sets it, so a reallocation
+    /// may be needed to store the new digits.
+    pub fn flip_bit(&mut self, bit: u64) {
+        // Note: as with `set_bit`, a saturated `digit_index` is guaranteed to fail allocation.
+        let bits_per_digit = u64::from(big_digit::BITS);
+        let digit_index = (bit / bits_per_digit).to_usize().unwrap_or(usize::MAX);
+        let bit_mask = (1 as BigDigit) << (bit % bits_per_digit);
+        if digit_index >= self.data.len() {
+            let new_len = digit_index.saturating_add(1);
+            self.data.resize(new_len, 0);
+            self.data[digit_index] = bit_mask;
+        } else {
+            self.data[digit_index] ^= bit_mask;
+            // the top bit may have been cleared, so normalize
+            self.normalize();
+        }
+    }
 }
 
 impl num_traits::FromBytes for BigUint {
@@ -1099,6 +2450,13 @@ impl IntDigits for BigUint {
     }
 }
 
+/// Returns the number of digits needed for `bits` bits, panicking if that can't be allocated.
+#[inline]
+fn bits_to_digits(bits: u64) -> usize {
+    let digits = Integer::div_ceil(&bits, &u64::from(big_digit::BITS));
+    usize::try_from(digits).expect("capacity overflow")
+}
+
 /// Convert a `u32` chunk (len is either 1 or 2) to a single `u64` digit
 #[inline]
 fn u32_chunk_to_u64(chunk: &[u32]) -> u64 {
@@ -0,0 +1,143 @@
+#![cfg(feature = "allocator-api")]
+#![cfg_attr(docsrs, doc(cfg(feature = "allocator-api")))]
+
+use super::{cmp_slice, BigUint};
+use crate::big_digit::{self, BigDigit};
+
+use alloc::alloc::{Allocator, Global};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt;
+
+/// A [`BigUint`] whose digits are stored by a custom [`Allocator`], such as an arena or a pool.
+///
+/// This is a storage type: values are moved in with [`BigUintIn::from_biguint`], and out with
+/// [`BigUintIn::into_biguint`] or [`BigUintIn::to_biguint`] to compute with them, which allocate
+/// the digits in the other allocator. Comparisons work directly on the stored digits, while
+/// formatting goes through a temporary [`BigUint`].
+///
+/// ```
+/// #![feature(allocator_api)]
+/// use num_bigint::{BigUint, BigUintIn};
+/// use std::alloc::System;
+///
+/// let n = BigUint::from(u64::MAX).pow(3);
+/// let stored = BigUintIn::from_biguint(n.clone(), System);
+/// assert_eq!(stored, n);
+/// assert_eq!(stored.bits(), 192);
+/// assert_eq!(stored.into_biguint(), n);
+/// ```
+pub struct BigUintIn<A: Allocator = Global> {
+    data: Vec<BigDigit, A>,
+}
+
+impl<A: Allocator> BigUintIn<A> {
+    /// Returns zero stored in `alloc`, without allocating.
+    #[inline]
+    pub fn new_in(alloc: A) -> Self {
+        BigUintIn {
+            data: Vec::new_in(alloc),
+        }
+    }
+
+    /// Moves the value of `n` into a new allocation from `alloc`.
+    pub fn from_biguint(n: BigUint, alloc: A) -> Self {
+        let mut data = Vec::with_capacity_in(n.data.len(), alloc);
+        data.extend_from_slice(&n.data);
+        BigUintIn { data }
+    }
+
+    /// Returns a copy of the value with its digits in the global allocator.
+    pub fn to_biguint(&self) -> BigUint {
+        BigUint {
+            data: self.data.to_vec(),
+        }
+    }
+
+    /// Moves the value into the global allocator, freeing its storage in this one.
+    #[inline]
+    pub fn into_biguint(self) -> BigUint {
+        self.to_biguint()
+    }
+
+    /// Returns the allocator that stores the digits.
+    #[inline]
+    pub fn allocator(&self) -> &A {
+        self.data.allocator()
+    }
+
+    /// Returns `true` if the value is zero.
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Returns the number of bits needed to represent the value, as for [`BigUint::bits`].
+    pub fn bits(&self) -> u64 {
+        match self.data.last() {
+            Some(&hi) => {
+                let zeros = u64::from(hi.leading_zeros());
+                self.data.len() as u64 * u64::from(big_digit::BITS) - zeros
+            }
+            None => 0,
+        }
+    }
+}
+
+impl<A: Allocator + Clone> Clone for BigUintIn<A> {
+    #[inline]
+    fn clone(&self) -> Self {
+        BigUintIn {
+            data: self.data.clone(),
+        }
+    }
+}
+
+impl<A: Allocator + Default> Default for BigUintIn<A> {
+    #[inline]
+    fn default() -> Self {
+        Self::new_in(A::default())
+    }
+}
+
+impl<A: Allocator> PartialEq for BigUintIn<A> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.data[..] == other.data[..]
+    }
+}
+
+impl<A: Allocator> Eq for BigUintIn<A> {}
+
+impl<A: Allocator> PartialEq<BigUint> for BigUintIn<A> {
+    #[inline]
+    fn eq(&self, other: &BigUint) -> bool {
+        self.data[..] == other.data[..]
+    }
+}
+
+impl<A: Allocator> PartialOrd for BigUintIn<A> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<A: Allocator> Ord for BigUintIn<A> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        cmp_slice(&self.data, &other.data)
+    }
+}
+
+impl<A: Allocator> fmt::Debug for BigUintIn<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl<A: Allocator> fmt::Display for BigUintIn<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.to_biguint(), f)
+    }
+}
@@ -0,0 +1,92 @@
+//! Barrett reduction, for many remainders with the same modulus
+
+use super::division::{div_rem_barrett, reciprocal};
+use super::wrapping::truncate;
+use super::{biguint_from_vec, BigUint};
+use crate::big_digit;
+
+use core::cmp;
+use num_integer::Integer;
+
+/// Reduces values modulo a fixed modulus with Barrett's method.
+///
+/// Creating the reducer precomputes `mu = floor(4^k / modulus)`, where `k` is the bit length of
+/// the modulus, and then each reduction of a value with at most `2k` bits takes two
+/// multiplications instead of a division. That's most useful for reducing many products of
+/// residues, as in modular exponentiation or elliptic curve arithmetic.
+///
+/// # Examples
+///
+/// ```
+/// use num_bigint::{BarrettReducer, BigUint};
+///
+/// let modulus = BigUint::from(1_000_000_007u32);
+/// let reducer = BarrettReducer::new(modulus.clone());
+///
+/// let x = BigUint::from(123_456_789u32);
+/// let y = BigUint::from(987_654_321u32);
+/// assert_eq!(reducer.reduce(&(&x * &y)), x * y % modulus);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BarrettReducer {
+    modulus: BigUint,
+    mu: BigUint,
+    k: u64,
+}
+
+impl BarrettReducer {
+    /// Creates a reducer for `modulus`.
+    ///
+    /// Panics if `modulus` is zero.
+    pub fn new(modulus: BigUint) -> Self {
+        assert!(
+            !modulus.is_zero(),
+            "attempt to calculate with zero modulus!"
+        );
+        let mu = reciprocal(&modulus);
+        let k = modulus.bits();
+        BarrettReducer { modulus, mu, k }
+    }
+
+    /// Returns the modulus.
+    #[inline]
+    pub fn modulus(&self) -> &BigUint {
+        &self.modulus
+    }
+
+    /// Returns `n % modulus`.
+    ///
+    /// Values with at most twice the bits of the modulus, like products of two residues, are
+    /// reduced at once. Larger values are reduced `k` bits at a time from the top, which is
+    /// still correct but gains less over plain division.
+    pub fn reduce(&self, n: &BigUint) -> BigUint {
+        if n.bits() <= 2 * self.k {
+            return div_rem_barrett(n, &self.modulus, &self.mu).1;
+        }
+
+        // Each step reduces `r * 2^k + chunk`, which is less than `modulus * 2^k <= 4^k`.
+        let chunks = Integer::div_ceil(&n.bits(), &self.k);
+        let mut r = BigUint::ZERO;
+        for i in (0..chunks).rev() {
+            let chunk = bit_window(n, i * self.k, self.k);
+            r = div_rem_barrett(&((r << self.k) + chunk), &self.modulus, &self.mu).1;
+        }
+        r
+    }
+}
+
+/// Returns the `len` bits of `n` starting at bit `start`, copying only the digits they touch.
+fn bit_window(n: &BigUint, start: u64, len: u64) -> BigUint {
+    let digit_bits = u64::from(big_digit::BITS);
+    let first = start / digit_bits;
+    let end = cmp::min(
+        Integer::div_ceil(&(start + len), &digit_bits),
+        n.data.len() as u64,
+    );
+    if first >= end {
+        return BigUint::ZERO;
+    }
+    // Both are at most the length, so they fit in a `usize`.
+    let window = biguint_from_vec(n.data[first as usize..end as usize].to_vec());
+    truncate(window >> (start % digit_bits), len)
+}
@@ -91,3 +91,93 @@ impl BitXorAssign<&BigUint> for BigUint {
         self.normalize();
     }
 }
+
+/// Set operations, treating a `BigUint` as the set of the positions of its one bits.
+impl BigUint {
+    /// Returns `true` if every bit set in `self` is also set in `other`, so `self & other` is
+    /// `self`.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let a = BigUint::from(0b0101u32);
+    /// assert!(a.is_subset_of(&BigUint::from(0b1101u32)));
+    /// assert!(!a.is_subset_of(&BigUint::from(0b1100u32)));
+    /// ```
+    pub fn is_subset_of(&self, other: &BigUint) -> bool {
+        self.data.len() <= other.data.len()
+            && self
+                .data
+                .iter()
+                .zip(&other.data)
+                .all(|(&a, &b)| a & !b == 0)
+    }
+
+    /// Returns `true` if every bit set in `other` is also set in `self`, so `self & other` is
+    /// `other`.
+    #[inline]
+    pub fn is_superset_of(&self, other: &BigUint) -> bool {
+        other.is_subset_of(self)
+    }
+
+    /// Returns the bits set in `self` but not in `other`, which is `self & !other`.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let a = BigUint::from(0b1101u32);
+    /// assert_eq!(a.difference(&BigUint::from(0b0110u32)), BigUint::from(0b1001u32));
+    /// ```
+    pub fn difference(&self, other: &BigUint) -> BigUint {
+        let mut result = self.clone();
+        for (a, &b) in result.data.iter_mut().zip(&other.data) {
+            *a &= !b;
+        }
+        result.normalized()
+    }
+
+    /// Returns the number of bits set in both `self` and `other`, which is
+    /// `(self & other).count_ones()` without creating the intersection.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let a = BigUint::from(0b1101u32);
+    /// assert_eq!(a.count_shared_bits(&BigUint::from(0b0111u32)), 2);
+    /// ```
+    pub fn count_shared_bits(&self, other: &BigUint) -> u64 {
+        self.data
+            .iter()
+            .zip(&other.data)
+            .map(|(&a, &b)| u64::from((a & b).count_ones()))
+            .sum()
+    }
+
+    /// Returns the number of bits that differ between `self` and `other`, which is
+    /// `(self ^ other).count_ones()` without creating the symmetric difference.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let a = BigUint::from(0b1101u32);
+    /// assert_eq!(a.hamming_distance(&BigUint::from(0b0111u32)), 2);
+    /// assert_eq!(a.hamming_distance(&BigUint::from(0u32)), 3);
+    /// ```
+    pub fn hamming_distance(&self, other: &BigUint) -> u64 {
+        let (short, long) = if self.data.len() <= other.data.len() {
+            (&self.data, &other.data)
+        } else {
+            (&other.data, &self.data)
+        };
+        let common: u64 = short
+            .iter()
+            .zip(long.iter())
+            .map(|(&a, &b)| u64::from((a ^ b).count_ones()))
+            .sum();
+        let rest: u64 = long[short.len()..]
+            .iter()
+            .map(|&d| u64::from(d.count_ones()))
+            .sum();
+        common + rest
+    }
+}
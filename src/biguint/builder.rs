@@ -0,0 +1,119 @@
+//! Building a `BigUint` one digit at a time
+
+use super::BigUint;
+use crate::ParseBigIntError;
+
+/// Builds a [`BigUint`] from its digits, most significant first, without collecting them into a
+/// string or buffer.
+///
+/// Digits are values like those given to `BigUint::from_radix_be`, so `7` rather than `b'7'`.
+/// Each digit is combined into a machine word first, and only multiplied into the big value
+/// when the word is full.
+///
+/// ```
+/// use num_bigint::{BigUint, BigUintBuilder};
+///
+/// let mut builder = BigUintBuilder::new();
+/// for b in "12345678901234567890123".bytes() {
+///     builder.push_digit(b - b'0', 10);
+/// }
+/// assert_eq!(builder.finish(), "12345678901234567890123".parse::<BigUint>().unwrap());
+/// ```
+#[derive(Clone, Debug)]
+pub struct BigUintBuilder {
+    value: BigUint,
+    // The digits pushed since `value` was last updated, and the product of their radixes.
+    chunk: u64,
+    chunk_base: u64,
+}
+
+impl BigUintBuilder {
+    /// Creates a builder with no digits, which would finish as zero.
+    pub fn new() -> Self {
+        BigUintBuilder {
+            value: BigUint::ZERO,
+            chunk: 0,
+            chunk_base: 1,
+        }
+    }
+
+    /// Appends `digit` as the new least significant digit, in the given radix, so the value
+    /// becomes `value * radix + digit`. The radix may differ from one digit to the next.
+    ///
+    /// Panics if `radix` is not in the range `2...256`, or `digit` is not less than `radix`.
+    pub fn push_digit(&mut self, digit: u8, radix: u32) {
+        assert!(
+            (2..=256).contains(&radix),
+            "The radix must be within 2...256"
+        );
+        assert!(
+            u32::from(digit) < radix,
+            "The digit must be less than the radix"
+        );
+
+        let radix = u64::from(radix);
+        if self.chunk_base > u64::MAX / radix {
+            self.flush();
+        }
+        self.chunk = self.chunk * radix + u64::from(digit);
+        self.chunk_base *= radix;
+    }
+
+    /// Returns the value of the digits pushed so far.
+    pub fn finish(mut self) -> BigUint {
+        self.flush();
+        self.value
+    }
+
+    fn flush(&mut self) {
+        if self.chunk_base > 1 {
+            self.value *= self.chunk_base;
+            self.value += self.chunk;
+        }
+        self.chunk = 0;
+        self.chunk_base = 1;
+    }
+}
+
+impl Default for BigUintBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BigUint {
+    /// Creates and initializes a [`BigUint`] from decimal digit values, most significant first,
+    /// such as from a tokenizer or a stream.
+    ///
+    /// The digits are values from `0` to `9`, not ASCII characters. An empty input is an
+    /// [`Empty`][crate::ParseBigIntErrorKind::Empty] error, and a value above `9` is an
+    /// [`InvalidDigit`][crate::ParseBigIntErrorKind::InvalidDigit] error at its position.
+    ///
+    /// ```
+    /// use num_bigint::{BigUint, ParseBigIntErrorKind};
+    ///
+    /// let n = BigUint::from_decimal_digits([1, 2, 3, 0]).unwrap();
+    /// assert_eq!(n, BigUint::from(1230u32));
+    ///
+    /// let err = BigUint::from_decimal_digits(b"12x".iter().map(|b| b.wrapping_sub(b'0')));
+    /// assert_eq!(err.unwrap_err().position(), 2);
+    /// ```
+    pub fn from_decimal_digits<I: IntoIterator<Item = u8>>(
+        digits: I,
+    ) -> Result<BigUint, ParseBigIntError> {
+        let mut builder = BigUintBuilder::new();
+        let mut len = 0;
+        for digit in digits {
+            if digit > 9 {
+                return Err(ParseBigIntError::invalid(len));
+            }
+            builder.push_digit(digit, 10);
+            len += 1;
+        }
+        if len == 0 {
+            return Err(ParseBigIntError::empty(0));
+        }
+        Ok(builder.finish())
+    }
+}
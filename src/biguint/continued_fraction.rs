@@ -0,0 +1,163 @@
+//! Continued fractions of non-negative rationals, and their convergents
+
+use super::BigUint;
+
+use alloc::vec::Vec;
+use core::borrow::Borrow;
+use core::iter::FusedIterator;
+use core::mem;
+use num_integer::Integer;
+use num_traits::{One, Zero};
+
+impl BigUint {
+    /// Returns the coefficients `[a0; a1, a2, ...]` of the continued fraction of
+    /// `numerator / denominator`, by the Euclidean algorithm.
+    ///
+    /// The expansion is finite and in its canonical form: every coefficient after `a0` is
+    /// positive, and the last one is greater than one unless it is `a0` itself. Zero is `[0]`.
+    ///
+    /// Panics if `denominator` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// // 415/93 = 4 + 1/(2 + 1/(6 + 1/7))
+    /// let coefficients = BigUint::continued_fraction(&415u32.into(), &93u32.into());
+    /// assert_eq!(coefficients, [4u32, 2, 6, 7].map(BigUint::from));
+    /// ```
+    pub fn continued_fraction(numerator: &BigUint, denominator: &BigUint) -> Vec<BigUint> {
+        if denominator.is_zero() {
+            panic!("attempt to divide by zero")
+        }
+        let mut coefficients = Vec::new();
+        let (mut p, mut q) = (numerator.clone(), denominator.clone());
+        while !q.is_zero() {
+            let (a, r) = p.div_rem(&q);
+            coefficients.push(a);
+            p = q;
+            q = r;
+        }
+        coefficients
+    }
+
+    /// Returns the numerator and denominator of the continued fraction `[a0; a1, a2, ...]` with
+    /// these `coefficients`, in lowest terms.
+    ///
+    /// This evaluates the convergents `p(k) / q(k)` with `p(k) = a(k) p(k-1) + p(k-2)`, and the
+    /// same for `q`, starting from `p(-1) / q(-1) = 1 / 0` and `p(-2) / q(-2) = 0 / 1`. The result
+    /// is the last convergent, so no coefficients gives `(1, 0)`. It inverts
+    /// [`BigUint::continued_fraction`], and also accepts non-canonical coefficients. To get the
+    /// convergents before the last too, use [`ConvergentIter`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// // √2 ≈ [1; 2, 2, 2, 2, 2] = 99/70
+    /// let (p, q) = BigUint::from_continued_fraction(&[1u32, 2, 2, 2, 2, 2].map(BigUint::from));
+    /// assert_eq!((p, q), (BigUint::from(99u32), BigUint::from(70u32)));
+    /// ```
+    pub fn from_continued_fraction(coefficients: &[BigUint]) -> (BigUint, BigUint) {
+        ConvergentIter::new(coefficients)
+            .last()
+            .unwrap_or_else(|| (BigUint::one(), BigUint::zero()))
+    }
+}
+
+/// An iterator of the convergents `p(k) / q(k)` of a continued fraction `[a0; a1, a2, ...]`, as
+/// `(p(k), q(k))` pairs in lowest terms, one for each coefficient.
+///
+/// Each convergent is found from the last two by `p(k) = a(k) p(k-1) + p(k-2)`, and the same for
+/// `q`. For a continued fraction from [`BigUint::continued_fraction`], the last convergent is the
+/// fraction itself, and those before it are its best rational approximations with smaller
+/// denominators. The coefficients may come from a slice, or from any iterator of [`BigUint`]s,
+/// even an endless one.
+///
+/// ```
+/// use num_bigint::{BigUint, ConvergentIter};
+///
+/// // π ≈ 3.14159265 = [3; 7, 15, 1, 292, ...]
+/// let pi = [3u32, 7, 15, 1, 292].map(BigUint::from);
+/// let convergents: Vec<(BigUint, BigUint)> = ConvergentIter::new(&pi).collect();
+/// let expected = [(3u32, 1u32), (22, 7), (333, 106), (355, 113), (103993, 33102)];
+/// assert_eq!(convergents, expected.map(|(p, q)| (p.into(), q.into())));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ConvergentIter<I> {
+    coefficients: I,
+    // The last two convergents, `p(k-1) / q(k-1)` and `p(k-2) / q(k-2)`.
+    p: BigUint,
+    p_prev: BigUint,
+    q: BigUint,
+    q_prev: BigUint,
+}
+
+impl<I: Iterator> ConvergentIter<I>
+where
+    I::Item: Borrow<BigUint>,
+{
+    /// Creates an iterator of the convergents of the continued fraction with these
+    /// `coefficients`.
+    pub fn new<C>(coefficients: C) -> Self
+    where
+        C: IntoIterator<IntoIter = I>,
+    {
+        ConvergentIter {
+            coefficients: coefficients.into_iter(),
+            p: BigUint::one(),
+            p_prev: BigUint::zero(),
+            q: BigUint::zero(),
+            q_prev: BigUint::one(),
+        }
+    }
+
+    /// Moves on to the convergent with the next coefficient, returning `false` if there is none.
+    fn advance(&mut self) -> bool {
+        match self.coefficients.next() {
+            Some(a) => {
+                let a = a.borrow();
+                self.p_prev = a.mul_add(&self.p, &self.p_prev);
+                self.q_prev = a.mul_add(&self.q, &self.q_prev);
+                mem::swap(&mut self.p, &mut self.p_prev);
+                mem::swap(&mut self.q, &mut self.q_prev);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl<I: Iterator> Iterator for ConvergentIter<I>
+where
+    I::Item: Borrow<BigUint>,
+{
+    type Item = (BigUint, BigUint);
+
+    fn next(&mut self) -> Option<(BigUint, BigUint)> {
+        if self.advance() {
+            Some((self.p.clone(), self.q.clone()))
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.coefficients.size_hint()
+    }
+
+    fn last(mut self) -> Option<(BigUint, BigUint)> {
+        // Skip the copies of the convergents before the last.
+        if !self.advance() {
+            return None;
+        }
+        while self.advance() {}
+        Some((self.p, self.q))
+    }
+}
+
+impl<I: ExactSizeIterator> ExactSizeIterator for ConvergentIter<I> where I::Item: Borrow<BigUint> {}
+
+impl<I: FusedIterator> FusedIterator for ConvergentIter<I> where I::Item: Borrow<BigUint> {}
@@ -4,7 +4,7 @@
 use super::{biguint_from_vec, BigUint, ToBigUint};
 
 use super::addition::add2;
-use super::division::{div_rem_digit, FAST_DIV_WIDE};
+use super::division::{div_rem_barrett, div_rem_digit, reciprocal, FAST_DIV_WIDE};
 use super::multiplication::mac_with_carry;
 
 use crate::big_digit::{self, BigDigit};
@@ -12,13 +12,14 @@ use crate::ParseBigIntError;
 use crate::TryFromBigIntError;
 
 use alloc::vec::Vec;
-use core::cmp::Ordering::{Equal, Greater, Less};
+use core::cmp;
+use core::cmp::Ordering::{self, Equal, Greater, Less};
 use core::convert::TryFrom;
 use core::mem;
 use core::str::FromStr;
 use num_integer::{Integer, Roots};
 use num_traits::float::FloatCore;
-use num_traits::{FromPrimitive, Num, One, PrimInt, ToPrimitive, Zero};
+use num_traits::{FromPrimitive, Num, One, Pow, PrimInt, ToPrimitive, Zero};
 
 /// Find last set bit
 /// fls(0) == 0, fls(u32::MAX) == 32
@@ -221,35 +222,37 @@ impl Num for BigUint {
     fn from_str_radix(s: &str, radix: u32) -> Result<BigUint, ParseBigIntError> {
         assert!(2 <= radix && radix <= 36, "The radix must be within 2...36");
         let mut s = s;
+        let mut start = 0;
         if let Some(tail) = s.strip_prefix('+') {
             if !tail.starts_with('+') {
-                s = tail
+                s = tail;
+                start = 1;
             }
         }
 
         if s.is_empty() {
-            return Err(ParseBigIntError::empty());
+            return Err(ParseBigIntError::empty(start));
         }
 
         if s.starts_with('_') {
             // Must lead with a real digit!
-            return Err(ParseBigIntError::invalid());
+            return Err(ParseBigIntError::invalid(start));
         }
 
         // First normalize all characters to plain digit values
         let mut v = Vec::with_capacity(s.len());
-        for b in s.bytes() {
+        for (i, b) in s.bytes().enumerate() {
             let d = match b {
                 b'0'..=b'9' => b - b'0',
                 b'a'..=b'z' => b - b'a' + 10,
                 b'A'..=b'Z' => b - b'A' + 10,
                 b'_' => continue,
-                _ => u8::MAX,
+                _ => return Err(ParseBigIntError::invalid(start + i)),
             };
             if d < radix as u8 {
                 v.push(d);
             } else {
-                return Err(ParseBigIntError::invalid());
+                return Err(ParseBigIntError::out_of_range(start + i));
             }
         }
 
@@ -269,6 +272,69 @@ impl Num for BigUint {
     }
 }
 
+/// Parses an optional `+`, then a `0x`, `0o` or `0b` radix prefix or none for decimal, then
+/// the digits. Underscores are allowed between and after the digits, as in Rust literals, only
+/// if `underscores` is set.
+pub(super) fn from_str_prefixed(s: &str, underscores: bool) -> Result<BigUint, ParseBigIntError> {
+    let mut start = if s.starts_with('+') { 1 } else { 0 };
+    let (radix, prefix_len) = match s.get(start..start + 2) {
+        Some("0x") | Some("0X") => (16, 2),
+        Some("0o") | Some("0O") => (8, 2),
+        Some("0b") | Some("0B") => (2, 2),
+        _ => (10, 0),
+    };
+    start += prefix_len;
+
+    let mut digits = &s[start..];
+    if underscores {
+        if prefix_len != 0 {
+            // Rust allows `0x_ff`, but not `_1` without a prefix.
+            let trimmed = digits.trim_start_matches('_');
+            start += digits.len() - trimmed.len();
+            digits = trimmed;
+        }
+    } else if let Some(i) = digits.find('_') {
+        return Err(ParseBigIntError::invalid(start + i));
+    }
+
+    if digits.starts_with('+') {
+        // `from_str_radix` would allow another sign here.
+        return Err(ParseBigIntError::invalid(start));
+    }
+    BigUint::from_str_radix(digits, radix).map_err(|e| e.shifted(start))
+}
+
+/// Parses an optional `+`, then an optional `0` and `prefix` radix prefix in either case, then
+/// the digits as for `from_str_radix`.
+pub(super) fn from_str_radix_prefix(
+    s: &str,
+    radix: u32,
+    prefix: u8,
+) -> Result<BigUint, ParseBigIntError> {
+    let mut start = if s.starts_with('+') { 1 } else { 0 };
+    let bytes = s.as_bytes();
+    if bytes.get(start) == Some(&b'0')
+        && bytes.get(start + 1).map(u8::to_ascii_lowercase) == Some(prefix)
+    {
+        start += 2;
+    }
+
+    let digits = &s[start..];
+    if digits.starts_with('+') {
+        // `from_str_radix` would allow another sign here.
+        return Err(ParseBigIntError::invalid(start));
+    }
+    BigUint::from_str_radix(digits, radix).map_err(|e| e.shifted(start))
+}
+
+/// Parses as `from_str_radix` after trimming whitespace from both ends, with error positions
+/// still counted in the untrimmed input.
+pub(super) fn from_str_lax(s: &str, radix: u32) -> Result<BigUint, ParseBigIntError> {
+    let trimmed = s.trim_start();
+    let start = s.len() - trimmed.len();
+    BigUint::from_str_radix(trimmed.trim_end(), radix).map_err(|e| e.shifted(start))
+}
+
 fn high_bits_to_u64(v: &BigUint) -> u64 {
     match v.data.len() {
         0 => 0,
@@ -456,6 +522,9 @@ impl FromPrimitive for BigUint {
         Some(BigUint::from(n))
     }
 
+    /// Converts a finite float by truncating it toward zero, like an `as` cast to a primitive
+    /// integer, so values in `(-1, 0]` become zero. See [`BigUint::from_f64_floor`] and its
+    /// siblings for other rounding.
     #[inline]
     fn from_f64(mut n: f64) -> Option<BigUint> {
         // handle NAN, INFINITY, NEG_INFINITY
@@ -487,6 +556,134 @@ impl FromPrimitive for BigUint {
     }
 }
 
+impl BigUint {
+    /// Converts a float to the integer toward zero, the same as [`FromPrimitive::from_f64`].
+    ///
+    /// Returns `None` if `f` is NaN or infinite, or if the rounded value is negative. This holds
+    /// for each of the rounding conversions, so for example `-0.5` converts to zero here and
+    /// with [`from_f64_ceil`][Self::from_f64_ceil], but not with
+    /// [`from_f64_floor`][Self::from_f64_floor].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::from_f64_trunc(2.7), Some(BigUint::from(2u32)));
+    /// assert_eq!(BigUint::from_f64_trunc(-0.5), Some(BigUint::ZERO));
+    /// assert_eq!(BigUint::from_f64_trunc(-1.0), None);
+    /// assert_eq!(BigUint::from_f64_trunc(f64::NAN), None);
+    /// ```
+    #[inline]
+    pub fn from_f64_trunc(f: f64) -> Option<BigUint> {
+        FromPrimitive::from_f64(f)
+    }
+
+    /// Converts a float to the greatest integer less than or equal to it.
+    ///
+    /// Returns `None` if `f` is NaN, infinite, or negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::from_f64_floor(2.7), Some(BigUint::from(2u32)));
+    /// assert_eq!(BigUint::from_f64_floor(-0.5), None);
+    /// ```
+    #[inline]
+    pub fn from_f64_floor(f: f64) -> Option<BigUint> {
+        FromPrimitive::from_f64(FloatCore::floor(f))
+    }
+
+    /// Converts a float to the least integer greater than or equal to it.
+    ///
+    /// Returns `None` if `f` is NaN or infinite, or if it's not greater than `-1.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::from_f64_ceil(2.1), Some(BigUint::from(3u32)));
+    /// assert_eq!(BigUint::from_f64_ceil(-0.5), Some(BigUint::ZERO));
+    /// ```
+    #[inline]
+    pub fn from_f64_ceil(f: f64) -> Option<BigUint> {
+        FromPrimitive::from_f64(FloatCore::ceil(f))
+    }
+
+    /// Converts a float to the nearest integer, rounding ties to even.
+    ///
+    /// Returns `None` if `f` is NaN or infinite, or if it rounds to a negative value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::from_f64_round(2.5), Some(BigUint::from(2u32)));
+    /// assert_eq!(BigUint::from_f64_round(3.5), Some(BigUint::from(4u32)));
+    /// assert_eq!(BigUint::from_f64_round(2.6), Some(BigUint::from(3u32)));
+    /// assert_eq!(BigUint::from_f64_round(-0.5), Some(BigUint::ZERO));
+    /// ```
+    pub fn from_f64_round(f: f64) -> Option<BigUint> {
+        // `round` breaks ties away from zero, so step back from odd results of a tie.
+        let mut rounded = FloatCore::round(f);
+        if FloatCore::abs(rounded - f) == 0.5 && FloatCore::fract(rounded / 2.0) != 0.0 {
+            rounded -= FloatCore::signum(f);
+        }
+        FromPrimitive::from_f64(rounded)
+    }
+
+    /// Converts to the nearest `f64`, like [`ToPrimitive::to_f64`], along with how that compares
+    /// to the exact value: `Less` if it was rounded down, `Greater` if it was rounded up, and
+    /// `Equal` if it's exact.
+    ///
+    /// Values too large for `f64` become infinity, which is `Greater`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    /// use std::cmp::Ordering;
+    ///
+    /// let n = BigUint::from(u64::MAX);
+    /// assert_eq!(n.to_f64_with_rounding(), (18446744073709551616.0, Ordering::Greater));
+    ///
+    /// let n = BigUint::from((1u64 << 53) + 1);
+    /// assert_eq!(n.to_f64_with_rounding(), (9007199254740992.0, Ordering::Less));
+    ///
+    /// let n = BigUint::from(1u32) << 1000u32;
+    /// assert_eq!(n.to_f64_with_rounding(), (2f64.powi(1000), Ordering::Equal));
+    /// ```
+    pub fn to_f64_with_rounding(&self) -> (f64, Ordering) {
+        let f = self.to_f64().unwrap();
+        let ordering = match BigUint::from_f64(f) {
+            Some(rounded) => rounded.cmp(self),
+            None => Greater,
+        };
+        (f, ordering)
+    }
+
+    /// Converts to an `f64` only if it represents the value exactly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::from(1u64 << 53).to_f64_exact(), Some(9007199254740992.0));
+    /// assert_eq!(BigUint::from((1u64 << 53) + 1).to_f64_exact(), None);
+    /// ```
+    pub fn to_f64_exact(&self) -> Option<f64> {
+        match self.to_f64_with_rounding() {
+            (f, Equal) => Some(f),
+            _ => None,
+        }
+    }
+}
+
 impl From<u64> for BigUint {
     #[inline]
     fn from(mut n: u64) -> Self {
@@ -747,6 +944,86 @@ pub(super) fn to_radix_digits_le(u: &BigUint, radix: u32) -> Vec<u8> {
     res
 }
 
+/// Numbers with at least this many digits are converted to decimal by divide and conquer.
+const DECIMAL_DC_THRESHOLD: usize = 32;
+
+/// Divisors with at least this many digits use a precomputed reciprocal, where two
+/// multiplications beat long division even counting the cost of the reciprocal.
+const DECIMAL_BARRETT_THRESHOLD: usize = 512;
+
+/// Converts to little-endian decimal digits by splitting on powers of `10^19` (or `10^9` with
+/// 32-bit digits), in the manner of GMP's subquadratic `mpn_get_str`.
+///
+/// Each power is the square of the one below, so every split divides a number by roughly its
+/// square root, and the largest divisions use reciprocals to run at the speed of multiplication.
+fn to_decimal_digits_le(u: &BigUint) -> Vec<u8> {
+    debug_assert!(!u.is_zero());
+
+    let (base, power) = get_radix_base(10);
+    let bits = u.bits();
+
+    // Every power that is used is at most `u`, and every number passed down at level `i` is less
+    // than the square of `powers[i].0`.
+    let mut powers = Vec::new();
+    let mut p = BigUint::from(base);
+    let mut digits = power;
+    while p <= *u {
+        let next = if 2 * p.bits() - 1 > bits {
+            None
+        } else {
+            Some(&p * &p)
+        };
+        let mu = if p.data.len() >= DECIMAL_BARRETT_THRESHOLD {
+            Some(reciprocal(&p))
+        } else {
+            None
+        };
+        powers.push((p, mu, digits));
+        match next {
+            Some(next) => p = next,
+            None => break,
+        }
+        digits *= 2;
+    }
+
+    // log10(2) is a little less than a third.
+    let mut res = Vec::with_capacity(bits as usize / 3 + 1);
+    decimal_dc(u, &powers, 0, &mut res);
+    res
+}
+
+/// Appends the decimal digits of `u` to `res`, padded with zeros to at least `pad` digits.
+fn decimal_dc(
+    u: &BigUint,
+    powers: &[(BigUint, Option<BigUint>, usize)],
+    pad: usize,
+    res: &mut Vec<u8>,
+) {
+    let start = res.len();
+    match powers.split_last() {
+        Some(((p, mu, digits), rest)) if u.data.len() >= DECIMAL_DC_THRESHOLD / 2 => {
+            if u < p {
+                decimal_dc(u, rest, pad, res);
+            } else {
+                let (q, r) = match mu {
+                    Some(mu) => div_rem_barrett(u, p, mu),
+                    None => u.div_rem(p),
+                };
+                decimal_dc(&r, rest, *digits, res);
+                decimal_dc(&q, rest, pad.saturating_sub(*digits), res);
+            }
+        }
+        _ => {
+            if !u.is_zero() {
+                res.extend(to_radix_digits_le(u, 10));
+            }
+            if res.len() - start < pad {
+                res.resize(start + pad, 0);
+            }
+        }
+    }
+}
+
 pub(super) fn to_radix_le(u: &BigUint, radix: u32) -> Vec<u8> {
     if u.is_zero() {
         vec![0]
@@ -758,6 +1035,8 @@ pub(super) fn to_radix_le(u: &BigUint, radix: u32) -> Vec<u8> {
         } else {
             to_inexact_bitwise_digits_le(u, bits)
         }
+    } else if radix == 10 && u.data.len() >= DECIMAL_DC_THRESHOLD {
+        to_decimal_digits_le(u)
     } else if radix == 10 {
         // 10 is so common that it's worth separating out for const-propagation.
         // Optimizers can often turn constant division into a faster multiplication.
@@ -767,6 +1046,49 @@ pub(super) fn to_radix_le(u: &BigUint, radix: u32) -> Vec<u8> {
     }
 }
 
+/// Returns the digits of `u` in any radix of at least two, least significant first, with a
+/// single zero digit for zero.
+pub(super) fn to_u32_radix_le(u: &BigUint, radix: u32) -> Vec<u32> {
+    debug_assert!(radix >= 2);
+    if radix <= 256 {
+        return to_radix_le(u, radix).into_iter().map(u32::from).collect();
+    }
+    if u.is_zero() {
+        return vec![0];
+    }
+
+    // Divide by the largest power of the radix that fits, as in `to_radix_digits_le`.
+    let max = if FAST_DIV_WIDE {
+        big_digit::MAX
+    } else {
+        big_digit::HALF
+    };
+    let radix = BigDigit::from(radix);
+    let (mut base, mut power) = (radix, 1);
+    while let Some(b) = base.checked_mul(radix).filter(|&b| b <= max) {
+        base = b;
+        power += 1;
+    }
+
+    let mut res = Vec::new();
+    let mut digits = u.clone();
+    while digits.data.len() > 1 {
+        let (q, mut r) = div_rem_digit(digits, base);
+        for _ in 0..power {
+            res.push((r % radix).to_u32().unwrap());
+            r /= radix;
+        }
+        digits = q;
+    }
+
+    let mut r = digits.data[0];
+    while r != 0 {
+        res.push((r % radix).to_u32().unwrap());
+        r /= radix;
+    }
+    res
+}
+
 pub(crate) fn to_str_radix_reversed(u: &BigUint, radix: u32) -> Vec<u8> {
     assert!(2 <= radix && radix <= 36, "The radix must be within 2...36");
 
@@ -788,6 +1110,48 @@ pub(crate) fn to_str_radix_reversed(u: &BigUint, radix: u32) -> Vec<u8> {
     res
 }
 
+/// Returns the number of digits of `u` in the given radix, the length of its string without
+/// formatting it.
+pub(super) fn digit_count(u: &BigUint, radix: u32) -> usize {
+    assert!(2 <= radix && radix <= 36, "The radix must be within 2...36");
+
+    if radix.is_power_of_two() {
+        let bits = u64::from(ilog2(radix));
+        return cmp::max(1, Integer::div_ceil(&u.bits(), &bits)) as usize;
+    }
+
+    // Strip off a lower bound of the digits at a time, since `⌊u / radixᵏ⌋` has exactly `k` fewer
+    // digits for any `radixᵏ ≤ u`, until what's left fits in a `u64` to count directly.
+    let mut count = 0;
+    let mut rest = None;
+    loop {
+        let n = rest.as_ref().unwrap_or(u);
+        if let Some(mut x) = n.to_u64() {
+            count += 1;
+            while x >= u64::from(radix) {
+                x /= u64::from(radix);
+                count += 1;
+            }
+            return count;
+        }
+
+        // `n ≥ 2ᵇ⁻¹`, so `⌊(b - 1) / log₂(radix)⌋` is a lower bound on `⌊log_radix(n)⌋`. With `std`,
+        // one less than the floating point estimate leaves room for rounding errors.
+        #[cfg(feature = "std")]
+        let k = {
+            let radix_log2 = f64::from(radix).log2();
+            ((n.bits() - 1) as f64 / radix_log2) as usize - 1
+        };
+        #[cfg(not(feature = "std"))]
+        let k = {
+            let radix_log2 = u64::from(ilog2(radix.next_power_of_two()));
+            ((n.bits() - 1) / radix_log2) as usize
+        };
+        rest = Some(n / BigUint::from(radix).pow(k));
+        count += k;
+    }
+}
+
 /// Returns the greatest power of the radix for the `BigDigit` bit size
 #[inline]
 fn get_radix_base(radix: u32) -> (BigDigit, usize) {
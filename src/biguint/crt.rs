@@ -0,0 +1,110 @@
+//! The Chinese Remainder Theorem, by Garner's algorithm
+
+use super::BigUint;
+
+use alloc::vec::Vec;
+
+impl BigUint {
+    /// Returns the unique `x` in `0..m` with `x ≡ residues[i] (mod moduli[i])` for every `i`,
+    /// where `m` is the product of the moduli, or `None` if the slices have different lengths or
+    /// the moduli are not pairwise coprime. Panics if a modulus is zero.
+    ///
+    /// This is Garner's algorithm, which builds `x` one modulus at a time in mixed radix, so it
+    /// only needs modular inverses smaller than each modulus. To solve many systems with the same
+    /// moduli, compute [`BigUint::garner_coefficients`] once and use
+    /// [`BigUint::crt_with_coefficients`].
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let moduli = [3u32, 5, 7].map(BigUint::from);
+    /// let residues = [2u32, 3, 2].map(BigUint::from);
+    /// assert_eq!(BigUint::crt(&residues, &moduli), Some(BigUint::from(23u32)));
+    ///
+    /// let moduli = [4u32, 6].map(BigUint::from);
+    /// let residues = [1u32, 1].map(BigUint::from);
+    /// assert_eq!(BigUint::crt(&residues, &moduli), None);
+    /// ```
+    pub fn crt(residues: &[BigUint], moduli: &[BigUint]) -> Option<BigUint> {
+        if residues.len() != moduli.len() {
+            return None;
+        }
+        let coefficients = Self::garner_coefficients(moduli)?;
+        Some(Self::crt_with_coefficients(residues, moduli, &coefficients))
+    }
+
+    /// Returns the coefficients for Garner's algorithm with these moduli, or `None` if they are
+    /// not pairwise coprime. Panics if a modulus is zero.
+    ///
+    /// The coefficient `i` is the inverse of `moduli[0] * ... * moduli[i - 1]` modulo
+    /// `moduli[i]`. They only depend on the moduli, so they can be reused with
+    /// [`BigUint::crt_with_coefficients`] for any residues.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let moduli = [3u32, 5, 7].map(BigUint::from);
+    /// let coefficients = BigUint::garner_coefficients(&moduli).unwrap();
+    /// // 3⁻¹ = 2 (mod 5) and 15⁻¹ = 1 (mod 7)
+    /// assert_eq!(coefficients, [1u32, 2, 1].map(BigUint::from));
+    /// ```
+    pub fn garner_coefficients(moduli: &[BigUint]) -> Option<Vec<BigUint>> {
+        let mut product = BigUint::from(1u32);
+        let mut coefficients = Vec::with_capacity(moduli.len());
+        for m in moduli {
+            coefficients.push(product.modinv(m)?);
+            product *= m;
+        }
+        Some(coefficients)
+    }
+
+    /// Returns the unique `x` in `0..m` with `x ≡ residues[i] (mod moduli[i])` for every `i`,
+    /// where `m` is the product of the moduli, using `coefficients` from
+    /// [`BigUint::garner_coefficients`] for the same moduli. Panics if the slices have different
+    /// lengths.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let moduli = [3u32, 5, 7].map(BigUint::from);
+    /// let coefficients = BigUint::garner_coefficients(&moduli).unwrap();
+    /// for x in 0u32..105 {
+    ///     let residues = [x % 3, x % 5, x % 7].map(BigUint::from);
+    ///     let y = BigUint::crt_with_coefficients(&residues, &moduli, &coefficients);
+    ///     assert_eq!(y, BigUint::from(x));
+    /// }
+    /// ```
+    pub fn crt_with_coefficients(
+        residues: &[BigUint],
+        moduli: &[BigUint],
+        coefficients: &[BigUint],
+    ) -> BigUint {
+        assert_eq!(
+            residues.len(),
+            moduli.len(),
+            "residues and moduli have different lengths"
+        );
+        assert_eq!(
+            coefficients.len(),
+            moduli.len(),
+            "coefficients and moduli have different lengths"
+        );
+
+        // x = v[0] + v[1] m[0] + v[2] m[0] m[1] + ..., with each digit v[i] in 0..m[i].
+        let mut x = BigUint::ZERO;
+        let mut product = BigUint::from(1u32);
+        for ((r, m), c) in residues.iter().zip(moduli).zip(coefficients) {
+            let r = r % m;
+            let x_mod_m = &x % m;
+            let diff = if r >= x_mod_m {
+                r - x_mod_m
+            } else {
+                r + m - x_mod_m
+            };
+            let v = diff * c % m;
+            x += &product * v;
+            product *= m;
+        }
+        x
+    }
+}
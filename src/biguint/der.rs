@@ -0,0 +1,115 @@
+//! ASN.1 DER `INTEGER`s, as used for RSA keys in X.509, TLS and SSH
+
+use super::BigUint;
+use crate::{DerError, DerErrorKind};
+
+use alloc::vec::Vec;
+use core::mem;
+
+/// The tag of a universal, primitive `INTEGER`.
+const TAG_INTEGER: u8 = 0x02;
+
+/// Returns the DER `INTEGER` with the given minimal two's-complement contents.
+pub(crate) fn encode_der_integer(contents: &[u8]) -> Vec<u8> {
+    let len = contents.len();
+    let mut der = Vec::with_capacity(len + 2 + mem::size_of::<usize>());
+    der.push(TAG_INTEGER);
+    if len < 0x80 {
+        der.push(len as u8);
+    } else {
+        // The long form: the number of length bytes, then the length in big-endian.
+        let len_bytes = len.to_be_bytes();
+        let skip = len_bytes.iter().take_while(|&&b| b == 0).count();
+        der.push(0x80 | (len_bytes.len() - skip) as u8);
+        der.extend_from_slice(&len_bytes[skip..]);
+    }
+    der.extend_from_slice(contents);
+    der
+}
+
+/// Returns the two's-complement contents of the DER `INTEGER` that makes up all of `der`,
+/// checking that they are minimal.
+pub(crate) fn decode_der_integer(der: &[u8]) -> Result<&[u8], DerError> {
+    let err = DerError::new;
+    let (&tag, rest) = der.split_first().ok_or(err(DerErrorKind::Truncated))?;
+    if tag != TAG_INTEGER {
+        return Err(err(DerErrorKind::UnexpectedTag));
+    }
+
+    let (&first, mut rest) = rest.split_first().ok_or(err(DerErrorKind::Truncated))?;
+    let len = if first < 0x80 {
+        usize::from(first)
+    } else {
+        let count = usize::from(first & 0x7f);
+        if count == 0 || count > mem::size_of::<usize>() {
+            // The indefinite form isn't allowed in DER, and other lengths can't fit in memory.
+            return Err(err(DerErrorKind::InvalidLength));
+        }
+        if rest.len() < count {
+            return Err(err(DerErrorKind::Truncated));
+        }
+        let (len_bytes, tail) = rest.split_at(count);
+        rest = tail;
+        let len = len_bytes
+            .iter()
+            .fold(0usize, |len, &b| len << 8 | usize::from(b));
+        // The long form must be shortest, and only used when the short form can't be.
+        if len_bytes[0] == 0 || len < 0x80 {
+            return Err(err(DerErrorKind::InvalidLength));
+        }
+        len
+    };
+
+    if rest.len() < len {
+        return Err(err(DerErrorKind::Truncated));
+    }
+    if rest.len() > len {
+        return Err(err(DerErrorKind::TrailingData));
+    }
+    match rest {
+        [] => Err(err(DerErrorKind::NonMinimal)),
+        [0x00, next, ..] | [0xff, next, ..] if (rest[0] ^ next) & 0x80 == 0 => {
+            Err(err(DerErrorKind::NonMinimal))
+        }
+        _ => Ok(rest),
+    }
+}
+
+impl BigUint {
+    /// Returns the ASN.1 DER encoding of `self` as an `INTEGER`: the tag `0x02`, the length of
+    /// the contents, and the contents, which are the minimal two's-complement big-endian bytes.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::from(0u32).to_der_integer(), [0x02, 0x01, 0x00]);
+    /// assert_eq!(BigUint::from(127u32).to_der_integer(), [0x02, 0x01, 0x7f]);
+    /// assert_eq!(BigUint::from(128u32).to_der_integer(), [0x02, 0x02, 0x00, 0x80]);
+    /// ```
+    pub fn to_der_integer(&self) -> Vec<u8> {
+        let mut contents = self.to_bytes_be();
+        if contents[0] & 0x80 != 0 {
+            contents.insert(0, 0);
+        }
+        encode_der_integer(&contents)
+    }
+
+    /// Decodes a non-negative ASN.1 DER `INTEGER`, which must make up all of `der`.
+    ///
+    /// Only the distinguished encoding is accepted, so the length and contents must be minimal.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let der = [0x02, 0x03, 0x01, 0x00, 0x01];
+    /// assert_eq!(BigUint::from_der_integer(&der), Ok(BigUint::from(65537u32)));
+    /// assert!(BigUint::from_der_integer(&der[..4]).is_err());
+    /// ```
+    pub fn from_der_integer(der: &[u8]) -> Result<BigUint, DerError> {
+        let contents = decode_der_integer(der)?;
+        if contents[0] & 0x80 != 0 {
+            return Err(DerError::new(DerErrorKind::Negative));
+        }
+        Ok(BigUint::from_bytes_be(contents))
+    }
+}
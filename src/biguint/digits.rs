@@ -0,0 +1,128 @@
+//! Borrowed access to the digits of a `BigUint`, in their native size
+
+use super::BigUint;
+
+use alloc::vec::Vec;
+use core::fmt;
+use core::mem;
+use core::ops::{Deref, DerefMut};
+
+/// The type of the digits that a [`BigUint`] is stored in, as borrowed by
+/// [`BigUint::as_digits`].
+///
+/// This is `u64` on targets with 64-bit pointers, and `u32` on others. Code that only converts
+/// it with `u64::from` and reads its size from `Digit::BITS` works the same on either.
+pub type Digit = crate::big_digit::BigDigit;
+
+/// A mutable borrow of the digits of a [`BigUint`], least significant digit first, as returned
+/// by [`BigUint::as_digits_mut`].
+///
+/// It dereferences to the digit slice. The number of digits can't change, but any of them can be
+/// set to zero, so the digits are normalized when they're put back in the [`BigUint`] on drop.
+/// Until then the [`BigUint`] holds zero, so it stays valid even if this is leaked.
+pub struct DigitsMut<'a> {
+    inner: &'a mut BigUint,
+    data: Vec<Digit>,
+}
+
+impl Deref for DigitsMut<'_> {
+    type Target = [Digit];
+
+    #[inline]
+    fn deref(&self) -> &[Digit] {
+        &self.data
+    }
+}
+
+impl DerefMut for DigitsMut<'_> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [Digit] {
+        &mut self.data
+    }
+}
+
+impl Drop for DigitsMut<'_> {
+    #[inline]
+    fn drop(&mut self) {
+        self.inner.data = mem::take(&mut self.data);
+        self.inner.normalize();
+    }
+}
+
+impl fmt::Debug for DigitsMut<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("DigitsMut").field(&&self.data[..]).finish()
+    }
+}
+
+impl BigUint {
+    /// Returns the digits of the [`BigUint`] ordered least significant digit first, borrowed
+    /// without copying. The slice is empty for zero, and otherwise its last digit is not zero.
+    ///
+    /// The digits are [`Digit`]s, which are `u64` or `u32` depending on the target. To get
+    /// digits of a fixed size on every target, use [`BigUint::iter_u32_digits`] or
+    /// [`BigUint::iter_u64_digits`], which convert them on the fly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::{BigUint, Digit};
+    ///
+    /// assert!(BigUint::from(0u32).as_digits().is_empty());
+    ///
+    /// let n = BigUint::from(1u128 << 127 | 5);
+    /// assert_eq!(n.as_digits().len(), 128 / Digit::BITS as usize);
+    /// assert_eq!(u64::from(n.as_digits()[0]), 5);
+    /// ```
+    #[inline]
+    pub fn as_digits(&self) -> &[Digit] {
+        &self.data
+    }
+
+    /// Returns the digits of the [`BigUint`] ordered least significant digit first, borrowed
+    /// mutably without copying. The [`BigUint`] is normalized when the returned [`DigitsMut`]
+    /// is dropped, so it stays valid if the top digits are set to zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let mut n = BigUint::from(1u128 << 100 | 5);
+    /// let mut digits = n.as_digits_mut();
+    /// let last = digits.len() - 1;
+    /// digits[last] = 0;
+    /// drop(digits);
+    /// assert_eq!(n, BigUint::from(5u32));
+    /// ```
+    #[inline]
+    pub fn as_digits_mut(&mut self) -> DigitsMut<'_> {
+        let data = mem::take(&mut self.data);
+        DigitsMut { inner: self, data }
+    }
+}
+
+#[test]
+fn test_digits_mut() {
+    let mut n = BigUint::from(3u32) << 200u32;
+    {
+        let mut digits = n.as_digits_mut();
+        let last = digits.len() - 1;
+        digits[last] = 0;
+        digits[0] = 7;
+    }
+    assert_eq!(n, BigUint::from(7u32));
+    assert_eq!(n.data.len(), 1);
+
+    let mut zero = BigUint::ZERO;
+    assert!(zero.as_digits_mut().is_empty());
+    assert_eq!(zero, BigUint::ZERO);
+
+    // A leaked borrow leaves zero behind, rather than digits that aren't normalized.
+    let mut n = BigUint::from(3u32) << 200u32;
+    let mut digits = n.as_digits_mut();
+    let last = digits.len() - 1;
+    digits[last] = 0;
+    mem::forget(digits);
+    assert_eq!(n, BigUint::ZERO);
+}
@@ -0,0 +1,69 @@
+//! Decimal formatting with grouped digits
+
+use super::BigUint;
+
+use core::fmt;
+
+/// Formats a [`BigUint`] or [`BigInt`][crate::BigInt] in decimal, with a separator between
+/// groups of digits, as returned by `BigUint::with_separator` or `BigInt::with_separator`.
+///
+/// It only borrows the integer, and the digits are produced each time it is formatted. The usual
+/// width, fill, alignment and sign flags apply to the grouped string as a whole.
+#[derive(Clone, Copy)]
+pub struct SeparatedDisplay<'a> {
+    magnitude: &'a BigUint,
+    negative: bool,
+    separator: char,
+    group: usize,
+}
+
+impl<'a> SeparatedDisplay<'a> {
+    pub(crate) fn new(
+        magnitude: &'a BigUint,
+        negative: bool,
+        separator: char,
+        group: usize,
+    ) -> Self {
+        assert!(group > 0, "The separator group size must not be zero");
+        SeparatedDisplay {
+            magnitude,
+            negative,
+            separator,
+            group,
+        }
+    }
+}
+
+impl fmt::Display for SeparatedDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let digits =
+            self.magnitude
+                .to_str_radix_options(10, false, Some((self.separator, self.group)));
+        f.pad_integral(!self.negative, "", &digits)
+    }
+}
+
+impl fmt::Debug for SeparatedDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl BigUint {
+    /// Returns a wrapper that formats `self` in decimal, with `separator` inserted between every
+    /// group of `group` digits, counting from the least significant digit.
+    ///
+    /// Panics if `group` is zero.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let n = BigUint::from(1234567u32);
+    /// assert_eq!(n.with_separator('_', 3).to_string(), "1_234_567");
+    /// assert_eq!(format!("{:>12}", n.with_separator(',', 3)), "   1,234,567");
+    /// assert_eq!(format!("{:?}", n.with_separator(' ', 4)), "123 4567");
+    /// ```
+    pub fn with_separator(&self, separator: char, group: usize) -> SeparatedDisplay<'_> {
+        SeparatedDisplay::new(self, false, separator, group)
+    }
+}
@@ -8,7 +8,7 @@ use core::cmp::Ordering::{Equal, Greater, Less};
 use core::mem;
 use core::ops::{Div, DivAssign, Rem, RemAssign};
 use num_integer::Integer;
-use num_traits::{CheckedDiv, CheckedEuclid, Euclid, One, ToPrimitive, Zero};
+use num_traits::{CheckedDiv, CheckedEuclid, Euclid, One, ToPrimitive};
 
 pub(super) const FAST_DIV_WIDE: bool = cfg!(any(target_arch = "x86", target_arch = "x86_64"));
 
@@ -107,7 +107,7 @@ pub(super) fn div_rem_digit(mut a: BigUint, b: BigDigit) -> (BigUint, BigDigit)
 }
 
 #[inline]
-fn rem_digit(a: &BigUint, b: BigDigit) -> BigDigit {
+pub(super) fn rem_digit(a: &BigUint, b: BigDigit) -> BigDigit {
     if b == 0 {
         panic!("attempt to divide by zero")
     }
@@ -342,6 +342,62 @@ fn div_rem_core(mut a: BigUint, b: &[BigDigit]) -> (BigUint, BigUint) {
     (q.normalized(), a)
 }
 
+/// Below this many bits, reciprocals are computed by long division.
+const RECIPROCAL_THRESHOLD: u64 = 32 * big_digit::BITS as u64;
+
+/// Returns `floor(4^m / d)`, where `m` is the bit length of `d`, for use with `div_rem_barrett`.
+///
+/// Large reciprocals are found by Newton's iteration from the reciprocal of the top half of `d`,
+/// so the cost is a few multiplications rather than a quadratic division.
+pub(super) fn reciprocal(d: &BigUint) -> BigUint {
+    debug_assert!(!d.is_zero());
+    let m = d.bits();
+    let one = BigUint::one() << (2 * m);
+    if m < RECIPROCAL_THRESHOLD {
+        return one / d;
+    }
+
+    // The reciprocal of the top `h` bits is good to about `h` bits, and one Newton step
+    // roughly doubles that, so a few extra bits leave an error of only a few units.
+    let h = m / 2 + 4;
+    let mut y = reciprocal(&(d >> (m - h))) << (m - h);
+    let dy = d * &y;
+    if dy <= one {
+        y += (&y * (&one - dy)) >> (2 * m);
+    } else {
+        y -= (&y * (dy - &one)) >> (2 * m);
+    }
+
+    // Fix the last few units of error.
+    let mut dy = d * &y;
+    while dy > one {
+        y -= 1u32;
+        dy -= d;
+    }
+    let mut r = one - dy;
+    while r >= *d {
+        y += 1u32;
+        r -= d;
+    }
+    y
+}
+
+/// Divides `x` by `d` using the precomputed `mu = reciprocal(d)`, which needs only two
+/// multiplications. The dividend must be less than `4^m`, where `m` is the bit length of `d`.
+pub(super) fn div_rem_barrett(x: &BigUint, d: &BigUint, mu: &BigUint) -> (BigUint, BigUint) {
+    let m = d.bits();
+    debug_assert!(x.bits() <= 2 * m);
+
+    // This estimate is at most two less than the true quotient.
+    let mut q = ((x >> (m - 1)) * mu) >> (m + 1);
+    let mut r = x - &q * d;
+    while r >= *d {
+        q += 1u32;
+        r -= d;
+    }
+    (q, r)
+}
+
 forward_val_ref_binop!(impl Div for BigUint, div);
 forward_ref_val_binop!(impl Div for BigUint, div);
 forward_val_assign!(impl DivAssign for BigUint, div_assign);
@@ -702,3 +758,21 @@ impl Euclid for BigUint {
         self.div_rem(v)
     }
 }
+
+#[test]
+fn test_reciprocal() {
+    let mut x = BigUint::from(3u32);
+    while x.bits() < 20_000 {
+        for d in [x.clone(), &x - 1u32, &x + 1u32] {
+            let m = d.bits();
+            let mu = reciprocal(&d);
+            assert_eq!(mu, (BigUint::one() << (2 * m)) / &d);
+
+            let max = (BigUint::one() << (2 * m)) - 1u32;
+            for n in [BigUint::ZERO, &d - 1u32, d.clone(), &d * &d - 1u32, max] {
+                assert_eq!(div_rem_barrett(&n, &d, &mu), div_rem_ref(&n, &d));
+            }
+        }
+        x = &x * &x * 7u32 + 1u32;
+    }
+}
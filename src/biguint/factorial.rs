@@ -0,0 +1,210 @@
+use super::BigUint;
+
+use core::iter::FusedIterator;
+
+/// Ranges of at most this many factors are multiplied directly.
+const PRODUCT_LEAF: u64 = 16;
+
+impl BigUint {
+    /// Returns the factorial `n!`, the product of the integers `1..=n`.
+    ///
+    /// The factors are multiplied in a balanced product tree, so the large multiplications have
+    /// operands of similar size, where the faster algorithms apply. This is much faster than
+    /// multiplying the factors one at a time for large `n`.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::factorial(0), BigUint::from(1u32));
+    /// assert_eq!(BigUint::factorial(20), BigUint::from(2_432_902_008_176_640_000u64));
+    /// assert_eq!(BigUint::factorial(100), (1u32..=100).product());
+    /// ```
+    pub fn factorial(n: u64) -> BigUint {
+        if n == 0 {
+            return BigUint::from(1u32);
+        }
+        range_product(1, n) * n
+    }
+
+    /// Returns the binomial coefficient `n` choose `k`, the number of ways to choose `k` items
+    /// from `n`, which is zero if `k > n`.
+    ///
+    /// This uses the multiplicative formula `∏ (n - i) / (i + 1)` for `i` in `0..k`, with exact
+    /// division at each step, rather than computing whole factorials.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::binomial(5, 2), BigUint::from(10u32));
+    /// assert_eq!(BigUint::binomial(5, 0), BigUint::from(1u32));
+    /// assert_eq!(BigUint::binomial(5, 6), BigUint::from(0u32));
+    /// assert_eq!(BigUint::binomial(100, 50).to_string(), "100891344545564193334812497256");
+    /// ```
+    pub fn binomial(n: u64, k: u64) -> BigUint {
+        if k > n {
+            return BigUint::ZERO;
+        }
+        // C(n, k) = C(n, n - k), so take the shorter product.
+        let k = k.min(n - k);
+        let mut result = BigUint::from(1u32);
+        for i in 0..k {
+            // The product of `i + 1` consecutive integers is divisible by `(i + 1)!`.
+            result *= n - i;
+            result /= i + 1;
+        }
+        result
+    }
+
+    /// Returns the Catalan number `C(n) = binomial(2n, n) / (n + 1)`, which counts, among other
+    /// things, the balanced strings of `n` pairs of parentheses.
+    ///
+    /// To get the Catalan numbers in order, [`CatalanIter`] is faster.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::catalan(0), BigUint::from(1u32));
+    /// assert_eq!(BigUint::catalan(5), BigUint::from(42u32));
+    /// assert_eq!(BigUint::catalan(10), BigUint::from(16796u32));
+    /// ```
+    pub fn catalan(n: u64) -> BigUint {
+        let two_n = n.checked_mul(2).expect("catalan number index is too large");
+        BigUint::binomial(two_n, n) / (n + 1)
+    }
+}
+
+/// An endless iterator of the Catalan numbers `C(0), C(1), C(2), ...`, each found from the last
+/// by the recurrence `C(n + 1) = C(n) · 2(2n + 1) / (n + 2)`.
+///
+/// ```
+/// use num_bigint::{BigUint, CatalanIter};
+///
+/// let c: Vec<BigUint> = CatalanIter::new().take(6).collect();
+/// assert_eq!(c, [1u32, 1, 2, 5, 14, 42].map(BigUint::from));
+/// ```
+#[derive(Debug, Clone)]
+pub struct CatalanIter {
+    n: u64,
+    next: BigUint,
+}
+
+impl CatalanIter {
+    /// Creates an iterator starting from `C(0) = 1`.
+    pub fn new() -> Self {
+        CatalanIter {
+            n: 0,
+            next: BigUint::from(1u32),
+        }
+    }
+}
+
+impl Default for CatalanIter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Iterator for CatalanIter {
+    type Item = BigUint;
+
+    fn next(&mut self) -> Option<BigUint> {
+        let c = self.next.clone();
+        let n = self.n;
+        self.next *= 2 * n + 1;
+        self.next <<= 1u8;
+        self.next /= n + 2;
+        self.n += 1;
+        Some(c)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl FusedIterator for CatalanIter {}
+
+/// Returns the product of the integers in `start..end`.
+fn range_product(start: u64, end: u64) -> BigUint {
+    if end - start <= PRODUCT_LEAF {
+        // Gather factors in a `u64` while they fit, to save on big multiplications.
+        let mut product = BigUint::from(1u32);
+        let mut word = 1u64;
+        for k in start..end {
+            match word.checked_mul(k) {
+                Some(w) => word = w,
+                None => {
+                    product *= word;
+                    word = k;
+                }
+            }
+        }
+        product * word
+    } else {
+        let mid = start + (end - start) / 2;
+        range_product(start, mid) * range_product(mid, end)
+    }
+}
+
+#[test]
+fn test_factorial() {
+    let mut expected = BigUint::from(1u32);
+    for n in 0u64..2000 {
+        if n > 0 {
+            expected *= n;
+        }
+        assert_eq!(BigUint::factorial(n), expected, "{}!", n);
+    }
+
+    // Factors that don't fit together in a `u64`
+    let n = u64::MAX;
+    let expected = BigUint::from(n - 2) * (n - 1) * n;
+    assert_eq!(range_product(n - 2, n) * n, expected);
+    let big = 1u64 << 40;
+    let expected: BigUint = (big..big + 100).map(BigUint::from).product();
+    assert_eq!(range_product(big, big + 100), expected);
+}
+
+#[test]
+fn test_binomial() {
+    // Pascal's triangle
+    let mut row = alloc::vec![BigUint::from(1u32)];
+    for n in 0u64..300 {
+        for (k, c) in row.iter().enumerate() {
+            assert_eq!(&BigUint::binomial(n, k as u64), c, "C({}, {})", n, k);
+        }
+        assert!(BigUint::binomial(n, n + 1).is_zero());
+        let mut next = alloc::vec![BigUint::from(1u32)];
+        next.extend(row.windows(2).map(|w| &w[0] + &w[1]));
+        next.push(BigUint::from(1u32));
+        row = next;
+    }
+
+    let expected = BigUint::factorial(1000) / BigUint::factorial(500).pow(2);
+    assert_eq!(BigUint::binomial(1000, 500), expected);
+    assert_eq!(BigUint::binomial(1000, 500).to_str_radix(10).len(), 300);
+    assert_eq!(BigUint::binomial(u64::MAX, 1), BigUint::from(u64::MAX));
+    assert_eq!(
+        BigUint::binomial(u64::MAX, u64::MAX - 1),
+        BigUint::from(u64::MAX)
+    );
+    assert_eq!(
+        BigUint::binomial(u64::MAX, 2),
+        BigUint::from(u64::MAX) * (u64::MAX - 1) / 2u32
+    );
+}
+
+#[test]
+fn test_catalan() {
+    let mut iter = CatalanIter::new();
+    for n in 0u64..300 {
+        let c = BigUint::catalan(n);
+        assert_eq!(iter.next(), Some(c.clone()), "C({})", n);
+        // C(n) = binomial(2n, n) - binomial(2n, n + 1)
+        assert_eq!(
+            c,
+            BigUint::binomial(2 * n, n) - BigUint::binomial(2 * n, n + 1)
+        );
+    }
+    assert_eq!(BigUint::catalan(100).to_str_radix(10).len(), 57);
+}
@@ -0,0 +1,65 @@
+use super::BigUint;
+
+impl BigUint {
+    /// Returns the Fibonacci number `F(n)`, where `F(0) = 0`, `F(1) = 1` and
+    /// `F(n + 2) = F(n) + F(n + 1)`.
+    ///
+    /// This takes `O(log n)` steps, each a few multiplications, by [`BigUint::fibonacci_pair`].
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::fibonacci(0), BigUint::from(0u32));
+    /// assert_eq!(BigUint::fibonacci(10), BigUint::from(55u32));
+    /// assert_eq!(BigUint::fibonacci(93), BigUint::from(12_200_160_415_121_876_738u64));
+    /// ```
+    pub fn fibonacci(n: u64) -> BigUint {
+        Self::fibonacci_pair(n).0
+    }
+
+    /// Returns the pair of Fibonacci numbers `(F(n), F(n + 1))`.
+    ///
+    /// These are found by fast doubling, from the bits of `n` down, with the identities
+    /// `F(2k) = F(k) (2 F(k + 1) - F(k))` and `F(2k + 1) = F(k)² + F(k + 1)²`.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let (a, b) = BigUint::fibonacci_pair(100);
+    /// assert_eq!(a.to_string(), "354224848179261915075");
+    /// assert_eq!(b.to_string(), "573147844013817084101");
+    /// ```
+    pub fn fibonacci_pair(n: u64) -> (BigUint, BigUint) {
+        // (F(k), F(k + 1)) for the leading bits `k` of `n`
+        let mut a = BigUint::ZERO;
+        let mut b = BigUint::from(1u32);
+        for i in (0..u64::BITS - n.leading_zeros()).rev() {
+            let even = &a * ((&b << 1u8) - &a);
+            let odd = a.square() + b.square();
+            if (n >> i) & 1 == 0 {
+                a = even;
+                b = odd;
+            } else {
+                b = even + &odd;
+                a = odd;
+            }
+        }
+        (a, b)
+    }
+}
+
+#[test]
+fn test_fibonacci() {
+    let mut a = BigUint::ZERO;
+    let mut b = BigUint::from(1u32);
+    for n in 0..1000 {
+        assert_eq!(
+            BigUint::fibonacci_pair(n),
+            (a.clone(), b.clone()),
+            "F({})",
+            n
+        );
+        let next = &a + &b;
+        a = core::mem::replace(&mut b, next);
+    }
+}
@@ -0,0 +1,269 @@
+use super::BigUint;
+
+use core::cmp;
+use core::mem;
+use num_integer::Integer;
+use num_traits::{One, ToPrimitive};
+
+/// Inputs with at least this many bits use Lehmer's algorithm, and smaller ones use Stein's.
+const LEHMER_THRESHOLD: u64 = 256;
+
+/// The number of leading bits used for Lehmer's single-word steps. This leaves room for the
+/// cofactors, which are bounded by the leading parts, to fit in an `i64`.
+const LEADING_BITS: u64 = 62;
+
+pub(super) fn gcd(a: &BigUint, b: &BigUint) -> BigUint {
+    if a.bits() >= LEHMER_THRESHOLD && b.bits() >= LEHMER_THRESHOLD {
+        lehmer_gcd(a.clone(), b.clone())
+    } else {
+        stein_gcd(a, b)
+    }
+}
+
+fn stein_gcd(a: &BigUint, b: &BigUint) -> BigUint {
+    #[inline]
+    fn twos(x: &BigUint) -> u64 {
+        x.trailing_zeros().unwrap_or(0)
+    }
+
+    if a.is_zero() {
+        return b.clone();
+    }
+    if b.is_zero() {
+        return a.clone();
+    }
+    let mut m = a.clone();
+    let mut n = b.clone();
+
+    // find common factors of 2
+    let shift = cmp::min(twos(&n), twos(&m));
+
+    // divide m and n by 2 until odd
+    // m inside loop
+    n >>= twos(&n);
+
+    while !m.is_zero() {
+        m >>= twos(&m);
+        if n > m {
+            mem::swap(&mut n, &mut m)
+        }
+        m -= &n;
+    }
+
+    n << shift
+}
+
+/// Lehmer's algorithm, from Knuth TAOCP vol 2 section 4.5.2, algorithm L.
+///
+/// Each round runs Euclid's algorithm on just the leading bits of `a` and `b`, for as long as
+/// the quotients are sure to match those of the full numbers, and then applies all of those
+/// steps to `a` and `b` at once with a few multiplications by single words.
+pub(super) fn lehmer_gcd(mut a: BigUint, mut b: BigUint) -> BigUint {
+    if a < b {
+        mem::swap(&mut a, &mut b);
+    }
+
+    while b.bits() > 64 {
+        let shift = a.bits().saturating_sub(LEADING_BITS);
+        let mut x = (&a >> shift).to_i64().unwrap();
+        let mut y = (&b >> shift).to_i64().unwrap();
+
+        // The cofactors such that the leading parts are x = ca*a + cb*b and y = cc*a + cd*b.
+        let (mut ca, mut cb, mut cc, mut cd) = (1i64, 0i64, 0i64, 1i64);
+        loop {
+            // The true quotient lies between these two estimates, so once they disagree we
+            // can't take another step without looking at more digits.
+            if y + cc <= 0 || y + cd <= 0 {
+                break;
+            }
+            let q = (x + ca) / (y + cc);
+            if q != (x + cb) / (y + cd) {
+                break;
+            }
+            (ca, cc) = (cc, ca - q * cc);
+            (cb, cd) = (cd, cb - q * cd);
+            (x, y) = (y, x - q * y);
+        }
+
+        if cb == 0 {
+            // The leading parts were no help, usually because `a` is much larger than `b`.
+            let r = a % &b;
+            a = b;
+            b = r;
+        } else {
+            let new_a = combine(&a, ca, &b, cb);
+            b = combine(&a, cc, &b, cd);
+            a = new_a;
+        }
+    }
+
+    if b.is_zero() {
+        return a;
+    }
+    let b = b.to_u64().unwrap();
+    let a = (a % b).to_u64().unwrap();
+    BigUint::from(a.gcd(&b))
+}
+
+/// Returns `ca * a + cb * b`, where the cofactors have opposite signs and the result is known
+/// to be non-negative.
+fn combine(a: &BigUint, ca: i64, b: &BigUint, cb: i64) -> BigUint {
+    if cb <= 0 {
+        a * ca.unsigned_abs() - b * cb.unsigned_abs()
+    } else {
+        b * cb.unsigned_abs() - a * ca.unsigned_abs()
+    }
+}
+
+impl BigUint {
+    /// Returns the greatest common divisor of all of the `values`, which is zero if there are
+    /// none, since zero is the identity of `gcd`.
+    ///
+    /// The values are combined in a balanced tree, so that each `gcd` is of operands with
+    /// similar sizes, and it stops early once the result is one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let values = [12u32, 18, 30].map(BigUint::from);
+    /// assert_eq!(BigUint::gcd_all(&values), BigUint::from(6u32));
+    /// assert_eq!(BigUint::gcd_all(&[]), BigUint::ZERO);
+    /// ```
+    pub fn gcd_all(values: &[BigUint]) -> BigUint {
+        match values {
+            [] => BigUint::ZERO,
+            [x] => x.clone(),
+            _ => {
+                let (left, right) = values.split_at(values.len() / 2);
+                let left = BigUint::gcd_all(left);
+                if left.is_one() {
+                    return left;
+                }
+                gcd(&left, &BigUint::gcd_all(right))
+            }
+        }
+    }
+
+    /// Returns the least common multiple of all of the `values`, which is one if there are
+    /// none, since one is the identity of `lcm`, and zero if any of them are zero.
+    ///
+    /// The values are combined in a balanced tree, like a product tree, so that each
+    /// multiplication is of operands with similar sizes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let values = [4u32, 6, 10].map(BigUint::from);
+    /// assert_eq!(BigUint::lcm_all(&values), BigUint::from(60u32));
+    /// assert_eq!(BigUint::lcm_all(&[]), BigUint::from(1u32));
+    /// ```
+    pub fn lcm_all(values: &[BigUint]) -> BigUint {
+        if values.iter().any(BigUint::is_zero) {
+            return BigUint::ZERO;
+        }
+        lcm_tree(values)
+    }
+}
+
+/// Returns the least common multiple of nonzero `values`.
+fn lcm_tree(values: &[BigUint]) -> BigUint {
+    match values {
+        [] => BigUint::one(),
+        [x] => x.clone(),
+        _ => {
+            let (left, right) = values.split_at(values.len() / 2);
+            lcm_tree(left).lcm(&lcm_tree(right))
+        }
+    }
+}
+
+#[cfg(test)]
+fn euclid(a: &BigUint, b: &BigUint) -> BigUint {
+    let mut m = a.clone();
+    let mut n = b.clone();
+    while !m.is_zero() {
+        let temp = m;
+        m = n % &temp;
+        n = temp;
+    }
+    n
+}
+
+/// Returns a generator of pseudorandom numbers with an exact number of bits.
+#[cfg(test)]
+fn random_generator() -> impl FnMut(u64) -> BigUint {
+    use super::biguint_from_vec;
+    use super::multiplication::digits;
+
+    let mut state = 0x2545_f491_4f6c_dd1du64;
+    move |bits: u64| {
+        let mut x = BigUint::ZERO;
+        while x.bits() < bits {
+            x = (x << crate::big_digit::BITS) + biguint_from_vec(digits(&mut state, 1));
+        }
+        let extra = x.bits() - bits;
+        x >> extra
+    }
+}
+
+#[test]
+fn test_stein_gcd() {
+    let mut random = random_generator();
+
+    assert!(stein_gcd(&BigUint::ZERO, &BigUint::ZERO).is_zero());
+    for &bits in &[1, 63, 64, 65, 128, 512, 2048] {
+        let x = random(bits);
+        assert_eq!(stein_gcd(&x, &BigUint::ZERO), x);
+        assert_eq!(stein_gcd(&BigUint::ZERO, &x), x);
+        assert_eq!(stein_gcd(&x, &x), x);
+
+        for &other in &[1, 64, 128, 512, 2048] {
+            let y = random(other);
+            assert_eq!(stein_gcd(&x, &y), euclid(&x, &y));
+
+            // Common factors, including a power of two.
+            let z = random(300) << 77u32;
+            let (xz, yz) = (&x * &z, &y * &z);
+            assert_eq!(stein_gcd(&xz, &yz), euclid(&xz, &yz));
+        }
+    }
+}
+
+#[test]
+fn test_lehmer_gcd() {
+    use num_traits::One;
+
+    let mut random = random_generator();
+
+    assert!(lehmer_gcd(BigUint::ZERO, BigUint::ZERO).is_zero());
+    for &bits in &[1, 63, 64, 65, 200, 1000, 2048] {
+        let x = random(bits);
+        assert_eq!(lehmer_gcd(x.clone(), BigUint::ZERO), x);
+        assert_eq!(lehmer_gcd(BigUint::ZERO, x.clone()), x);
+        assert_eq!(lehmer_gcd(x.clone(), x.clone()), x);
+        assert!(lehmer_gcd(x.clone(), &x + 1u32).is_one());
+
+        for &other in &[1, 64, 100, 1000, 2048] {
+            let y = random(other);
+            assert_eq!(lehmer_gcd(x.clone(), y.clone()), euclid(&x, &y));
+
+            // A large common factor.
+            let z = random(300);
+            let (xz, yz) = (&x * &z, &y * &z);
+            assert_eq!(lehmer_gcd(xz.clone(), yz.clone()), euclid(&xz, &yz));
+        }
+    }
+
+    // Consecutive Fibonacci numbers take the most steps.
+    let (mut f0, mut f1) = (BigUint::one(), BigUint::one());
+    for _ in 0..3000 {
+        f0 += &f1;
+        mem::swap(&mut f0, &mut f1);
+    }
+    assert!(lehmer_gcd(f1.clone(), f0.clone()).is_one());
+    assert_eq!(lehmer_gcd(&f1 * 6u32, &f0 * 4u32), BigUint::from(2u32));
+}
@@ -0,0 +1,51 @@
+#![cfg(feature = "std")]
+#![cfg_attr(docsrs, doc(cfg(feature = "std")))]
+
+use super::BigUint;
+
+use alloc::vec::Vec;
+use std::io::{self, Read};
+
+impl BigUint {
+    /// Writes the little-endian bytes of `self`, as by [`BigUint::to_bytes_le`], returning the
+    /// number of bytes written.
+    ///
+    /// The bytes are not prefixed by their length, so a format that doesn't fix the length must
+    /// record the returned count to read the value back with [`BigUint::read_from_le_bytes`].
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let mut buf = Vec::new();
+    /// assert_eq!(BigUint::from(0x1234u32).write_to_le_bytes(&mut buf).unwrap(), 2);
+    /// assert_eq!(buf, [0x34, 0x12]);
+    /// ```
+    pub fn write_to_le_bytes<W: io::Write>(&self, w: &mut W) -> io::Result<usize> {
+        let bytes = self.to_bytes_le();
+        w.write_all(&bytes)?;
+        Ok(bytes.len())
+    }
+
+    /// Reads an integer from exactly `byte_len` little-endian bytes, as by
+    /// [`BigUint::from_bytes_le`]. High zero bytes, as in a fixed-width field, are allowed.
+    ///
+    /// If the reader ends first, this fails with [`io::ErrorKind::UnexpectedEof`].
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let mut r: &[u8] = &[0x34, 0x12, 0x00, 0x00, 0xff];
+    /// assert_eq!(BigUint::read_from_le_bytes(&mut r, 4).unwrap(), BigUint::from(0x1234u32));
+    /// assert_eq!(BigUint::read_from_le_bytes(&mut r, 1).unwrap(), BigUint::from(0xffu32));
+    /// assert!(BigUint::read_from_le_bytes(&mut r, 1).is_err());
+    /// ```
+    pub fn read_from_le_bytes<R: io::Read>(r: &mut R, byte_len: usize) -> io::Result<BigUint> {
+        // Read through `take`, so a huge `byte_len` isn't allocated before the data arrives.
+        let mut bytes = Vec::new();
+        r.take(byte_len as u64).read_to_end(&mut bytes)?;
+        if bytes.len() < byte_len {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+        Ok(BigUint::from_bytes_le(&bytes))
+    }
+}
@@ -1,3 +1,5 @@
+use crate::big_digit::{self, BigDigit};
+
 use core::iter::FusedIterator;
 
 cfg_digit!(
@@ -276,6 +278,87 @@ impl ExactSizeIterator for U64Digits<'_> {
 
 impl FusedIterator for U64Digits<'_> {}
 
+/// An iterator of the bits of a `BigUint`, ordered least significant bit first, and ending
+/// with the most significant set bit.
+#[derive(Clone, Debug)]
+pub struct Bits<'a> {
+    data: &'a [BigDigit],
+    // The range of bit indices not yet yielded from either end.
+    front: u64,
+    back: u64,
+}
+
+impl<'a> Bits<'a> {
+    #[inline]
+    pub(super) fn new(data: &'a [BigDigit], bits: u64) -> Self {
+        Bits {
+            data,
+            front: 0,
+            back: bits,
+        }
+    }
+
+    #[inline]
+    fn get(&self, i: u64) -> bool {
+        let digit_bits = u64::from(big_digit::BITS);
+        let digit = self.data[(i / digit_bits) as usize];
+        (digit >> (i % digit_bits)) & 1 == 1
+    }
+}
+
+impl Iterator for Bits<'_> {
+    type Item = bool;
+    #[inline]
+    fn next(&mut self) -> Option<bool> {
+        if self.front < self.back {
+            let bit = self.get(self.front);
+            self.front += 1;
+            Some(bit)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match usize::try_from(self.back - self.front) {
+            Ok(len) => (len, Some(len)),
+            Err(_) => (usize::MAX, None),
+        }
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<bool> {
+        self.front = Ord::min(self.front.saturating_add(n as u64), self.back);
+        self.next()
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<bool> {
+        self.next_back()
+    }
+}
+
+impl DoubleEndedIterator for Bits<'_> {
+    #[inline]
+    fn next_back(&mut self) -> Option<bool> {
+        if self.front < self.back {
+            self.back -= 1;
+            Some(self.get(self.back))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<bool> {
+        self.back = Ord::max(self.back.saturating_sub(n as u64), self.front);
+        self.next_back()
+    }
+}
+
+impl FusedIterator for Bits<'_> {}
+
 #[test]
 fn test_iter_u32_digits() {
     let n = super::BigUint::from(5u8);
@@ -359,3 +442,31 @@ fn test_iter_u64_digits_be() {
     assert_eq!(it.len(), 0);
     assert_eq!(it.next(), None);
 }
+
+#[test]
+fn test_iter_bits() {
+    let n = super::BigUint::from(0b1101u8);
+    let mut it = n.iter_bits();
+    assert_eq!(it.size_hint(), (4, Some(4)));
+    assert_eq!(it.next(), Some(true));
+    assert_eq!(it.next_back(), Some(true));
+    assert_eq!(it.size_hint(), (2, Some(2)));
+    assert_eq!(it.next(), Some(false));
+    assert_eq!(it.next_back(), Some(true));
+    assert_eq!(it.size_hint(), (0, Some(0)));
+    assert_eq!(it.next(), None);
+    assert_eq!(it.next_back(), None);
+
+    let n = super::BigUint::from(5u8) << 100u32;
+    let mut it = n.iter_bits();
+    assert_eq!(it.nth(100), Some(true));
+    assert_eq!(it.nth(1), Some(true));
+    assert_eq!(it.next(), None);
+    assert_eq!(n.iter_bits().nth_back(2), Some(true));
+    assert_eq!(n.iter_bits().nth_back(3), Some(false));
+    assert_eq!(n.iter_bits().nth_back(103), None);
+    assert_eq!(n.iter_bits().last(), Some(true));
+    assert_eq!(n.iter_bits().filter(|&b| b).count(), 2);
+
+    assert_eq!(super::BigUint::default().iter_bits().next(), None);
+}
@@ -0,0 +1,82 @@
+use super::BigUint;
+
+use core::mem;
+
+/// Returns the Jacobi symbol `(a/n)` for odd `n`, by the binary algorithm, which only needs
+/// shifts and subtractions. See Shallit and Sorenson, "A Binary Algorithm for the Jacobi
+/// Symbol", 1993.
+pub(super) fn jacobi(a: &BigUint, n: &BigUint) -> i8 {
+    assert!(
+        n.is_odd(),
+        "the Jacobi symbol is only defined for odd positive n"
+    );
+
+    let low_bits = |x: &BigUint| x.data.first().map_or(0, |&d| d & 7);
+
+    let mut a = a.clone();
+    let mut n = n.clone();
+    let mut t = 1;
+    while !a.is_zero() {
+        // (2/n) = -1 exactly when n = 3 or 5 (mod 8).
+        let twos = a.trailing_zeros().unwrap();
+        a >>= twos;
+        if twos % 2 == 1 && matches!(low_bits(&n), 3 | 5) {
+            t = -t;
+        }
+
+        // Both are odd now, so quadratic reciprocity applies.
+        if a < n {
+            mem::swap(&mut a, &mut n);
+            if low_bits(&a) & 3 == 3 && low_bits(&n) & 3 == 3 {
+                t = -t;
+            }
+        }
+        a -= &n;
+    }
+
+    if n.is_one() {
+        t
+    } else {
+        0
+    }
+}
+
+#[test]
+fn test_jacobi() {
+    use num_integer::Integer;
+
+    // The Legendre symbol by Euler's criterion.
+    fn legendre(a: u32, p: u32) -> i8 {
+        let (a, p) = (u64::from(a), u64::from(p));
+        let r = (0..(p - 1) / 2).fold(1, |r, _| r * a % p);
+        match r {
+            0 => 0,
+            1 => 1,
+            _ => -1,
+        }
+    }
+
+    // The Jacobi symbol as the product of Legendre symbols over the factors of `n`.
+    fn naive(a: u32, mut n: u32) -> i8 {
+        let mut t = 1;
+        let mut p = 3;
+        while n > 1 {
+            while n % p == 0 {
+                t *= legendre(a, p);
+                n /= p;
+            }
+            p += 2;
+        }
+        t
+    }
+
+    for n in (1u32..300).step_by(2) {
+        for a in 0u32..700 {
+            let expected = naive(a, n);
+            assert_eq!(jacobi(&a.into(), &n.into()), expected, "({}/{})", a, n);
+            if a.gcd(&n) != 1 {
+                assert_eq!(expected, 0);
+            }
+        }
+    }
+}
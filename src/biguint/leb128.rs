@@ -0,0 +1,108 @@
+//! Unsigned LEB128, the variable-length encoding used by DWARF, WebAssembly and protobuf varints
+
+use super::{convert, BigUint};
+use crate::Leb128Error;
+
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use std::io;
+
+/// The continuation bit, set on every byte but the last.
+const MORE: u8 = 0x80;
+
+impl BigUint {
+    /// Returns the unsigned LEB128 encoding of `self`: its value in groups of 7 bits, least
+    /// significant first, one per byte, with the high bit set on every byte but the last.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::from(0u32).to_leb128(), [0x00]);
+    /// assert_eq!(BigUint::from(127u32).to_leb128(), [0x7f]);
+    /// assert_eq!(BigUint::from(624_485u32).to_leb128(), [0xe5, 0x8e, 0x26]);
+    /// ```
+    pub fn to_leb128(&self) -> Vec<u8> {
+        let mut bytes = convert::to_radix_le(self, 128);
+        let last = bytes.len() - 1;
+        for b in &mut bytes[..last] {
+            *b |= MORE;
+        }
+        bytes
+    }
+
+    /// Decodes an unsigned LEB128 integer from the start of `bytes`, returning it with the
+    /// number of bytes it took. Any bytes after the integer are ignored. Returns an error if
+    /// there is no final byte, without the high bit set.
+    ///
+    /// Redundant trailing `0x80` bytes, which some encoders use for padding, are accepted.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let bytes = [0xe5, 0x8e, 0x26, 0xff];
+    /// assert_eq!(BigUint::from_leb128(&bytes), Ok((BigUint::from(624_485u32), 3)));
+    /// assert!(BigUint::from_leb128(&bytes[..2]).is_err());
+    /// ```
+    pub fn from_leb128(bytes: &[u8]) -> Result<(BigUint, usize), Leb128Error> {
+        let len = match bytes.iter().position(|&b| b & MORE == 0) {
+            Some(i) => i + 1,
+            None => return Err(Leb128Error { len: bytes.len() }),
+        };
+        let groups: Vec<u8> = bytes[..len].iter().map(|&b| b & !MORE).collect();
+        Ok((convert::from_radix_le(&groups, 128).unwrap(), len))
+    }
+
+    /// Writes the unsigned LEB128 encoding of `self`, as by [`BigUint::to_leb128`], returning
+    /// the number of bytes written.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let mut buf = Vec::new();
+    /// assert_eq!(BigUint::from(300u32).write_leb128(&mut buf).unwrap(), 2);
+    /// assert_eq!(buf, [0xac, 0x02]);
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn write_leb128<W: io::Write>(&self, w: &mut W) -> io::Result<usize> {
+        let bytes = self.to_leb128();
+        w.write_all(&bytes)?;
+        Ok(bytes.len())
+    }
+
+    /// Reads an unsigned LEB128 integer, consuming exactly the bytes of its encoding.
+    ///
+    /// If the reader ends before the last byte, this fails with
+    /// [`io::ErrorKind::UnexpectedEof`], wrapping a [`Leb128Error`].
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let mut r: &[u8] = &[0xac, 0x02, 0x01];
+    /// assert_eq!(BigUint::read_leb128(&mut r).unwrap(), BigUint::from(300u32));
+    /// assert_eq!(BigUint::read_leb128(&mut r).unwrap(), BigUint::from(1u32));
+    /// assert!(BigUint::read_leb128(&mut r).is_err());
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn read_leb128<R: io::Read>(r: &mut R) -> io::Result<BigUint> {
+        let mut groups = Vec::new();
+        loop {
+            let mut byte = [0u8];
+            if let Err(e) = r.read_exact(&mut byte) {
+                return Err(match e.kind() {
+                    io::ErrorKind::UnexpectedEof => io::Error::new(
+                        io::ErrorKind::UnexpectedEof,
+                        Leb128Error { len: groups.len() },
+                    ),
+                    _ => e,
+                });
+            }
+            groups.push(byte[0] & !MORE);
+            if byte[0] & MORE == 0 {
+                return Ok(convert::from_radix_le(&groups, 128).unwrap());
+            }
+        }
+    }
+}
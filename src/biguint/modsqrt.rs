@@ -0,0 +1,94 @@
+use super::jacobi::jacobi;
+use super::BigUint;
+
+/// Returns a square root of `a` modulo the odd prime `p`, or `None` if `a` is not a square.
+///
+/// When `p = 3 (mod 4)`, the root is `a^((p + 1) / 4)`. Otherwise this is the Tonelli-Shanks
+/// algorithm, which takes `O(s^2)` multiplications on top of a few exponentiations, where `2^s`
+/// is the largest power of two dividing `p - 1`.
+pub(super) fn sqrt_mod_prime(a: &BigUint, p: &BigUint) -> Option<BigUint> {
+    let a = a % p;
+    if a.is_zero() {
+        return Some(a);
+    }
+    if jacobi(&a, p) != 1 {
+        return None;
+    }
+
+    if p.bit(1) {
+        let e = (p >> 2u32) + 1u32;
+        return Some(a.modpow(&e, p));
+    }
+
+    // Write p - 1 = q * 2^s with odd q.
+    let p_minus_one = p - 1u32;
+    let s = p_minus_one.trailing_zeros().unwrap();
+    let q = &p_minus_one >> s;
+
+    // Any non-square z will do. A symbol of 0 means that `p` isn't prime after all.
+    let mut z = BigUint::from(2u32);
+    loop {
+        match jacobi(&z, p) {
+            -1 => break,
+            0 => return None,
+            _ => z += 1u32,
+        }
+    }
+
+    // Invariants: r^2 = a t, c^(2^(m - 1)) = -1 and t^(2^(m - 1)) = 1.
+    let mut m = s;
+    let mut c = z.modpow(&q, p);
+    let mut t = a.modpow(&q, p);
+    let mut r = a.modpow(&((q + 1u32) >> 1u32), p);
+    while !t.is_one() {
+        // Find the order 2^i of t, which is less than 2^m.
+        let mut i = 0;
+        let mut t2 = t.clone();
+        while !t2.is_one() {
+            i += 1;
+            if i == m {
+                return None;
+            }
+            t2 = t2.square() % p;
+        }
+
+        let mut b = c;
+        for _ in 0..m - i - 1 {
+            b = b.square() % p;
+        }
+        m = i;
+        c = b.square() % p;
+        t = t * &c % p;
+        r = r * b % p;
+    }
+    Some(r)
+}
+
+#[test]
+fn test_sqrt_mod_prime() {
+    use alloc::vec::Vec;
+
+    // Both 3 (mod 4) primes and 1 (mod 4) primes with up to 2^6 dividing p - 1.
+    for p in [
+        3u32, 5, 7, 11, 13, 17, 41, 43, 73, 97, 193, 257, 449, 577, 641,
+    ] {
+        let big_p = BigUint::from(p);
+        let squares: Vec<u32> = (0..p).map(|x| x * x % p).collect();
+        for a in 0..2 * p {
+            let root = sqrt_mod_prime(&BigUint::from(a), &big_p);
+            match root {
+                Some(x) => {
+                    assert!(x < big_p);
+                    assert_eq!(
+                        x.square() % p,
+                        BigUint::from(a % p),
+                        "sqrt({}) mod {}",
+                        a,
+                        p
+                    );
+                }
+                None => assert!(!squares.contains(&(a % p)), "sqrt({}) mod {}", a, p),
+            }
+        }
+    }
+}
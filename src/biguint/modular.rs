@@ -0,0 +1,99 @@
+//! Arithmetic modulo a fixed modulus
+
+use super::BigUint;
+
+use alloc::borrow::Cow;
+
+/// Arithmetic on residues modulo a fixed modulus, to avoid repeating it in every expression.
+///
+/// The operands may be any values, not only residues in `[0, modulus)`, and every result is
+/// reduced into that range. Reduced operands skip the extra division, so keeping values reduced
+/// between operations is cheaper than writing out `(a % m + b % m) % m` each time.
+///
+/// # Examples
+///
+/// ```
+/// use num_bigint::{BigUint, ModularArithmetic};
+///
+/// let m = ModularArithmetic::new(BigUint::from(17u32));
+/// let a = BigUint::from(12u32);
+/// let b = BigUint::from(9u32);
+/// assert_eq!(m.add(&a, &b), BigUint::from(4u32));
+/// assert_eq!(m.sub(&b, &a), BigUint::from(14u32));
+/// assert_eq!(m.mul(&a, &b), BigUint::from(6u32));
+/// assert_eq!(m.neg(&a), BigUint::from(5u32));
+/// assert_eq!(m.reduce(&BigUint::from(100u32)), BigUint::from(15u32));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ModularArithmetic {
+    modulus: BigUint,
+}
+
+impl ModularArithmetic {
+    /// Creates the arithmetic modulo `modulus`.
+    ///
+    /// Panics if `modulus` is zero.
+    pub fn new(modulus: BigUint) -> Self {
+        assert!(
+            !modulus.is_zero(),
+            "attempt to calculate with zero modulus!"
+        );
+        ModularArithmetic { modulus }
+    }
+
+    /// Returns the modulus.
+    #[inline]
+    pub fn modulus(&self) -> &BigUint {
+        &self.modulus
+    }
+
+    /// Returns `n % modulus`.
+    pub fn reduce(&self, n: &BigUint) -> BigUint {
+        self.residue(n).into_owned()
+    }
+
+    /// Returns `(a + b) % modulus`.
+    pub fn add(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        let sum = &*self.residue(a) + &*self.residue(b);
+        if sum >= self.modulus {
+            sum - &self.modulus
+        } else {
+            sum
+        }
+    }
+
+    /// Returns `(a - b) % modulus`, wrapping around to a residue in `[0, modulus)` when
+    /// `a < b`.
+    pub fn sub(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        let (a, b) = (self.residue(a), self.residue(b));
+        if a >= b {
+            &*a - &*b
+        } else {
+            &self.modulus - (&*b - &*a)
+        }
+    }
+
+    /// Returns `(a * b) % modulus`.
+    pub fn mul(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        &*self.residue(a) * &*self.residue(b) % &self.modulus
+    }
+
+    /// Returns `-a % modulus`, the residue that adds to `a` to make zero.
+    pub fn neg(&self, a: &BigUint) -> BigUint {
+        let a = self.residue(a);
+        if a.is_zero() {
+            BigUint::ZERO
+        } else {
+            &self.modulus - &*a
+        }
+    }
+
+    /// Returns `n` reduced modulo the modulus, borrowing it if it is already a residue.
+    fn residue<'a>(&self, n: &'a BigUint) -> Cow<'a, BigUint> {
+        if *n < self.modulus {
+            Cow::Borrowed(n)
+        } else {
+            Cow::Owned(n % &self.modulus)
+        }
+    }
+}
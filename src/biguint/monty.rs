@@ -132,6 +132,121 @@ fn mul_add_www(x: BigDigit, y: BigDigit, c: BigDigit) -> (BigDigit, BigDigit) {
     ((z >> big_digit::BITS) as BigDigit, z as BigDigit)
 }
 
+/// Returns `x` with its digits extended to the length `n`.
+fn padded(x: &BigUint, n: usize) -> BigUint {
+    let mut x = x.clone();
+    x.data.resize(n, 0);
+    x
+}
+
+/// The arithmetic of numbers in Montgomery form for an odd modulus `n`, where each residue `a`
+/// is represented by `a * R mod n`, with `R` the power of two just above `n` in whole digits.
+///
+/// Multiplying in this form needs no division, only Montgomery's reduction (REDC), so it suits
+/// long chains of modular multiplications with the same modulus. Converting into and out of the
+/// form costs one multiplication each.
+///
+/// [`BigUint::modpow`] already uses this internally for odd moduli.
+///
+/// # Examples
+///
+/// ```
+/// use num_bigint::{BigUint, MontgomeryForm};
+///
+/// let modulus = BigUint::from(1_000_000_007u32);
+/// let form = MontgomeryForm::new(modulus.clone());
+///
+/// let a = form.enter(&BigUint::from(123_456_789u32));
+/// let b = form.enter(&BigUint::from(987_654_321u32));
+/// let product = form.exit(&form.mul(&a, &b));
+/// assert_eq!(product, BigUint::from(123_456_789u64 * 987_654_321 % 1_000_000_007));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MontgomeryForm {
+    n: BigUint,
+    /// `R^2 mod n`, padded to the length of `n`
+    rr: BigUint,
+    /// `-1/n mod 2^BITS`
+    n_prime: BigDigit,
+}
+
+/// A residue in Montgomery form, created by [`MontgomeryForm::enter`].
+///
+/// Each value belongs to the form that created it, and mixing values of different forms gives
+/// meaningless results.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MontgomeryInt {
+    /// `a * R mod n`, fully reduced
+    value: BigUint,
+}
+
+impl MontgomeryForm {
+    /// Creates the Montgomery form for `n`.
+    ///
+    /// Panics if `n` is even, which includes zero.
+    pub fn new(n: BigUint) -> Self {
+        assert!(n.is_odd(), "Montgomery form requires an odd modulus");
+        let num_words = n.data.len();
+        let rr = BigUint::one().shl(2 * num_words as u64 * u64::from(big_digit::BITS)) % &n;
+        MontgomeryForm {
+            rr: padded(&rr, num_words),
+            n_prime: MontyReducer::new(&n).n0inv,
+            n,
+        }
+    }
+
+    /// Returns the modulus.
+    #[inline]
+    pub fn modulus(&self) -> &BigUint {
+        &self.n
+    }
+
+    /// Multiplies residues in padded form, and reduces the result below `n`.
+    fn redc(&self, x: &BigUint, y: &BigUint) -> MontgomeryInt {
+        let mut value = montgomery(x, y, &self.n, self.n_prime, self.n.data.len());
+        value.normalize();
+        // The result is less than `R`, but possibly not less than `n`.
+        while value >= self.n {
+            value -= &self.n;
+        }
+        MontgomeryInt { value }
+    }
+
+    /// Converts `a mod n` into Montgomery form.
+    pub fn enter(&self, a: &BigUint) -> MontgomeryInt {
+        let num_words = self.n.data.len();
+        self.redc(&padded(&(a % &self.n), num_words), &self.rr)
+    }
+
+    /// Converts a value out of Montgomery form, to its residue in `[0, n)`.
+    pub fn exit(&self, a: &MontgomeryInt) -> BigUint {
+        let num_words = self.n.data.len();
+        let one = padded(&BigUint::one(), num_words);
+        self.redc(&padded(&a.value, num_words), &one).value
+    }
+
+    /// Returns the product of `a` and `b`, in Montgomery form.
+    pub fn mul(&self, a: &MontgomeryInt, b: &MontgomeryInt) -> MontgomeryInt {
+        let num_words = self.n.data.len();
+        self.redc(&padded(&a.value, num_words), &padded(&b.value, num_words))
+    }
+
+    /// Returns `a` raised to the power `exponent`, in Montgomery form.
+    ///
+    /// This is binary exponentiation with [`mul`][Self::mul], while [`BigUint::modpow`] uses a
+    /// window of exponent bits to save multiplications.
+    pub fn pow(&self, a: &MontgomeryInt, exponent: &BigUint) -> MontgomeryInt {
+        let mut acc = self.enter(&BigUint::one());
+        for i in (0..exponent.bits()).rev() {
+            acc = self.mul(&acc, &acc);
+            if exponent.bit(i) {
+                acc = self.mul(&acc, a);
+            }
+        }
+        acc
+    }
+}
+
 /// Calculates x ** y mod m using a fixed, 4-bit window.
 #[allow(clippy::many_single_char_names)]
 pub(super) fn monty_modpow(x: &BigUint, y: &BigUint, m: &BigUint) -> BigUint {
@@ -6,10 +6,11 @@ use crate::big_digit::{self, BigDigit, DoubleBigDigit};
 use crate::Sign::{self, Minus, NoSign, Plus};
 use crate::{BigInt, UsizePromotion};
 
-use core::cmp::Ordering;
+use alloc::vec::Vec;
+use core::cmp::{self, Ordering};
 use core::iter::Product;
 use core::ops::{Mul, MulAssign};
-use num_traits::{CheckedMul, FromPrimitive, One, Zero};
+use num_traits::{CheckedMul, FromPrimitive, MulAdd, One, Zero};
 
 #[inline]
 pub(super) fn mac_with_carry(
@@ -86,7 +87,17 @@ fn mac3(mut acc: &mut [BigDigit], mut b: &[BigDigit], mut c: &[BigDigit]) {
     let acc = acc;
     let (x, y) = if b.len() < c.len() { (b, c) } else { (c, b) };
 
-    // We use four algorithms for different input sizes.
+    // With the `ntt-mul` feature, the largest inputs use a number-theoretic
+    // transform, as long as the product fits in a single transform.
+    #[cfg(feature = "ntt-mul")]
+    {
+        if super::ntt::use_ntt(x, y) {
+            super::ntt::mac3(acc, x, y);
+            return;
+        }
+    }
+
+    // Otherwise we use four algorithms for different input sizes.
     //
     // - For small inputs, long multiplication is fastest.
     // - If y is at least least twice as long as x, split using Half-Karatsuba.
@@ -357,52 +368,66 @@ fn mac3(mut acc: &mut [BigDigit], mut b: &[BigDigit], mut c: &[BigDigit]) {
         // w(-2)
         let r3 = ((p2 + x2) * 2 - x0) * ((q2 + y2) * 2 - y0);
 
-        // Evaluating these points gives us the following system of linear equations.
-        //
-        //  0  0  0  0  1 | a
-        //  1  1  1  1  1 | b
-        //  1 -1  1 -1  1 | c
-        // 16 -8  4 -2  1 | d
-        //  1  0  0  0  0 | e
-        //
-        // The solved equation (after gaussian elimination or similar)
-        // in terms of its coefficients:
-        //
-        // w0 = w(0)
-        // w1 = w(0)/2 + w(1)/3 - w(-1) + w(-2)/6 - 2*w(inf)
-        // w2 = -w(0) + w(1)/2 + w(-1)/2 - w(inf)
-        // w3 = -w(0)/2 + w(1)/6 + w(-1)/2 - w(-2)/6 + 2*w(inf)
-        // w4 = w(inf)
-        //
-        // This particular sequence is given by Bodrato and is an interpolation
-        // of the above equations.
-        let mut comp3: BigInt = (r3 - &r1) / 3u32;
-        let mut comp1: BigInt = (r1 - &r2) >> 1;
-        let mut comp2: BigInt = r2 - &r0;
-        comp3 = ((&comp2 - comp3) >> 1) + (&r4 << 1);
-        comp2 += &comp1 - &r4;
-        comp1 -= &comp3;
-
-        // Recomposition. The coefficients of the polynomial are now known.
-        //
-        // Evaluate at w(t) where t is our given base to get the result.
-        //
-        //     let bits = u64::from(big_digit::BITS) * i as u64;
-        //     let result = r0
-        //         + (comp1 << bits)
-        //         + (comp2 << (2 * bits))
-        //         + (comp3 << (3 * bits))
-        //         + (r4 << (4 * bits));
-        //     let result_pos = result.to_biguint().unwrap();
-        //     add2(&mut acc[..], &result_pos.data);
-        //
-        // But with less intermediate copying:
-        for (j, result) in [&r0, &comp1, &comp2, &comp3, &r4].iter().enumerate().rev() {
-            match result.sign() {
-                Plus => add2(&mut acc[i * j..], result.digits()),
-                Minus => sub2(&mut acc[i * j..], result.digits()),
-                NoSign => {}
-            }
+        toom3_recompose(acc, i, r0, r1, r2, r3, r4);
+    }
+}
+
+/// Solves for the coefficients of a Toom-3 product from its values at `0`, `1`, `-1`, `-2` and
+/// infinity, and adds the result to `acc`, where `i` is the length of each part in digits.
+fn toom3_recompose(
+    acc: &mut [BigDigit],
+    i: usize,
+    r0: BigInt,
+    r1: BigInt,
+    r2: BigInt,
+    r3: BigInt,
+    r4: BigInt,
+) {
+    // Evaluating these points gives us the following system of linear equations.
+    //
+    //  0  0  0  0  1 | a
+    //  1  1  1  1  1 | b
+    //  1 -1  1 -1  1 | c
+    // 16 -8  4 -2  1 | d
+    //  1  0  0  0  0 | e
+    //
+    // The solved equation (after gaussian elimination or similar)
+    // in terms of its coefficients:
+    //
+    // w0 = w(0)
+    // w1 = w(0)/2 + w(1)/3 - w(-1) + w(-2)/6 - 2*w(inf)
+    // w2 = -w(0) + w(1)/2 + w(-1)/2 - w(inf)
+    // w3 = -w(0)/2 + w(1)/6 + w(-1)/2 - w(-2)/6 + 2*w(inf)
+    // w4 = w(inf)
+    //
+    // This particular sequence is given by Bodrato and is an interpolation
+    // of the above equations.
+    let mut comp3: BigInt = (r3 - &r1) / 3u32;
+    let mut comp1: BigInt = (r1 - &r2) >> 1;
+    let mut comp2: BigInt = r2 - &r0;
+    comp3 = ((&comp2 - comp3) >> 1) + (&r4 << 1);
+    comp2 += &comp1 - &r4;
+    comp1 -= &comp3;
+
+    // Recomposition. The coefficients of the polynomial are now known.
+    //
+    // Evaluate at w(t) where t is our given base to get the result.
+    //
+    //     let bits = u64::from(big_digit::BITS) * i as u64;
+    //     let result = r0
+    //         + (comp1 << bits)
+    //         + (comp2 << (2 * bits))
+    //         + (comp3 << (3 * bits))
+    //         + (r4 << (4 * bits));
+    //     let result_pos = result.to_biguint().unwrap();
+    //     add2(&mut acc[..], &result_pos.data);
+    //
+    // But with less intermediate copying:
+    for (j, result) in [&r0, &comp1, &comp2, &comp3, &r4].iter().enumerate().rev() {
+        match result.sign() {
+            Plus => add2(&mut acc[i * j..], result.digits()),
+            Minus => sub2(&mut acc[i * j..], result.digits()),
+            NoSign => {}
         }
     }
 }
@@ -415,6 +440,125 @@ fn mul3(x: &[BigDigit], y: &[BigDigit]) -> BigUint {
     prod.normalized()
 }
 
+/// Squares `x` into `prod`, which must be zeroed and at least twice as long as `x`.
+///
+/// This follows the same algorithms as `mac3`, but each one only needs squares of smaller
+/// numbers, which in turn need about half of the digit products of a general multiplication.
+fn square_into(mut prod: &mut [BigDigit], mut x: &[BigDigit]) {
+    // Least-significant zeros have no effect on the output.
+    if let Some(&0) = x.first() {
+        if let Some(nz) = x.iter().position(|&d| d != 0) {
+            x = &x[nz..];
+            prod = &mut prod[nz * 2..];
+        } else {
+            return;
+        }
+    }
+    debug_assert!(prod.len() >= x.len() * 2);
+
+    #[cfg(feature = "ntt-mul")]
+    {
+        if super::ntt::use_ntt(x, x) {
+            super::ntt::mac3(prod, x, x);
+            return;
+        }
+    }
+
+    if x.len() <= 32 {
+        // Long squaring, from Knuth TAOCP vol 2 section 4.3.3, exercise 16:
+        //
+        // Each product x[i] * x[j] with i != j appears twice in the square, so we add up those
+        // with i < j, double the sum, and then add the squares x[i]^2 along the diagonal.
+        //
+        // Row i only reaches prod[i + x.len() - 1], so the carry out of each row can be stored
+        // directly in the next digit, which is still zero.
+        for (i, &xi) in x.iter().enumerate() {
+            let mut carry = 0;
+            for (p, &xj) in prod[i * 2 + 1..].iter_mut().zip(&x[i + 1..]) {
+                *p = mac_with_carry(*p, xj, xi, &mut carry);
+            }
+            prod[i + x.len()] = carry as BigDigit;
+        }
+
+        let mut shifted_out = 0;
+        let mut carry = 0;
+        for (i, &xi) in x.iter().enumerate() {
+            let (hi, lo) =
+                big_digit::from_doublebigdigit(DoubleBigDigit::from(xi) * DoubleBigDigit::from(xi));
+            let (d0, d1) = (prod[i * 2], prod[i * 2 + 1]);
+            carry += DoubleBigDigit::from((d0 << 1) | shifted_out) + DoubleBigDigit::from(lo);
+            prod[i * 2] = carry as BigDigit;
+            carry >>= big_digit::BITS;
+            carry += DoubleBigDigit::from((d1 << 1) | (d0 >> (big_digit::BITS - 1)))
+                + DoubleBigDigit::from(hi);
+            prod[i * 2 + 1] = carry as BigDigit;
+            carry >>= big_digit::BITS;
+            shifted_out = d1 >> (big_digit::BITS - 1);
+        }
+        debug_assert!(carry == 0 && shifted_out == 0);
+    } else if x.len() <= 256 {
+        // Karatsuba squaring, with x = x0 + x1 * b:
+        //
+        // x^2 = x0^2 + 2 * x0 * x1 * b + x1^2 * b^2
+        //     = p0 + (p0 + p2 - p1) * b + p2 * b^2
+        //
+        // Where the three intermediate squares are:
+        //
+        // p0 = x0^2
+        // p1 = (x1 - x0)^2
+        // p2 = x1^2
+        //
+        // Unlike general Karatsuba, p1 can never be negative, and p0 and p2 don't overlap, so
+        // they go straight into the output.
+        let b = x.len() / 2;
+        let (x0, x1) = x.split_at(b);
+        let (p0, p2) = prod.split_at_mut(b * 2);
+        square_into(p0, x0);
+        square_into(p2, x1);
+
+        // The middle term is p0 + p2 - p1 = 2 * x0 * x1.
+        let mut middle = vec![0; x1.len() * 2 + 1];
+        middle[..b * 2].copy_from_slice(&prod[..b * 2]);
+        add2(&mut middle, &prod[b * 2..b * 2 + x1.len() * 2]);
+
+        let (_, j) = sub_sign(x1, x0);
+        if !j.is_zero() {
+            let mut p1 = vec![0; j.data.len() * 2];
+            square_into(&mut p1, &j.data);
+            sub2(&mut middle, &p1);
+        }
+
+        add2(&mut prod[b..], &middle);
+    } else {
+        // Toom-3 squaring, with the same evaluation points as Toom-3 multiplication.
+        let i = x.len() / 3 + 1;
+
+        let x0 = bigint_from_slice(&x[..i]);
+        let x1 = bigint_from_slice(&x[i..Ord::min(x.len(), i * 2)]);
+        let x2 = bigint_from_slice(&x[Ord::min(x.len(), i * 2)..]);
+
+        // x0 + x2, and x2 - x1 + x0
+        let p = &x0 + &x2;
+        let p2 = &p - &x1;
+
+        let r0 = x0.square();
+        let r4 = x2.square();
+        let r1 = (p + x1).square();
+        let r2 = p2.square();
+        let r3 = ((p2 + x2) * 2u32 - x0).square();
+
+        toom3_recompose(prod, i, r0, r1, r2, r3, r4);
+    }
+}
+
+pub(super) fn square(x: &[BigDigit]) -> BigUint {
+    let len = x.len() * 2;
+    let mut prod = BigUint { data: vec![0; len] };
+
+    square_into(&mut prod.data, x);
+    prod.normalized()
+}
+
 fn scalar_mul(a: &mut BigUint, b: BigDigit) {
     match b {
         0 => a.set_zero(),
@@ -604,6 +748,71 @@ impl CheckedMul for BigUint {
     }
 }
 
+impl BigUint {
+    /// Returns `self * a + b`.
+    ///
+    /// The product is accumulated directly into a copy of `b`, without a temporary for
+    /// `self * a`. [`MulAdd`] is also implemented for references, the same way.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let x = BigUint::from(u64::MAX);
+    /// let y = BigUint::from(3u32);
+    /// assert_eq!(x.mul_add(&x, &y), &x * &x + &y);
+    /// ```
+    pub fn mul_add(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        let len = cmp::max(b.data.len(), self.data.len() + a.data.len()) + 1;
+        let mut data = Vec::with_capacity(len);
+        data.extend_from_slice(&b.data);
+        let mut acc = BigUint { data };
+        acc.mul_add_assign(self, a);
+        acc
+    }
+
+    /// Adds `a * b` to `self`, accumulating the product directly into `self` without a
+    /// temporary.
+    ///
+    /// Note that this is not [`MulAddAssign`][num_traits::MulAddAssign], which would instead
+    /// compute `self * a + b`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// // An inner product of two vectors.
+    /// let xs = [1u32, 2, 3].map(BigUint::from);
+    /// let ys = [4u32, 5, 6].map(BigUint::from);
+    /// let mut dot = BigUint::from(0u32);
+    /// for (x, y) in xs.iter().zip(&ys) {
+    ///     dot.mul_add_assign(x, y);
+    /// }
+    /// assert_eq!(dot, BigUint::from(32u32));
+    /// ```
+    pub fn mul_add_assign(&mut self, a: &BigUint, b: &BigUint) {
+        if a.is_zero() || b.is_zero() {
+            return;
+        }
+        // The sum has at most one more digit than the longer of the accumulator and the product.
+        let len = cmp::max(self.data.len(), a.data.len() + b.data.len()) + 1;
+        self.data.resize(len, 0);
+        mac3(&mut self.data, &a.data, &b.data);
+        self.normalize();
+    }
+}
+
+impl MulAdd<&BigUint, &BigUint> for &BigUint {
+    type Output = BigUint;
+
+    #[inline]
+    fn mul_add(self, a: &BigUint, b: &BigUint) -> BigUint {
+        BigUint::mul_add(self, a, b)
+    }
+}
+
 impl_product_iter_type!(BigUint);
 
 #[test]
@@ -624,3 +833,54 @@ fn test_sub_sign() {
     assert_eq!(sub_sign_i(&a.data, &b.data), &a_i - &b_i);
     assert_eq!(sub_sign_i(&b.data, &a.data), &b_i - &a_i);
 }
+
+/// Simple xorshift so the test digits are deterministic without pulling in `rand`.
+#[cfg(test)]
+pub(super) fn digits(state: &mut u64, len: usize) -> alloc::vec::Vec<BigDigit> {
+    (0..len)
+        .map(|_| {
+            *state ^= *state << 13;
+            *state ^= *state >> 7;
+            *state ^= *state << 17;
+            *state as BigDigit
+        })
+        .collect()
+}
+
+#[test]
+fn test_mul3_matches_long_multiplication() {
+    fn long_mul(x: &[BigDigit], y: &[BigDigit]) -> BigUint {
+        let mut prod = vec![0; x.len() + y.len() + 1];
+        for (i, xi) in x.iter().enumerate() {
+            mac_digit(&mut prod[i..], y, *xi);
+        }
+        biguint_from_vec(prod)
+    }
+
+    let mut state = 0x2545_f491_4f6c_dd1d;
+    // Sizes straddle the long multiplication, Karatsuba and Toom-3 thresholds,
+    // and the unbalanced pairs exercise Half-Karatsuba.
+    let sizes = [1, 31, 32, 33, 64, 100, 255, 256, 257, 400];
+    for &m in &sizes {
+        for &n in &sizes {
+            let x = digits(&mut state, m);
+            let y = digits(&mut state, n);
+            assert_eq!(mul3(&x, &y), long_mul(&x, &y), "{} x {} digits", m, n);
+        }
+    }
+}
+
+#[test]
+fn test_square_matches_mul3() {
+    let mut state = 0x9e37_79b9_7f4a_7c15;
+    // Sizes straddle the long squaring, Karatsuba and Toom-3 thresholds.
+    for &n in &[1, 2, 31, 32, 33, 64, 100, 255, 256, 257, 400, 1000] {
+        let x = digits(&mut state, n);
+        assert_eq!(square(&x), mul3(&x, &x), "{} digits", n);
+
+        // All ones maximizes the carries, and low zeros are skipped.
+        let mut y = vec![0; 3];
+        y.extend(core::iter::repeat(big_digit::MAX).take(n));
+        assert_eq!(square(&y), mul3(&y, &y), "{} digits of ones", n);
+    }
+}
@@ -0,0 +1,278 @@
+#![cfg(feature = "ntt-mul")]
+
+//! Multiplication by number-theoretic transform.
+//!
+//! The operands are split into 16-bit limbs and convolved exactly in two prime fields, then the
+//! results are combined with the Chinese remainder theorem. Each convolution term is less than
+//! `MAX_LEN * 2^32`, which is below the product of the two primes, so the result is exact.
+
+use super::addition::add2;
+
+use crate::big_digit::{self, BigDigit};
+
+use alloc::vec::Vec;
+
+/// `119 * 2^23 + 1`, with primitive root 3.
+const P1: u32 = 998_244_353;
+
+/// `7 * 2^26 + 1`, with primitive root 3.
+const P2: u32 = 469_762_049;
+
+const ROOT: u32 = 3;
+
+/// The inverse of `P1` modulo `P2`.
+const P1_INV_P2: u32 = 208_783_132;
+
+/// The largest transform supported by both primes, in 16-bit limbs.
+const MAX_LEN: usize = 1 << 23;
+
+const LIMB_BITS: u8 = 16;
+const LIMBS_PER_DIGIT: usize = (big_digit::BITS / LIMB_BITS) as usize;
+
+/// The shorter input must have at least this many 16-bit limbs to use the NTT, which is roughly
+/// where it overtakes Toom-3.
+const THRESHOLD: usize = 1 << 17;
+
+/// Returns true if `x * y` should use the NTT, where `x` is the shorter input.
+pub(super) fn use_ntt(x: &[BigDigit], y: &[BigDigit]) -> bool {
+    x.len() * LIMBS_PER_DIGIT >= THRESHOLD && fits(x, y)
+}
+
+/// Returns true if the product of `x` and `y` fits in a single transform.
+fn fits(x: &[BigDigit], y: &[BigDigit]) -> bool {
+    (x.len() + y.len()) * LIMBS_PER_DIGIT <= MAX_LEN
+}
+
+/// Three argument multiply accumulate:
+/// acc += b * c
+pub(super) fn mac3(acc: &mut [BigDigit], b: &[BigDigit], c: &[BigDigit]) {
+    debug_assert!(fits(b, c));
+
+    let len = ((b.len() + c.len()) * LIMBS_PER_DIGIT).next_power_of_two();
+
+    let mut b1 = to_limbs(b, len);
+    let mut c1 = to_limbs(c, len);
+    let mut b2 = b1.clone();
+    let mut c2 = c1.clone();
+
+    convolve(&mut b1, &mut c1, P1);
+    convolve(&mut b2, &mut c2, P2);
+
+    // Recombine each pair of residues and propagate the carries.
+    let mut prod = Vec::with_capacity(b.len() + c.len());
+    let mut digit: BigDigit = 0;
+    let mut carry: u64 = 0;
+    for (i, (&r1, &r2)) in b1.iter().zip(&b2).enumerate() {
+        let t = (u64::from(r2) + u64::from(P2) - u64::from(r1) % u64::from(P2))
+            * u64::from(P1_INV_P2)
+            % u64::from(P2);
+        carry += u64::from(r1) + t * u64::from(P1);
+
+        let limb = carry as u16;
+        carry >>= LIMB_BITS;
+
+        let k = i % LIMBS_PER_DIGIT;
+        digit |= BigDigit::from(limb) << (k * usize::from(LIMB_BITS));
+        if k == LIMBS_PER_DIGIT - 1 {
+            prod.push(digit);
+            digit = 0;
+        }
+    }
+    debug_assert_eq!(carry, 0);
+
+    // The transform length was padded, so trim the product before adding it.
+    while let Some(&0) = prod.last() {
+        prod.pop();
+    }
+    add2(acc, &prod);
+}
+
+fn to_limbs(x: &[BigDigit], len: usize) -> Vec<u32> {
+    let mut limbs = Vec::with_capacity(len);
+    for &d in x {
+        for k in 0..LIMBS_PER_DIGIT {
+            limbs.push(u32::from((d >> (k * usize::from(LIMB_BITS))) as u16));
+        }
+    }
+    limbs.resize(len, 0);
+    limbs
+}
+
+/// Arithmetic modulo one of the primes, with multiplication in Montgomery form (`R = 2^32`).
+#[derive(Clone, Copy)]
+struct Field {
+    p: u32,
+    /// `-p^-1 mod R`
+    neg_inv: u32,
+    /// `R^2 mod p`
+    r2: u32,
+}
+
+impl Field {
+    fn new(p: u32) -> Self {
+        // Newton's iteration doubles the correct low bits each time, starting from 3 bits.
+        let mut inv = p;
+        for _ in 0..4 {
+            inv = inv.wrapping_mul(2u32.wrapping_sub(p.wrapping_mul(inv)));
+        }
+        let r = (1u64 << 32) % u64::from(p);
+        Field {
+            p,
+            neg_inv: inv.wrapping_neg(),
+            r2: (r * r % u64::from(p)) as u32,
+        }
+    }
+
+    /// Returns `a * b / R mod p`.
+    #[inline]
+    fn mul(self, a: u32, b: u32) -> u32 {
+        let t = u64::from(a) * u64::from(b);
+        let m = (t as u32).wrapping_mul(self.neg_inv);
+        // Both primes are below 2^30, so this cannot overflow.
+        let u = ((t + u64::from(m) * u64::from(self.p)) >> 32) as u32;
+        if u >= self.p {
+            u - self.p
+        } else {
+            u
+        }
+    }
+
+    /// Converts `a` to Montgomery form, `a * R mod p`.
+    #[inline]
+    fn to_mont(self, a: u32) -> u32 {
+        self.mul(a, self.r2)
+    }
+
+    /// Returns `base^exp mod p`, in Montgomery form.
+    fn pow(self, base: u32, mut exp: u32) -> u32 {
+        let mut base = self.to_mont(base);
+        let mut acc = self.to_mont(1);
+        while exp > 0 {
+            if exp & 1 == 1 {
+                acc = self.mul(acc, base);
+            }
+            base = self.mul(base, base);
+            exp >>= 1;
+        }
+        acc
+    }
+}
+
+/// Computes the cyclic convolution of `a` and `b` modulo `p`, leaving it in `a`.
+fn convolve(a: &mut [u32], b: &mut [u32], p: u32) {
+    let f = Field::new(p);
+    let n = a.len();
+
+    // The forward transforms leave their output in bit-reversed order, which is fine for the
+    // pointwise products, and the inverse transform takes it back to natural order.
+    let roots = roots(f, n, false);
+    forward(a, f, &roots);
+    forward(b, f, &roots);
+
+    // The pointwise products come out divided by `R`, which we make up for along with the
+    // `1/n` of the inverse transform.
+    let scale = f.mul(f.pow(n as u32, p - 2), f.r2);
+    for (x, &y) in a.iter_mut().zip(b.iter()) {
+        *x = f.mul(f.mul(*x, y), scale);
+    }
+
+    let roots = self::roots(f, n, true);
+    inverse(a, f, &roots);
+}
+
+/// Returns the twiddle factors for every level of a transform of length `n`, in Montgomery
+/// form. The powers of the `len`-th root of unity start at index `len / 2`.
+fn roots(f: Field, n: usize, invert: bool) -> Vec<u32> {
+    let mut roots = vec![0; n.max(2)];
+    let mut len = 2;
+    while len <= n {
+        let exp = (f.p - 1) / len as u32;
+        let w = f.pow(ROOT, if invert { f.p - 1 - exp } else { exp });
+
+        let half = len / 2;
+        let mut r = f.to_mont(1);
+        for root in &mut roots[half..len] {
+            *root = r;
+            r = f.mul(r, w);
+        }
+        len <<= 1;
+    }
+    roots
+}
+
+/// In-place decimation-in-frequency transform, from natural to bit-reversed order.
+fn forward(a: &mut [u32], f: Field, roots: &[u32]) {
+    let p = f.p;
+    let mut len = a.len();
+    while len >= 2 {
+        let half = len / 2;
+        let roots = &roots[half..len];
+        for chunk in a.chunks_exact_mut(len) {
+            let (lo, hi) = chunk.split_at_mut(half);
+            for ((u, v), &r) in lo.iter_mut().zip(hi.iter_mut()).zip(roots) {
+                let (x, y) = (*u, *v);
+                *u = if x + y >= p { x + y - p } else { x + y };
+                *v = f.mul(if x >= y { x - y } else { x + p - y }, r);
+            }
+        }
+        len >>= 1;
+    }
+}
+
+/// In-place decimation-in-time transform, from bit-reversed to natural order, without the
+/// `1/n` scaling of a true inverse.
+fn inverse(a: &mut [u32], f: Field, roots: &[u32]) {
+    let p = f.p;
+    let mut len = 2;
+    while len <= a.len() {
+        let half = len / 2;
+        let roots = &roots[half..len];
+        for chunk in a.chunks_exact_mut(len) {
+            let (lo, hi) = chunk.split_at_mut(half);
+            for ((u, v), &r) in lo.iter_mut().zip(hi.iter_mut()).zip(roots) {
+                let x = *u;
+                let y = f.mul(*v, r);
+                *u = if x + y >= p { x + y - p } else { x + y };
+                *v = if x >= y { x - y } else { x + p - y };
+            }
+        }
+        len <<= 1;
+    }
+}
+
+#[test]
+fn test_constants() {
+    assert_eq!(u64::from(P1) * u64::from(P1_INV_P2) % u64::from(P2), 1);
+    assert_eq!((P1 - 1) % MAX_LEN as u32, 0);
+    assert_eq!((P2 - 1) % MAX_LEN as u32, 0);
+}
+
+#[test]
+fn test_ntt_mac3() {
+    use super::biguint_from_vec;
+    use super::BigUint;
+
+    fn long_mul(x: &[BigDigit], y: &[BigDigit]) -> BigUint {
+        let mut prod = BigUint::ZERO;
+        for (i, &d) in x.iter().enumerate() {
+            prod += (biguint_from_vec(y.to_vec()) * d) << (i * usize::from(big_digit::BITS));
+        }
+        prod
+    }
+
+    for &(m, n) in &[(1, 1), (3, 5), (100, 100), (257, 1000)] {
+        let x: Vec<BigDigit> = (0..m).map(|i| big_digit::MAX - i as BigDigit).collect();
+        let y: Vec<BigDigit> = (0..n)
+            .map(|i| big_digit::MAX / (i as BigDigit + 1))
+            .collect();
+        let mut acc = vec![0; m + n + 1];
+        mac3(&mut acc, &x, &y);
+        assert_eq!(
+            biguint_from_vec(acc),
+            long_mul(&x, &y),
+            "{} x {} digits",
+            m,
+            n
+        );
+    }
+}
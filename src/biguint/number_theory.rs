@@ -0,0 +1,512 @@
+//! Multiplicative functions of integers, computed from their prime factorization, and searches
+//! for nearby primes
+#![cfg(feature = "number-theory")]
+#![cfg_attr(docsrs, doc(cfg(feature = "number-theory")))]
+
+use super::{prime, BigUint};
+
+use alloc::vec::Vec;
+use num_traits::{Pow, ToPrimitive};
+
+#[cfg(feature = "rand")]
+use {
+    crate::{BarrettReducer, RandBigInt},
+    num_integer::Integer,
+    num_traits::One,
+    rand::Rng,
+};
+
+impl BigUint {
+    /// Returns Euler's totient function `φ(self)`, the number of integers in `1..=self` that are
+    /// coprime to `self`. Panics if `self` is zero.
+    ///
+    /// This factors `self` by trial division, with no bound on the divisors, so it takes time
+    /// proportional to the second largest prime factor of `self`. That is fast when every prime
+    /// factor except perhaps the largest is below about 10⁸, but for a product of two large
+    /// primes, such as an RSA modulus, it will not finish in any reasonable time.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::from(1u32).euler_totient(), BigUint::from(1u32));
+    /// assert_eq!(BigUint::from(36u32).euler_totient(), BigUint::from(12u32));
+    /// assert_eq!(BigUint::from(97u32).euler_totient(), BigUint::from(96u32));
+    /// ```
+    pub fn euler_totient(&self) -> BigUint {
+        assert!(!self.is_zero(), "totient of zero is undefined");
+        factor(self)
+            .into_iter()
+            .map(|(p, e)| Pow::pow(&p, e - 1) * (p - 1u32))
+            .product()
+    }
+
+    /// Returns the divisor function `σ₀(self)`, the number of divisors of `self`. Panics if
+    /// `self` is zero.
+    ///
+    /// Factors `self` like [`BigUint::euler_totient`].
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::from(1u32).sigma_0(), BigUint::from(1u32));
+    /// assert_eq!(BigUint::from(36u32).sigma_0(), BigUint::from(9u32));
+    /// ```
+    pub fn sigma_0(&self) -> BigUint {
+        assert!(!self.is_zero(), "zero has infinitely many divisors");
+        factor(self)
+            .into_iter()
+            .map(|(_, e)| BigUint::from(e) + 1u32)
+            .product()
+    }
+
+    /// Returns the divisor function `σ₁(self)`, the sum of the divisors of `self`. Panics if
+    /// `self` is zero.
+    ///
+    /// Factors `self` like [`BigUint::euler_totient`].
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::from(1u32).sigma_1(), BigUint::from(1u32));
+    /// assert_eq!(BigUint::from(36u32).sigma_1(), BigUint::from(91u32));
+    /// // 28 is a perfect number.
+    /// assert_eq!(BigUint::from(28u32).sigma_1(), BigUint::from(56u32));
+    /// ```
+    pub fn sigma_1(&self) -> BigUint {
+        assert!(!self.is_zero(), "zero has infinitely many divisors");
+        factor(self)
+            .into_iter()
+            .map(|(p, e)| (Pow::pow(&p, e + 1) - 1u32) / (p - 1u32))
+            .product()
+    }
+
+    /// Returns the number of divisors of `self`, the same as [`BigUint::sigma_0`]. Panics if
+    /// `self` is zero.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::from(12u32).num_divisors(), BigUint::from(6u32));
+    /// ```
+    #[inline]
+    pub fn num_divisors(&self) -> BigUint {
+        self.sigma_0()
+    }
+
+    /// Returns the sum of the divisors of `self`, the same as [`BigUint::sigma_1`]. Panics if
+    /// `self` is zero.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::from(12u32).sum_of_divisors(), BigUint::from(28u32));
+    /// ```
+    #[inline]
+    pub fn sum_of_divisors(&self) -> BigUint {
+        self.sigma_1()
+    }
+
+    /// Returns `true` if `self` is a perfect number, equal to the sum of its proper divisors, so
+    /// that `σ₁(self) == 2 * self`. Zero is not perfect.
+    ///
+    /// Factors `self` like [`BigUint::euler_totient`].
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert!(BigUint::from(28u32).is_perfect());
+    /// assert!(!BigUint::from(12u32).is_perfect());
+    /// ```
+    pub fn is_perfect(&self) -> bool {
+        !self.is_zero() && self.sigma_1() == self << 1u8
+    }
+
+    /// Returns `true` if `self` is an abundant number, less than the sum of its proper divisors,
+    /// so that `σ₁(self) > 2 * self`. Zero is not abundant.
+    ///
+    /// Factors `self` like [`BigUint::euler_totient`].
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert!(BigUint::from(12u32).is_abundant());
+    /// assert!(!BigUint::from(28u32).is_abundant());
+    /// ```
+    pub fn is_abundant(&self) -> bool {
+        !self.is_zero() && self.sigma_1() > self << 1u8
+    }
+
+    /// Returns the smallest prime greater than `self`.
+    ///
+    /// Candidates are first checked for small factors, and the rest are tested with
+    /// [`BigUint::is_prime`], so the result is prime with very high probability.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::from(0u32).next_prime(), BigUint::from(2u32));
+    /// assert_eq!(BigUint::from(2u32).next_prime(), BigUint::from(3u32));
+    /// assert_eq!(BigUint::from(89u32).next_prime(), BigUint::from(97u32));
+    /// ```
+    pub fn next_prime(&self) -> BigUint {
+        if self.bits() <= 1 {
+            return BigUint::from(2u32);
+        }
+        let start = if self.is_even() {
+            self + 1u32
+        } else {
+            self + 2u32
+        };
+        prime::step_to_prime(start, true).unwrap()
+    }
+
+    /// Returns the largest prime less than `self`, or `None` if `self` is at most 2.
+    ///
+    /// This searches like [`BigUint::next_prime`], in the other direction.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::from(2u32).prev_prime(), None);
+    /// assert_eq!(BigUint::from(3u32).prev_prime(), Some(BigUint::from(2u32)));
+    /// assert_eq!(BigUint::from(97u32).prev_prime(), Some(BigUint::from(89u32)));
+    /// ```
+    pub fn prev_prime(&self) -> Option<BigUint> {
+        match self.to_u32() {
+            Some(0..=2) => return None,
+            Some(3) => return Some(BigUint::from(2u32)),
+            _ => {}
+        }
+        let start = if self.is_even() {
+            self - 1u32
+        } else {
+            self - 2u32
+        };
+        prime::step_to_prime(start, false)
+    }
+}
+
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "number-theory", feature = "rand"))))]
+impl BigUint {
+    /// Returns the prime factors of `self`, with multiplicity, in increasing order. Panics if
+    /// `self` is zero.
+    ///
+    /// Factors below 1000 are found by trial division, and the rest by repeatedly splitting
+    /// composite cofactors with [`BigUint::factor_pollard_rho`]. That finds a factor `p` in
+    /// about `sqrt(p)` steps, so this is practical when all but the largest prime factor have
+    /// up to about 25 digits. Primes are recognized by [`BigUint::is_prime`].
+    ///
+    /// ```rust,ignore
+    /// use num_bigint::BigUint;
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let n = BigUint::from(1_000_000_007u64 * 998_244_353 * 12);
+    /// let factors = [2u32, 2, 3, 998_244_353, 1_000_000_007].map(BigUint::from);
+    /// assert_eq!(n.factorize(&mut rng), factors);
+    /// ```
+    pub fn factorize<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<BigUint> {
+        assert!(!self.is_zero(), "zero has no prime factorization");
+        let mut factors = Vec::new();
+        let mut n = self.clone();
+        for d in trial_divisors() {
+            while (&n % d).is_zero() {
+                n /= d;
+                factors.push(BigUint::from(d));
+            }
+        }
+
+        let mut composites = Vec::new();
+        if !n.is_one() {
+            composites.push(n);
+        }
+        while let Some(n) = composites.pop() {
+            match n.factor_pollard_rho(rng) {
+                Some(d) => {
+                    composites.push(&n / &d);
+                    composites.push(d);
+                }
+                None => factors.push(n),
+            }
+        }
+        factors.sort();
+        factors
+    }
+
+    /// Returns a nontrivial factor of `self`, or `None` if `self` is zero, one, or prime.
+    ///
+    /// Small factors are found by trial division up to 1000 first. Otherwise this runs Pollard's
+    /// rho algorithm with Floyd's cycle detection, from random starting points drawn from `rng`.
+    /// Perfect powers are split by their root. The factor found isn't necessarily prime or the
+    /// smallest.
+    ///
+    /// ```rust,ignore
+    /// use num_bigint::BigUint;
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let n = BigUint::from(1_000_000_007u64 * 998_244_353);
+    /// let p = n.factor_pollard_rho(&mut rng).unwrap();
+    /// assert!(p == BigUint::from(1_000_000_007u32) || p == BigUint::from(998_244_353u32));
+    /// ```
+    pub fn factor_pollard_rho<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<BigUint> {
+        if self.bits() <= 1 || self.is_prime() {
+            return None;
+        }
+        if let Some(d) = trial_divisors().find(|&d| (self % d).is_zero()) {
+            return Some(BigUint::from(d));
+        }
+        // `self` is now composite with no factors below 1000, so any root it is a perfect power
+        // of has at least 10 bits. Rho can't split prime powers quickly, so check those first.
+        for k in 2..=(self.bits() / 10) as u32 {
+            let root = self.nth_root(k);
+            if &Pow::pow(&root, k) == self {
+                return Some(root);
+            }
+        }
+        loop {
+            let c = rng.gen_biguint_range(&BigUint::one(), &(self - 1u32));
+            let start = rng.gen_biguint_below(self);
+            if let Some(d) = pollard_rho(self, start, &c) {
+                return Some(d);
+            }
+        }
+    }
+}
+
+/// Returns 2 and the odd numbers below 1000. Composites among them never divide what's left after
+/// their prime factors are removed, so these work as well as the primes for trial division.
+#[cfg(feature = "rand")]
+fn trial_divisors() -> impl Iterator<Item = u32> {
+    core::iter::once(2).chain((3..1000).step_by(2))
+}
+
+/// How many steps of the cycle search to take between `gcd` computations.
+#[cfg(feature = "rand")]
+const RHO_BATCH: usize = 64;
+
+/// Searches for a factor of `n` with the sequence `x -> x^2 + c mod n` from `start`, comparing
+/// `x_i` with `x_2i` as in Floyd's cycle detection. Returns `None` if the cycle closes without
+/// separating any factors, and the search should be repeated with a different `c`.
+///
+/// Differences are multiplied together so that only one `gcd` is needed per batch of steps, and
+/// a batch is stepped through again if its product shares every factor with `n`.
+#[cfg(feature = "rand")]
+fn pollard_rho(n: &BigUint, start: BigUint, c: &BigUint) -> Option<BigUint> {
+    let reducer = BarrettReducer::new(n.clone());
+    let f = |x: &BigUint| reducer.reduce(&(x.square() + c));
+    let distance = |x: &BigUint, y: &BigUint| if x > y { x - y } else { y - x };
+
+    let mut x = start.clone();
+    let mut y = start;
+    loop {
+        let (saved_x, saved_y) = (x.clone(), y.clone());
+        let mut product = BigUint::one();
+        for _ in 0..RHO_BATCH {
+            x = f(&x);
+            y = f(&f(&y));
+            product = reducer.reduce(&(product * distance(&x, &y)));
+        }
+        let d = product.gcd(n);
+        if d.is_one() {
+            continue;
+        }
+        if d != *n {
+            return Some(d);
+        }
+
+        // Some step in the batch found every factor at once, or the cycle closed, so retrace
+        // the batch one step at a time.
+        x = saved_x;
+        y = saved_y;
+        for _ in 0..RHO_BATCH {
+            x = f(&x);
+            y = f(&f(&y));
+            let d = distance(&x, &y).gcd(n);
+            if !d.is_one() {
+                return if d == *n { None } else { Some(d) };
+            }
+        }
+        unreachable!("a step in the batch has a common factor with n");
+    }
+}
+
+/// Returns the prime factorization of `n > 0`, as pairs of a prime and its exponent in
+/// increasing order of the primes.
+///
+/// Factors are found by trial division. Whenever the part left over is prime, as found by
+/// [`BigUint::is_prime`], it is taken as the last factor without further division.
+fn factor(n: &BigUint) -> Vec<(BigUint, u32)> {
+    let mut factors = Vec::new();
+    let mut m = n.clone();
+
+    let twos = m.trailing_zeros().unwrap();
+    if twos > 0 {
+        factors.push((BigUint::from(2u32), twos as u32));
+        m >>= twos;
+    }
+
+    let mut d = 3u64;
+    let mut check_prime = true;
+    while !m.is_one() {
+        if let Some(small) = m.to_u64() {
+            factor_u64(small, d, &mut factors);
+            break;
+        }
+        if check_prime {
+            if m.is_prime() {
+                factors.push((m, 1));
+                break;
+            }
+            check_prime = false;
+        }
+        let mut e = 0;
+        while (&m % d).is_zero() {
+            m /= d;
+            e += 1;
+        }
+        if e > 0 {
+            factors.push((BigUint::from(d), e));
+            check_prime = true;
+        }
+        d += 2;
+    }
+    factors
+}
+
+/// Factors odd `n` by trial division with odd divisors from `d`, where `n` has no smaller factors.
+fn factor_u64(mut n: u64, mut d: u64, factors: &mut Vec<(BigUint, u32)>) {
+    while n > 1 {
+        if d > n / d {
+            // No factor up to the square root, so `n` is prime.
+            factors.push((BigUint::from(n), 1));
+            break;
+        }
+        let mut e = 0;
+        while n % d == 0 {
+            n /= d;
+            e += 1;
+        }
+        if e > 0 {
+            factors.push((BigUint::from(d), e));
+        }
+        d += 2;
+    }
+}
+
+#[test]
+fn test_factor() {
+    fn product(factors: &[(BigUint, u32)]) -> BigUint {
+        factors.iter().map(|(p, e)| Pow::pow(p, *e)).product()
+    }
+
+    for n in 1u32..2000 {
+        let n = BigUint::from(n);
+        let factors = factor(&n);
+        assert_eq!(product(&factors), n);
+        assert!(factors.windows(2).all(|w| w[0].0 < w[1].0));
+        assert!(factors.iter().all(|(p, e)| p.is_prime() && *e > 0));
+    }
+
+    // A large prime cofactor after small factors.
+    let m127 = BigUint::from(2u32).pow(127u32) - 1u32;
+    let n = &m127 * 360u32;
+    let small = [(2u32, 3), (3, 2), (5, 1)];
+    let expected: Vec<_> = small.iter().map(|&(p, e)| (BigUint::from(p), e)).collect();
+    let mut factors = factor(&n);
+    assert_eq!(factors.pop(), Some((m127.clone(), 1)));
+    assert_eq!(factors, expected);
+
+    // A prime cofactor above 2^32, found after switching to `u64`.
+    let p = BigUint::from(10_007u32);
+    let q = BigUint::from(4_294_967_311u64);
+    let n = Pow::pow(&p, 3u32) * &q;
+    assert_eq!(factor(&n), [(p, 3), (q, 1)]);
+}
+
+#[test]
+fn test_multiplicative_functions() {
+    use num_integer::Integer;
+
+    for n in 1u32..500 {
+        let big = BigUint::from(n);
+        let coprime = (1..=n).filter(|k| k.gcd(&n) == 1).count();
+        let divisors: Vec<u32> = (1..=n).filter(|k| n % k == 0).collect();
+        assert_eq!(big.euler_totient(), BigUint::from(coprime), "φ({})", n);
+        assert_eq!(big.sigma_0(), BigUint::from(divisors.len()), "σ₀({})", n);
+        let sum: u32 = divisors.iter().sum();
+        assert_eq!(big.sigma_1(), BigUint::from(sum), "σ₁({})", n);
+        assert_eq!(big.num_divisors(), big.sigma_0());
+        assert_eq!(big.sum_of_divisors(), big.sigma_1());
+        assert_eq!(big.is_perfect(), sum == 2 * n, "{} perfect", n);
+        assert_eq!(big.is_abundant(), sum > 2 * n, "{} abundant", n);
+    }
+
+    // For a prime p, φ(p) = p - 1, σ₀(p) = 2 and σ₁(p) = p + 1.
+    let one = BigUint::from(1u32);
+    for p in [
+        BigUint::from(65_537u32),
+        BigUint::from(2u32).pow(89u32) - 1u32,
+    ] {
+        assert_eq!(p.euler_totient(), &p - 1u32);
+        assert_eq!(p.sigma_0(), BigUint::from(2u32));
+        assert_eq!(p.sigma_1(), &p + 1u32);
+        assert_eq!(p.num_divisors(), BigUint::from(2u32));
+        assert_eq!(p.sum_of_divisors(), &p + 1u32);
+        assert!(!p.is_perfect() && !p.is_abundant());
+    }
+
+    // Even perfect numbers are 2^(p-1) (2^p - 1) for Mersenne primes 2^p - 1.
+    for p in [2u32, 3, 5, 7, 13, 17, 19, 31, 89, 107] {
+        let n = (BigUint::from(1u32) << (p - 1)) * ((BigUint::from(1u32) << p) - 1u32);
+        assert!(n.is_perfect(), "{} perfect", n);
+        assert!(!n.is_abundant(), "{} abundant", n);
+    }
+    assert!(!BigUint::ZERO.is_perfect());
+    assert!(!BigUint::ZERO.is_abundant());
+
+    // And φ(p^k) = p^(k-1) (p - 1), σ₀(p^k) = k + 1.
+    let p = BigUint::from(65_537u32);
+    let cube = Pow::pow(&p, 3u32);
+    assert_eq!(cube.euler_totient(), Pow::pow(&p, 2u32) * (&p - 1u32));
+    assert_eq!(cube.sigma_0(), BigUint::from(4u32));
+
+    assert_eq!(one.euler_totient(), one);
+    assert_eq!(one.sigma_0(), one);
+    assert_eq!(one.sigma_1(), one);
+}
+
+#[test]
+fn test_next_prev_prime() {
+    let limit = 3000u32;
+    let primes: Vec<u32> = (2..limit)
+        .filter(|&n| (2..n).take_while(|d| d * d <= n).all(|d| n % d != 0))
+        .collect();
+
+    for n in 0..*primes.last().unwrap() {
+        let next = primes.iter().find(|&&p| p > n).unwrap();
+        let prev = primes.iter().rev().find(|&&p| p < n);
+        let big = BigUint::from(n);
+        assert_eq!(big.next_prime(), BigUint::from(*next), "next_prime({})", n);
+        assert_eq!(
+            big.prev_prime(),
+            prev.map(|&p| BigUint::from(p)),
+            "prev_prime({})",
+            n
+        );
+    }
+
+    // The primes nearest to 2^64 are 2^64 - 59 and 2^64 + 13.
+    let two64 = BigUint::from(1u32) << 64u32;
+    assert_eq!(two64.prev_prime(), Some(&two64 - 59u32));
+    assert_eq!(two64.next_prime(), &two64 + 13u32);
+    assert_eq!((&two64 - 59u32).next_prime(), &two64 + 13u32);
+    assert_eq!((&two64 + 13u32).prev_prime(), Some(&two64 - 59u32));
+
+    // Mersenne primes
+    let m89 = (BigUint::from(1u32) << 89u32) - 1u32;
+    let m127 = (BigUint::from(1u32) << 127u32) - 1u32;
+    assert_eq!((&m89 - 1u32).next_prime(), m89);
+    assert_eq!((&m127 + 1u32).prev_prime(), Some(m127.clone()));
+    assert!(m127.next_prime() > m127);
+    assert!(m127.prev_prime().unwrap() < m127);
+}
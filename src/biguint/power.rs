@@ -1,10 +1,15 @@
+use super::division::{div_rem_barrett, reciprocal};
 use super::monty::monty_modpow;
+use super::wrapping::truncate;
 use super::BigUint;
 
-use crate::big_digit::{self, BigDigit};
+use crate::big_digit;
 
-use num_integer::Integer;
-use num_traits::{One, Pow, ToPrimitive, Zero};
+use core::cmp;
+use num_traits::{One, Pow, ToPrimitive};
+
+#[cfg(test)]
+use {crate::big_digit::BigDigit, num_integer::Integer, num_traits::Zero};
 
 impl Pow<&BigUint> for BigUint {
     type Output = BigUint;
@@ -22,7 +27,7 @@ impl Pow<&BigUint> for BigUint {
         } else {
             // At this point, `self >= 2` and `exp >= 2¹²⁸`. The smallest possible result given
             // `2.pow(2¹²⁸)` would require far more memory than 64-bit targets can address!
-            panic!("memory overflow")
+            panic!("memory overflow: the power would have more than u64::MAX bits")
         }
     }
 }
@@ -69,10 +74,19 @@ macro_rules! pow_impl {
                 if exp == 0 {
                     return BigUint::one();
                 }
+                // The power of an `n`-bit number has at least `(n - 1) * exp + 1` bits, so refuse
+                // results whose length can't even be counted before running out of memory.
+                if self.bits() > 1 {
+                    let min_bits = u128::from(self.bits() - 1).checked_mul(exp as u128);
+                    assert!(
+                        min_bits.map_or(false, |bits| bits < u128::from(u64::MAX)),
+                        "memory overflow: the power would have more than u64::MAX bits"
+                    );
+                }
                 let mut base = self;
 
                 while exp & 1 == 0 {
-                    base = &base * &base;
+                    base = base.square();
                     exp >>= 1;
                 }
 
@@ -83,7 +97,7 @@ macro_rules! pow_impl {
                 let mut acc = base.clone();
                 while exp > 1 {
                     exp >>= 1;
-                    base = &base * &base;
+                    base = base.square();
                     if exp & 1 == 1 {
                         acc *= &base;
                     }
@@ -141,11 +155,86 @@ pub(super) fn modpow(x: &BigUint, exponent: &BigUint, modulus: &BigUint) -> BigU
         // For an odd modulus, we can use Montgomery multiplication in base 2^32.
         monty_modpow(x, exponent, modulus)
     } else {
-        // Otherwise do basically the same as `num::pow`, but with a modulus.
-        plain_modpow(x, &exponent.data, modulus)
+        even_modpow(x, exponent, modulus)
     }
 }
 
+/// Splits an even modulus into `2^k * m` with `m` odd, to use Montgomery multiplication modulo
+/// `m` and truncation modulo `2^k`, and then combines those results with the Chinese remainder
+/// theorem, as in HAC 14.75.
+fn even_modpow(x: &BigUint, exponent: &BigUint, modulus: &BigUint) -> BigUint {
+    let k = modulus.trailing_zeros().expect("the modulus is nonzero");
+    let odd = modulus >> k;
+    let r2 = pow2_modpow(x, exponent, k);
+    if odd.is_one() {
+        return r2;
+    }
+    let r1 = monty_modpow(x, exponent, &odd);
+
+    // Garner's formula: `r1 + odd * h` is `r1` modulo `odd`, and `h` makes it `r2` modulo `2^k`.
+    let inverse = odd
+        .modinv(&(BigUint::one() << k))
+        .expect("an odd number is invertible modulo a power of two");
+    let h = r2.wrapping_sub(&r1, k).wrapping_mul(&inverse, k);
+    r1 + odd * h
+}
+
+/// Returns `x^exponent mod 2^bits`, for `bits > 0`.
+fn pow2_modpow(x: &BigUint, exponent: &BigUint, bits: u64) -> BigUint {
+    if exponent.is_zero() {
+        return BigUint::one();
+    }
+    let base = truncate(x.clone(), bits);
+    let exponent = match base.trailing_zeros() {
+        None => return BigUint::ZERO,
+        // An even base to a power of at least `bits / zeros` is a multiple of `2^bits`.
+        Some(zeros) if zeros > 0 => match exponent.to_u64() {
+            Some(e) if e.checked_mul(zeros).map_or(false, |shift| shift < bits) => exponent.clone(),
+            _ => return BigUint::ZERO,
+        },
+        // Odd numbers modulo `2^bits` have an order dividing `2^(bits - 2)`, for `bits >= 3`.
+        Some(_) => truncate(exponent.clone(), cmp::max(bits.saturating_sub(2), 1)),
+    };
+
+    let mut acc = BigUint::one();
+    for i in (0..exponent.bits()).rev() {
+        acc = truncate(acc.square(), bits);
+        if exponent.bit(i) {
+            acc = truncate(acc * &base, bits);
+        }
+    }
+    acc
+}
+
+/// Moduli with at least this many digits use Barrett reduction instead of long division.
+const BARRETT_THRESHOLD: usize = 32;
+
+/// Reduces products of two residues modulo `modulus`.
+struct Reducer<'a> {
+    modulus: &'a BigUint,
+    mu: Option<BigUint>,
+}
+
+impl<'a> Reducer<'a> {
+    fn new(modulus: &'a BigUint) -> Self {
+        let mu = if modulus.data.len() >= BARRETT_THRESHOLD {
+            Some(reciprocal(modulus))
+        } else {
+            None
+        };
+        Reducer { modulus, mu }
+    }
+
+    fn reduce(&self, x: BigUint) -> BigUint {
+        match self.mu {
+            Some(ref mu) => div_rem_barrett(&x, self.modulus, mu).1,
+            None => x % self.modulus,
+        }
+    }
+}
+
+/// Does basically the same as `num::pow`, but with a modulus, to check the faster methods.
+#[cfg(test)]
 fn plain_modpow(base: &BigUint, exp_data: &[BigDigit], modulus: &BigUint) -> BigUint {
     assert!(
         !modulus.is_zero(),
@@ -157,17 +246,18 @@ fn plain_modpow(base: &BigUint, exp_data: &[BigDigit], modulus: &BigUint) -> Big
         Some(i) => i,
     };
 
+    let reducer = Reducer::new(modulus);
     let mut base = base % modulus;
     for _ in 0..i {
         for _ in 0..big_digit::BITS {
-            base = &base * &base % modulus;
+            base = reducer.reduce(base.square());
         }
     }
 
     let mut r = exp_data[i];
     let mut b = 0u8;
     while r.is_even() {
-        base = &base * &base % modulus;
+        base = reducer.reduce(base.square());
         r >>= 1;
         b += 1;
     }
@@ -183,10 +273,9 @@ fn plain_modpow(base: &BigUint, exp_data: &[BigDigit], modulus: &BigUint) -> Big
 
     {
         let mut unit = |exp_is_odd| {
-            base = &base * &base % modulus;
+            base = reducer.reduce(base.square());
             if exp_is_odd {
-                acc *= &base;
-                acc %= modulus;
+                acc = reducer.reduce(&acc * &base);
             }
         };
 
@@ -217,6 +306,31 @@ fn plain_modpow(base: &BigUint, exp_data: &[BigDigit], modulus: &BigUint) -> Big
     acc
 }
 
+pub(super) fn modpow_ct(x: &BigUint, exponent: &BigUint, modulus: &BigUint) -> BigUint {
+    assert!(
+        !modulus.is_zero(),
+        "attempt to calculate with zero modulus!"
+    );
+
+    let reducer = Reducer::new(modulus);
+
+    // Montgomery ladder, keeping r1 = r0 * x throughout.
+    let mut r0 = BigUint::one() % modulus;
+    let mut r1 = x % modulus;
+
+    // Every digit of the exponent is used in full, so leading zero bits cost the same time.
+    for &digit in exponent.data.iter().rev() {
+        for i in (0..big_digit::BITS).rev() {
+            let bit = (digit >> i) & 1 == 1;
+            r0.conditional_swap(&mut r1, bit);
+            r1 = reducer.reduce(&r0 * &r1);
+            r0 = reducer.reduce(r0.square());
+            r0.conditional_swap(&mut r1, bit);
+        }
+    }
+    r0
+}
+
 #[test]
 fn test_plain_modpow() {
     let two = &BigUint::from(2u32);
@@ -249,6 +363,54 @@ fn test_plain_modpow() {
     );
 }
 
+#[test]
+fn test_even_modpow() {
+    let mut state = 0x9e37_79b9_7f4a_7c15u64;
+    let mut random = |bits: u64| {
+        let mut x = BigUint::ZERO;
+        for i in 0..bits {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            x.set_bit(i, state & 1 == 1);
+        }
+        x
+    };
+
+    // Moduli with small and large powers of two, and with small and large odd parts
+    let mut moduli = vec![
+        BigUint::from(2u32),
+        BigUint::from(6u32),
+        BigUint::one() << 200u32,
+    ];
+    for (zeros, bits) in [(1, 100), (3, 300), (64, 64), (100, 30), (130, 150)] {
+        moduli.push(((random(bits) << 1u32) + 1u32) << zeros);
+    }
+
+    for modulus in &moduli {
+        for (base_bits, exp_bits) in [(1, 1), (10, 10), (100, 70), (400, 200), (500, 1)] {
+            let base = random(base_bits);
+            let exp = random(exp_bits);
+            // Each parity of the base and exponent, and the zero special cases
+            for base in [&base << 1u32, (&base << 1u32) + 1u32, BigUint::ZERO] {
+                for exp in [&exp << 1u32, (&exp << 1u32) + 1u32, BigUint::ZERO] {
+                    let expected = plain_modpow(&base, &exp.data, modulus);
+                    assert_eq!(even_modpow(&base, &exp, modulus), expected);
+                }
+            }
+        }
+    }
+
+    // A high power of two in the base exceeds a small power of two in the modulus.
+    let modulus = BigUint::from(12u32);
+    let base = BigUint::from(8u32);
+    assert_eq!(even_modpow(&base, &BigUint::from(1u32), &modulus), base);
+    assert_eq!(
+        even_modpow(&base, &BigUint::from(2u32), &modulus),
+        BigUint::from(4u32)
+    );
+}
+
 #[test]
 fn test_pow_biguint() {
     let base = BigUint::from(5u8);
@@ -0,0 +1,255 @@
+use super::division::rem_digit;
+use super::{biguint_from_vec, BigUint};
+
+use crate::big_digit::BigDigit;
+
+use alloc::vec::Vec;
+use num_traits::ToPrimitive;
+
+/// All of the primes below 256, for trial division.
+const SMALL_PRIMES: [u8; 54] = [
+    2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97,
+    101, 103, 107, 109, 113, 127, 131, 137, 139, 149, 151, 157, 163, 167, 173, 179, 181, 191, 193,
+    197, 199, 211, 223, 227, 229, 233, 239, 241, 251,
+];
+
+/// These witnesses make Miller-Rabin deterministic for every `n < 2^64`.
+/// See <https://en.wikipedia.org/wiki/Miller%E2%80%93Rabin_primality_test#Testing_against_small_sets_of_bases>.
+const U64_WITNESSES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+pub(super) fn is_prime_miller_rabin(n: &BigUint, rounds: usize) -> bool {
+    // Witnesses come from a SplitMix64 sequence, so results are reproducible.
+    let mut state = 0x9e37_79b9_7f4a_7c15_u64;
+    let mut next = move || {
+        state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    };
+
+    probably_prime(n, rounds, |bound| {
+        let len = bound.data.len() + 1;
+        let data: Vec<BigDigit> = (0..len).map(|_| next() as BigDigit).collect();
+        biguint_from_vec(data) % bound
+    })
+}
+
+/// Tests `n` for primality with `rounds` of Miller-Rabin, after trial division by small primes.
+///
+/// Numbers that fit in a `u64` are tested deterministically. Otherwise each round uses a witness
+/// `2 + gen(n - 3)`, where `gen(bound)` must return a value below `bound`.
+pub(crate) fn probably_prime<F>(n: &BigUint, rounds: usize, mut gen: F) -> bool
+where
+    F: FnMut(&BigUint) -> BigUint,
+{
+    if let Some(n) = n.to_u64() {
+        return is_prime_u64(n);
+    }
+
+    if SMALL_PRIMES
+        .iter()
+        .any(|&p| rem_digit(n, BigDigit::from(p)) == 0)
+    {
+        return false;
+    }
+
+    let n_minus_one = n - 1u32;
+    let s = n_minus_one.trailing_zeros().unwrap();
+    let d = &n_minus_one >> s;
+    let bound = n - 3u32;
+
+    (0..rounds).all(|_| {
+        let a = gen(&bound) + 2u32;
+        miller_rabin(n, &n_minus_one, &d, s, &a)
+    })
+}
+
+/// How far `search_prime` looks from its start before giving up.
+#[cfg(feature = "rand")]
+const SEARCH_LIMIT: u32 = 1 << 20;
+
+/// Searches the odd numbers `q = start, start + 2, ...` for one that is prime and, if `safe` is
+/// set, such that `2q + 1` is also prime. Returns the prime found, `2q + 1` if `safe` is set, or
+/// `None` if there is none before the bit length of `start` changes or `SEARCH_LIMIT` is reached.
+///
+/// Candidates with small factors are skipped using their residues modulo the small primes, which
+/// are only computed once. The rest are tested with `is_prime(n, rounds)`, first with a single
+/// round to rule out most composites cheaply.
+#[cfg(feature = "rand")]
+pub(crate) fn search_prime<F>(
+    start: BigUint,
+    safe: bool,
+    rounds: usize,
+    mut is_prime: F,
+) -> Option<BigUint>
+where
+    F: FnMut(&BigUint, usize) -> bool,
+{
+    debug_assert!(start.bit(0));
+    let bits = start.bits();
+    let odd_primes = &SMALL_PRIMES[1..];
+    let residues: Vec<u32> = odd_primes
+        .iter()
+        .map(|&p| rem_digit(&start, BigDigit::from(p)) as u32)
+        .collect();
+    // Above 8 bits, a candidate can't be one of the small primes itself.
+    let sieve = bits > 8;
+
+    let mut delta = 0;
+    while delta < SEARCH_LIMIT {
+        let survives = !sieve
+            || odd_primes.iter().zip(&residues).all(|(&p, &r)| {
+                let p = u32::from(p);
+                let r = (r + delta % p) % p;
+                r != 0 && !(safe && (2 * r + 1) % p == 0)
+            });
+        if survives {
+            let q = &start + delta;
+            if q.bits() > bits {
+                return None;
+            }
+            if safe {
+                let p = (&q << 1u32) + 1u32;
+                if is_prime(&q, 1)
+                    && is_prime(&p, 1)
+                    && is_prime(&q, rounds)
+                    && is_prime(&p, rounds)
+                {
+                    return Some(p);
+                }
+            } else if is_prime(&q, 1) && is_prime(&q, rounds) {
+                return Some(q);
+            }
+        }
+        delta += 2;
+    }
+    None
+}
+
+/// Returns the first prime in the odd numbers `start, start + 2, ...` if `up` is set, or in
+/// `start, start - 2, ...` down to 3 otherwise, where `start` is odd.
+///
+/// Like `search_prime`, candidates with small factors are skipped using their residues modulo
+/// the small primes, which are updated as the search moves.
+#[cfg(feature = "number-theory")]
+pub(crate) fn step_to_prime(start: BigUint, up: bool) -> Option<BigUint> {
+    debug_assert!(start.bit(0));
+    let odd_primes = &SMALL_PRIMES[1..];
+    let mut residues: Vec<u8> = odd_primes
+        .iter()
+        .map(|&p| rem_digit(&start, BigDigit::from(p)) as u8)
+        .collect();
+
+    let mut n = start;
+    loop {
+        // At most 8 bits, a candidate could be one of the small primes itself.
+        let survives = n.bits() <= 8 || residues.iter().all(|&r| r != 0);
+        if survives && n.is_prime() {
+            return Some(n);
+        }
+
+        if up {
+            n += 2u32;
+            for (r, &p) in residues.iter_mut().zip(odd_primes) {
+                *r = ((u16::from(*r) + 2) % u16::from(p)) as u8;
+            }
+        } else {
+            if n.bits() <= 2 {
+                return None;
+            }
+            n -= 2u32;
+            for (r, &p) in residues.iter_mut().zip(odd_primes) {
+                *r = ((u16::from(*r) + u16::from(p) - 2) % u16::from(p)) as u8;
+            }
+        }
+    }
+}
+
+/// Returns false if `a` witnesses that `n` is composite, where `n - 1 = d * 2^s` with odd `d`.
+fn miller_rabin(n: &BigUint, n_minus_one: &BigUint, d: &BigUint, s: u64, a: &BigUint) -> bool {
+    let mut x = a.modpow(d, n);
+    if x.is_one() || x == *n_minus_one {
+        return true;
+    }
+    for _ in 1..s {
+        x = x.square() % n;
+        if x == *n_minus_one {
+            return true;
+        }
+        if x.is_one() {
+            return false;
+        }
+    }
+    false
+}
+
+fn is_prime_u64(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+    for &p in &SMALL_PRIMES {
+        let p = u64::from(p);
+        if n % p == 0 {
+            return n == p;
+        }
+    }
+    if n < 256 * 256 {
+        return true;
+    }
+
+    let mul_mod = |a: u64, b: u64| (u128::from(a) * u128::from(b) % u128::from(n)) as u64;
+    let pow_mod = |mut base: u64, mut exp: u64| {
+        let mut acc = 1;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                acc = mul_mod(acc, base);
+            }
+            base = mul_mod(base, base);
+            exp >>= 1;
+        }
+        acc
+    };
+
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+    U64_WITNESSES.iter().all(|&a| {
+        let mut x = pow_mod(a, d);
+        if x == 1 || x == n - 1 {
+            return true;
+        }
+        for _ in 1..s {
+            x = mul_mod(x, x);
+            if x == n - 1 {
+                return true;
+            }
+        }
+        false
+    })
+}
+
+#[test]
+fn test_is_prime_u64() {
+    let sieve: Vec<bool> = {
+        let mut sieve = vec![true; 70_000];
+        sieve[0] = false;
+        sieve[1] = false;
+        for i in 2..sieve.len() {
+            if sieve[i] {
+                for j in (i * i..sieve.len()).step_by(i) {
+                    sieve[j] = false;
+                }
+            }
+        }
+        sieve
+    };
+    for (n, &prime) in sieve.iter().enumerate() {
+        assert_eq!(is_prime_u64(n as u64), prime, "{}", n);
+    }
+
+    // Strong pseudoprimes to several of the smaller bases
+    assert!(!is_prime_u64(3_215_031_751));
+    assert!(!is_prime_u64(3_825_123_056_546_413_051));
+    assert!(is_prime_u64(u64::MAX - 58));
+    assert!(!is_prime_u64(u64::MAX));
+}
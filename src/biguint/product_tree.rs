@@ -0,0 +1,124 @@
+//! Product and remainder trees, for reducing a value modulo many moduli at once
+
+use super::BigUint;
+
+use alloc::vec::Vec;
+use num_traits::One;
+
+/// A product tree of fixed moduli, for reducing values modulo each of them at once.
+///
+/// The leaves are the moduli and each node above them is the product of its two children, so
+/// the root is the product of all the moduli. [`ProductTree::reduce`] walks back down as a
+/// remainder tree: it reduces a value by the root once, and then each node's remainder by its
+/// children, so every division is by a modulus about the size of the value it divides.
+///
+/// With `k` moduli of `s` bits in total, the tree has `⌈log₂ k⌉` levels whose products add up to
+/// about `s` bits each. Building it or reducing a value of at most `s` bits costs `O(M(s) log k)`,
+/// where `M(s)` is the cost of multiplying or dividing `s`-bit numbers. With quasi-linear
+/// multiplication, like the `ntt-mul` feature, that is `O(s log² s)`, against `O(s²)` for
+/// dividing by every modulus in turn.
+///
+/// # Examples
+///
+/// ```
+/// use num_bigint::{BigUint, ProductTree};
+///
+/// let moduli = [3u32, 5, 7, 11].map(BigUint::from);
+/// let tree = ProductTree::new(&moduli);
+/// assert_eq!(tree.product(), &BigUint::from(1155u32));
+///
+/// let n = BigUint::from(1_000_000u32);
+/// assert_eq!(tree.reduce(&n), [1u32, 0, 1, 1].map(BigUint::from));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProductTree {
+    // `levels[0]` holds the moduli, and each later level the products of pairs from the one
+    // before, with an odd node out carried up as it is. The last level is the root alone, which
+    // is one above an empty level of moduli.
+    levels: Vec<Vec<BigUint>>,
+}
+
+impl ProductTree {
+    /// Creates the product tree of `moduli`.
+    ///
+    /// Panics if any modulus is zero.
+    pub fn new(moduli: &[BigUint]) -> Self {
+        assert!(
+            moduli.iter().all(|m| !m.is_zero()),
+            "attempt to calculate with zero modulus!"
+        );
+        let mut levels = Vec::new();
+        let mut level = moduli.to_vec();
+        while level.len() > 1 {
+            let next = level
+                .chunks(2)
+                .map(|pair| match pair {
+                    [a, b] => a * b,
+                    _ => pair[0].clone(),
+                })
+                .collect();
+            levels.push(level);
+            level = next;
+        }
+        if level.is_empty() {
+            // Without moduli, the root is the empty product.
+            levels.push(level);
+            level = vec![BigUint::one()];
+        }
+        levels.push(level);
+        ProductTree { levels }
+    }
+
+    /// Returns the moduli, the leaves of the tree.
+    #[inline]
+    pub fn moduli(&self) -> &[BigUint] {
+        &self.levels[0]
+    }
+
+    /// Returns the product of all the moduli, the root of the tree, which is one if there are
+    /// none.
+    #[inline]
+    pub fn product(&self) -> &BigUint {
+        &self.levels[self.levels.len() - 1][0]
+    }
+
+    /// Returns `n % m` for each of the moduli `m`, in order.
+    pub fn reduce(&self, n: &BigUint) -> Vec<BigUint> {
+        let mut remainders = vec![n % self.product()];
+        for level in self.levels.iter().rev().skip(1) {
+            remainders = level
+                .iter()
+                .enumerate()
+                .map(|(i, m)| &remainders[i / 2] % m)
+                .collect();
+        }
+        remainders
+    }
+}
+
+impl BigUint {
+    /// Returns `n % m` for each of the `moduli`, in order, by way of their product tree.
+    ///
+    /// This divides `n` by the product of all the moduli once, and then splits the remainder
+    /// down a [`ProductTree`], which is much faster than dividing a large `n` by each modulus in
+    /// turn when there are many of them. To reduce many values by the same moduli, create the
+    /// [`ProductTree`] once and use [`ProductTree::reduce`].
+    ///
+    /// Panics if any modulus is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let n = BigUint::from(10u32).pow(30);
+    /// let moduli = [7u32, 97, 65537].map(BigUint::from);
+    /// let remainders = BigUint::batch_reduce(&n, &moduli);
+    /// for (r, m) in remainders.iter().zip(&moduli) {
+    ///     assert_eq!(*r, &n % m);
+    /// }
+    /// ```
+    pub fn batch_reduce(n: &BigUint, moduli: &[BigUint]) -> Vec<BigUint> {
+        ProductTree::new(moduli).reduce(n)
+    }
+}
@@ -0,0 +1,152 @@
+//! Iterators over ranges of `BigUint`, since `core::iter::Step` is unstable
+
+use super::BigUint;
+
+use core::iter::FusedIterator;
+use core::mem;
+use core::ops::Range;
+use num_integer::Integer;
+use num_traits::ToPrimitive;
+
+/// Returns the size hint for `len` remaining items.
+fn size_hint(len: BigUint) -> (usize, Option<usize>) {
+    match len.to_usize() {
+        Some(len) => (len, Some(len)),
+        None => (usize::MAX, None),
+    }
+}
+
+/// An iterator of the [`BigUint`] values from `start` up to but not including `end`.
+///
+/// The standard `start..end` ranges can't iterate over [`BigUint`] on stable Rust, but they
+/// convert into this.
+///
+/// # Examples
+///
+/// ```
+/// use num_bigint::{BigUint, BigUintRange};
+///
+/// let start = BigUint::from(u64::MAX);
+/// let end = &start + 3u32;
+/// let values: Vec<BigUint> = BigUintRange::from(start.clone()..end).collect();
+/// assert_eq!(values, [&start + 0u32, &start + 1u32, &start + 2u32]);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BigUintRange {
+    start: BigUint,
+    end: BigUint,
+}
+
+impl BigUintRange {
+    /// Creates a range from `start` up to but not including `end`, which is empty if `end` is
+    /// not greater than `start`.
+    #[inline]
+    pub fn new(start: BigUint, end: BigUint) -> Self {
+        BigUintRange { start, end }
+    }
+}
+
+impl From<Range<BigUint>> for BigUintRange {
+    #[inline]
+    fn from(range: Range<BigUint>) -> Self {
+        BigUintRange::new(range.start, range.end)
+    }
+}
+
+impl Iterator for BigUintRange {
+    type Item = BigUint;
+
+    fn next(&mut self) -> Option<BigUint> {
+        if self.start < self.end {
+            let next = &self.start + 1u32;
+            Some(mem::replace(&mut self.start, next))
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.start < self.end {
+            size_hint(&self.end - &self.start)
+        } else {
+            (0, Some(0))
+        }
+    }
+
+    fn nth(&mut self, n: usize) -> Option<BigUint> {
+        self.start += n;
+        self.next()
+    }
+}
+
+impl DoubleEndedIterator for BigUintRange {
+    fn next_back(&mut self) -> Option<BigUint> {
+        if self.start < self.end {
+            self.end -= 1u32;
+            Some(self.end.clone())
+        } else {
+            None
+        }
+    }
+}
+
+impl FusedIterator for BigUintRange {}
+
+/// An iterator of the [`BigUint`] values from `start` up to but not including `end`, counting
+/// by `step`.
+///
+/// This is created by [`BigUint::range`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BigUintStepRange {
+    start: BigUint,
+    end: BigUint,
+    step: BigUint,
+}
+
+impl BigUint {
+    /// Returns an iterator of the values from `start` up to but not including `end`, counting
+    /// by `step`. Panics if `step` is zero.
+    ///
+    /// For a step of one, [`BigUintRange`] also iterates backwards.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let range = BigUint::range(1u32.into(), 10u32.into(), 4u32.into());
+    /// assert_eq!(range.collect::<Vec<_>>(), [1u32, 5, 9].map(BigUint::from));
+    /// ```
+    pub fn range(start: BigUint, end: BigUint, step: BigUint) -> BigUintStepRange {
+        assert!(!step.is_zero(), "range step must not be zero");
+        BigUintStepRange { start, end, step }
+    }
+}
+
+impl Iterator for BigUintStepRange {
+    type Item = BigUint;
+
+    fn next(&mut self) -> Option<BigUint> {
+        if self.start < self.end {
+            let next = &self.start + &self.step;
+            Some(mem::replace(&mut self.start, next))
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.start < self.end {
+            size_hint(Integer::div_ceil(&(&self.end - &self.start), &self.step))
+        } else {
+            (0, Some(0))
+        }
+    }
+
+    fn nth(&mut self, n: usize) -> Option<BigUint> {
+        self.start += &self.step * n;
+        self.next()
+    }
+}
+
+impl FusedIterator for BigUintStepRange {}
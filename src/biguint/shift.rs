@@ -91,6 +91,45 @@ fn biguint_shr2(n: Cow<'_, BigUint>, digits: usize, shift: u8) -> BigUint {
     biguint_from_vec(data)
 }
 
+impl BigUint {
+    /// Returns `self * 2^bits`, the same as `self << bits`.
+    ///
+    /// Panics if the result would be too large to allocate, which for a nonzero value is
+    /// always the case when `bits` doesn't fit in `usize`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let mantissa = BigUint::from(3u32);
+    /// assert_eq!(mantissa.mul_pow2(100), BigUint::from(3u32) << 100);
+    /// assert_eq!(BigUint::ZERO.mul_pow2(u64::MAX), BigUint::ZERO);
+    /// ```
+    #[inline]
+    pub fn mul_pow2(&self, bits: u64) -> BigUint {
+        biguint_shl(Cow::Borrowed(self), bits)
+    }
+
+    /// Returns `self / 2^bits`, rounded down, the same as `self >> bits`.
+    ///
+    /// This is zero if `bits` is at least [`self.bits()`][BigUint::bits], however large.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let n = BigUint::from(0xff00u32);
+    /// assert_eq!(n.div_pow2(8), BigUint::from(0xffu32));
+    /// assert_eq!(n.div_pow2(u64::MAX), BigUint::ZERO);
+    /// ```
+    #[inline]
+    pub fn div_pow2(&self, bits: u64) -> BigUint {
+        biguint_shr(Cow::Borrowed(self), bits)
+    }
+}
+
 macro_rules! impl_shift {
     (@ref $Shx:ident :: $shx:ident, $ShxAssign:ident :: $shx_assign:ident, $rhs:ty) => {
         impl $Shx<&$rhs> for BigUint {
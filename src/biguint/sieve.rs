@@ -0,0 +1,93 @@
+//! A segmented sieve of Eratosthenes, with each segment held in a `BigUint` as a bitset
+#![cfg(feature = "number-theory")]
+#![cfg_attr(docsrs, doc(cfg(feature = "number-theory")))]
+
+use super::BigUint;
+
+use alloc::vec::Vec;
+use core::cmp;
+use num_integer::Roots;
+use num_traits::{One, ToPrimitive};
+
+impl BigUint {
+    /// Returns all the primes up to and including `limit`, in increasing order.
+    ///
+    /// This is a segmented sieve of Eratosthenes: the range is sieved in blocks of `⌊√limit⌋ + 1`
+    /// numbers, each a bitset in a [`BigUint`] whose bits are cleared at the multiples of the
+    /// primes up to `√limit`, which all come from the first block. Only one block is held at a
+    /// time, so it stays small enough for the cache while the list of primes grows.
+    ///
+    /// Panics if `limit` doesn't fit in a `u64`, though the primes below it would not fit in
+    /// memory long before that.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let primes = BigUint::prime_sieve(&BigUint::from(30u32));
+    /// assert_eq!(primes, [2u32, 3, 5, 7, 11, 13, 17, 19, 23, 29].map(BigUint::from));
+    /// assert_eq!(BigUint::prime_sieve(&BigUint::from(1_000_000u32)).len(), 78498);
+    /// ```
+    pub fn prime_sieve(limit: &BigUint) -> Vec<BigUint> {
+        let limit = limit.to_u64().expect("the sieve limit is too large");
+        let segment_len = limit.sqrt() + 1;
+
+        // The primes in the first segment, which include all those up to `√limit`.
+        let mut base_primes: Vec<u64> = Vec::new();
+        let mut primes = Vec::new();
+        let mut low = 0;
+        while low <= limit {
+            let high = cmp::min(low + (segment_len - 1), limit);
+            let len = high - low + 1;
+            let mut segment = (BigUint::one() << len) - 1u32;
+
+            if low == 0 {
+                segment.clear_bit(0);
+                segment.clear_bit(1);
+                let mut p = 2;
+                while p * p <= high {
+                    if segment.test_bit(p) {
+                        clear_multiples(&mut segment, 0, high, p, p * p);
+                    }
+                    p += 1;
+                }
+            } else {
+                for &p in base_primes.iter().take_while(|&&p| p * p <= high) {
+                    let first = cmp::max(p * p, (low + (p - 1)) / p * p);
+                    clear_multiples(&mut segment, low, high, p, first);
+                }
+            }
+
+            for (i, mut word) in segment.iter_u64_digits().enumerate() {
+                while word != 0 {
+                    let n = low + i as u64 * 64 + u64::from(word.trailing_zeros());
+                    if low == 0 {
+                        base_primes.push(n);
+                    }
+                    primes.push(BigUint::from(n));
+                    word &= word - 1;
+                }
+            }
+
+            low = match high.checked_add(1) {
+                Some(next) => next,
+                None => break,
+            };
+        }
+        primes
+    }
+}
+
+/// Clears the bits of `segment`, which holds the numbers from `low` to `high`, at the multiples
+/// of `p` from `first`.
+fn clear_multiples(segment: &mut BigUint, low: u64, high: u64, p: u64, first: u64) {
+    let mut m = first;
+    while m <= high {
+        segment.clear_bit(m - low);
+        m = match m.checked_add(p) {
+            Some(next) => next,
+            None => break,
+        };
+    }
+}
@@ -0,0 +1,65 @@
+use super::division::rem_digit;
+use super::BigUint;
+
+use crate::big_digit::BigDigit;
+use num_integer::Roots;
+
+/// Returns a mask with bit `r` set for each square `r` modulo `m <= 128`.
+const fn squares_mod(m: u32) -> u128 {
+    let mut mask = 0;
+    let mut x = 0;
+    while x < m {
+        mask |= 1 << (x * x % m);
+        x += 1;
+    }
+    mask
+}
+
+const SQUARES_MOD_64: u128 = squares_mod(64);
+const SQUARES_MOD_63: u128 = squares_mod(63);
+const SQUARES_MOD_65: u128 = squares_mod(65);
+const SQUARES_MOD_11: u128 = squares_mod(11);
+
+/// Returns true if `n` is the square of an integer.
+///
+/// Most non-squares are rejected by their residues modulo 64, 63, 65 and 11, which only let
+/// through 12/64, 16/63, 21/65 and 6/11 of values, leaving less than 1% for the square root.
+/// These are the filters that GMP uses.
+pub(super) fn is_perfect_square(n: &BigUint) -> bool {
+    let low = n.data.first().map_or(0, |&d| d & 63);
+    if SQUARES_MOD_64 >> low & 1 == 0 {
+        return false;
+    }
+
+    // One division gives all three residues, since 63 * 65 * 11 = 45045.
+    let r = rem_digit(n, 45045 as BigDigit);
+    if SQUARES_MOD_63 >> (r % 63) & 1 == 0
+        || SQUARES_MOD_65 >> (r % 65) & 1 == 0
+        || SQUARES_MOD_11 >> (r % 11) & 1 == 0
+    {
+        return false;
+    }
+
+    let root = Roots::sqrt(n);
+    root.square() == *n
+}
+
+#[test]
+fn test_squares_mod() {
+    assert_eq!(SQUARES_MOD_64.count_ones(), 12);
+    assert_eq!(SQUARES_MOD_63.count_ones(), 16);
+    assert_eq!(SQUARES_MOD_65.count_ones(), 21);
+    assert_eq!(SQUARES_MOD_11.count_ones(), 6);
+}
+
+#[test]
+fn test_is_perfect_square() {
+    let mut next_root = 0u32;
+    for n in 0u32..100_000 {
+        let square = next_root * next_root == n;
+        if square {
+            next_root += 1;
+        }
+        assert_eq!(is_perfect_square(&BigUint::from(n)), square, "{}", n);
+    }
+}
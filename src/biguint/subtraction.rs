@@ -300,6 +300,29 @@ impl Sub<BigUint> for u128 {
     );
 }
 
+impl BigUint {
+    /// Returns the absolute difference `|self - other|`, subtracting the smaller value from the
+    /// larger so that it can't underflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let a = BigUint::from(3u32);
+    /// let b = BigUint::from(10u32);
+    /// assert_eq!(a.abs_diff(&b), BigUint::from(7u32));
+    /// assert_eq!(b.abs_diff(&a), BigUint::from(7u32));
+    /// ```
+    pub fn abs_diff(&self, other: &BigUint) -> BigUint {
+        match self.cmp(other) {
+            Less => other - self,
+            Equal => Self::ZERO,
+            Greater => self - other,
+        }
+    }
+}
+
 impl CheckedSub for BigUint {
     #[inline]
     fn checked_sub(&self, v: &BigUint) -> Option<BigUint> {
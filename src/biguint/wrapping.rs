@@ -0,0 +1,276 @@
+//! Arithmetic modulo `2^bits`, for fixed-width registers and counters
+
+use super::BigUint;
+use crate::big_digit;
+
+use core::fmt;
+use core::ops::{Add, AddAssign, Mul, MulAssign, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign};
+
+/// Returns `x mod 2^bits`, by dropping the higher bits.
+pub(super) fn truncate(mut x: BigUint, bits: u64) -> BigUint {
+    let digit_bits = u64::from(big_digit::BITS);
+    let digits = bits / digit_bits;
+    if (x.data.len() as u64) > digits {
+        // `digits` is less than the length, so it fits in a `usize`.
+        let digits = digits as usize;
+        let rem = bits % digit_bits;
+        if rem == 0 {
+            x.data.truncate(digits);
+        } else {
+            x.data.truncate(digits + 1);
+            x.data[digits] &= (1 << rem) - 1;
+        }
+        x.normalize();
+    }
+    x
+}
+
+impl BigUint {
+    /// Returns `(self + rhs) mod 2^bits`.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let a = BigUint::from(200u32);
+    /// assert_eq!(a.wrapping_add(&BigUint::from(100u32), 8), BigUint::from(44u32));
+    /// ```
+    pub fn wrapping_add(&self, rhs: &Self, bits: u64) -> Self {
+        truncate(self + rhs, bits)
+    }
+
+    /// Returns `(self - rhs) mod 2^bits`, which wraps around to the top of the range when `rhs`
+    /// is larger.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let a = BigUint::from(1u32);
+    /// assert_eq!(a.wrapping_sub(&BigUint::from(2u32), 8), BigUint::from(255u32));
+    /// ```
+    pub fn wrapping_sub(&self, rhs: &Self, bits: u64) -> Self {
+        let a = truncate(self.clone(), bits);
+        let b = truncate(rhs.clone(), bits);
+        if a >= b {
+            a - b
+        } else {
+            // 0 < b - a < 2^bits, so this is in range.
+            (BigUint::from(1u32) << bits) - (b - a)
+        }
+    }
+
+    /// Returns `(self * rhs) mod 2^bits`.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let a = BigUint::from(u64::MAX);
+    /// assert_eq!(a.wrapping_mul(&a, 64), BigUint::from(1u32));
+    /// ```
+    pub fn wrapping_mul(&self, rhs: &Self, bits: u64) -> Self {
+        let a = truncate(self.clone(), bits);
+        let b = truncate(rhs.clone(), bits);
+        truncate(a * b, bits)
+    }
+
+    /// Returns `(self << shift) mod 2^bits`, so bits shifted past the width are lost.
+    ///
+    /// Unlike the primitive `wrapping_shl`, the shift amount isn't masked, so shifting by
+    /// `bits` or more gives zero.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let a = BigUint::from(0b1011u32);
+    /// assert_eq!(a.wrapping_shl(2, 4), BigUint::from(0b1100u32));
+    /// assert_eq!(a.wrapping_shl(4, 4), BigUint::from(0u32));
+    /// ```
+    pub fn wrapping_shl(&self, shift: u64, bits: u64) -> Self {
+        if shift >= bits {
+            return BigUint::ZERO;
+        }
+        truncate(self.clone(), bits - shift) << shift
+    }
+}
+
+/// An unsigned integer of a fixed width of `bits` bits, with arithmetic that wraps around modulo
+/// `2^bits`, like the primitive integers with [`Wrapping`][core::num::Wrapping].
+///
+/// The operators combine two values of the same width, and panic if the widths differ. Shifts
+/// take a primitive amount, and left shifts drop the bits shifted past the width.
+///
+/// ```
+/// use num_bigint::{BigUint, WrappingBigUint};
+///
+/// let a = WrappingBigUint::new(BigUint::from(250u32), 8);
+/// let b = WrappingBigUint::new(BigUint::from(10u32), 8);
+/// assert_eq!((&a + &b).value(), &BigUint::from(4u32));
+/// assert_eq!((&b - &a).value(), &BigUint::from(16u32));
+/// assert_eq!((&a * &b).value(), &BigUint::from(196u32));
+/// assert_eq!((a << 4u32).value(), &BigUint::from(160u32));
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct WrappingBigUint {
+    value: BigUint,
+    bits: u64,
+}
+
+impl WrappingBigUint {
+    /// Creates a value of width `bits` from `value mod 2^bits`.
+    #[inline]
+    pub fn new(value: BigUint, bits: u64) -> Self {
+        WrappingBigUint {
+            value: truncate(value, bits),
+            bits,
+        }
+    }
+
+    /// Returns the value, which is always less than `2^bits`.
+    #[inline]
+    pub fn value(&self) -> &BigUint {
+        &self.value
+    }
+
+    /// Returns the width in bits.
+    #[inline]
+    pub fn bits(&self) -> u64 {
+        self.bits
+    }
+
+    /// Returns the value, discarding the width.
+    #[inline]
+    pub fn into_inner(self) -> BigUint {
+        self.value
+    }
+
+    #[inline]
+    fn check_width(&self, other: &Self) {
+        assert_eq!(
+            self.bits, other.bits,
+            "attempt to combine wrapping integers of different widths"
+        );
+    }
+}
+
+impl fmt::Debug for WrappingBigUint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} (mod 2^{})", self.value, self.bits)
+    }
+}
+
+impl fmt::Display for WrappingBigUint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.value, f)
+    }
+}
+
+impl From<WrappingBigUint> for BigUint {
+    #[inline]
+    fn from(x: WrappingBigUint) -> Self {
+        x.value
+    }
+}
+
+macro_rules! impl_wrapping_binop {
+    ($Op:ident :: $op:ident, $OpAssign:ident :: $op_assign:ident, $wrapping:ident) => {
+        impl $Op<&WrappingBigUint> for WrappingBigUint {
+            type Output = WrappingBigUint;
+
+            #[inline]
+            fn $op(mut self, other: &WrappingBigUint) -> WrappingBigUint {
+                self.$op_assign(other);
+                self
+            }
+        }
+
+        impl $OpAssign<&WrappingBigUint> for WrappingBigUint {
+            #[inline]
+            fn $op_assign(&mut self, other: &WrappingBigUint) {
+                self.check_width(other);
+                self.value = self.value.$wrapping(&other.value, self.bits);
+            }
+        }
+
+        forward_val_val_binop!(impl $Op for WrappingBigUint, $op);
+        forward_ref_ref_binop!(impl $Op for WrappingBigUint, $op);
+        forward_ref_val_binop!(impl $Op for WrappingBigUint, $op);
+        forward_val_assign!(impl $OpAssign for WrappingBigUint, $op_assign);
+    };
+}
+
+impl_wrapping_binop!(Add::add, AddAssign::add_assign, wrapping_add);
+impl_wrapping_binop!(Sub::sub, SubAssign::sub_assign, wrapping_sub);
+impl_wrapping_binop!(Mul::mul, MulAssign::mul_assign, wrapping_mul);
+
+macro_rules! impl_wrapping_shift {
+    ($($rhs:ty),+) => {$(
+        impl Shl<$rhs> for WrappingBigUint {
+            type Output = WrappingBigUint;
+
+            #[inline]
+            fn shl(mut self, rhs: $rhs) -> WrappingBigUint {
+                self <<= rhs;
+                self
+            }
+        }
+
+        impl Shl<$rhs> for &WrappingBigUint {
+            type Output = WrappingBigUint;
+
+            #[inline]
+            fn shl(self, rhs: $rhs) -> WrappingBigUint {
+                self.clone() << rhs
+            }
+        }
+
+        impl ShlAssign<$rhs> for WrappingBigUint {
+            #[inline]
+            fn shl_assign(&mut self, rhs: $rhs) {
+                // An amount that doesn't fit in a `u64` is certainly past the width.
+                let shift = u64::try_from(rhs).unwrap_or(u64::MAX);
+                self.value = self.value.wrapping_shl(shift, self.bits);
+            }
+        }
+
+        impl Shr<$rhs> for WrappingBigUint {
+            type Output = WrappingBigUint;
+
+            #[inline]
+            fn shr(mut self, rhs: $rhs) -> WrappingBigUint {
+                self >>= rhs;
+                self
+            }
+        }
+
+        impl Shr<$rhs> for &WrappingBigUint {
+            type Output = WrappingBigUint;
+
+            #[inline]
+            fn shr(self, rhs: $rhs) -> WrappingBigUint {
+                WrappingBigUint {
+                    value: &self.value >> rhs,
+                    bits: self.bits,
+                }
+            }
+        }
+
+        impl ShrAssign<$rhs> for WrappingBigUint {
+            #[inline]
+            fn shr_assign(&mut self, rhs: $rhs) {
+                self.value >>= rhs;
+            }
+        }
+    )*};
+}
+
+impl_wrapping_shift! { u8, u16, u32, u64, u128, usize }
+
+#[test]
+fn test_truncate() {
+    let x = (BigUint::from(1u32) << 200u32) - 1u32;
+    for bits in [0, 1, 31, 32, 33, 63, 64, 65, 128, 199, 200, 201, 1000] {
+        let expected = x.clone() % (BigUint::from(1u32) << bits);
+        let y = truncate(x.clone(), bits);
+        assert_eq!(y, expected);
+        assert_ne!(y.data.last(), Some(&0));
+    }
+}
@@ -0,0 +1,52 @@
+#![cfg(feature = "zeroize")]
+#![cfg_attr(docsrs, doc(cfg(feature = "zeroize")))]
+
+use super::BigUint;
+
+use zeroize::{Zeroize, Zeroizing};
+
+/// A [`BigUint`] that is zeroized when it's dropped, for secrets like private keys.
+///
+/// This is [`Zeroizing`], which dereferences to the [`BigUint`] inside.
+///
+/// ```
+/// use num_bigint::{BigUint, ZeroizingBigUint};
+///
+/// let secret = ZeroizingBigUint::new(BigUint::from(0xdead_beef_u32));
+/// assert_eq!(secret.bits(), 32);
+/// ```
+pub type ZeroizingBigUint = Zeroizing<BigUint>;
+
+/// Overwrites all of the digits with zeros, including any spare capacity, which leaves the
+/// value zero.
+///
+/// Only the current allocation is cleared. Operations that reallocate, or that compute a new
+/// value, may leave copies of the digits behind in memory that has been freed, so secrets should
+/// be updated in place where possible.
+impl Zeroize for BigUint {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.data.zeroize();
+    }
+}
+
+#[test]
+fn test_zeroize_in_place() {
+    for mut x in [
+        BigUint::from(0xdead_beef_u32),
+        BigUint::from_bytes_le(&[0xa5; 100]),
+    ] {
+        let (len, capacity) = (x.data.len(), x.data.capacity());
+        x.zeroize();
+        assert!(x.data.is_empty());
+        assert_eq!(x.data.capacity(), capacity);
+
+        // The old digits are now spare capacity, read through the same `&mut` that zeroized it.
+        let spare = x.data.spare_capacity_mut();
+        assert!(spare.len() >= len);
+        // SAFETY: `Vec::zeroize` writes zeros over its whole capacity, so it's all initialized.
+        assert!(spare
+            .iter()
+            .all(|digit| unsafe { digit.assume_init() } == 0));
+    }
+}
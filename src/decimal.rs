@@ -0,0 +1,632 @@
+//! Decimal numbers, as a `BigInt` scaled by a power of ten
+#![cfg(feature = "decimal")]
+#![cfg_attr(docsrs, doc(cfg(feature = "decimal")))]
+
+use crate::{BigInt, BigUint, ParseBigIntError, Sign};
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash;
+use core::iter::{Product, Sum};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use core::str::FromStr;
+use num_integer::Integer;
+use num_traits::{FromPrimitive, One, Pow, ToPrimitive, Zero};
+
+/// The quotient of `/` is rounded to at least this many significant digits.
+const DIV_DIGITS: i128 = 32;
+
+/// Parsing rejects exponents beyond this magnitude, since operations on the result may need
+/// powers of ten with about that many digits.
+const MAX_PARSE_EXPONENT: i64 = 1_000_000;
+
+/// An arbitrary-precision decimal number, `mantissa × 10^scale`.
+///
+/// Addition, subtraction and multiplication are exact, and the result of addition and
+/// subtraction has the smaller scale of the operands, so `0.1 + 0.2` is exactly `0.3`. Division
+/// generally can't be exact, so [`BigDecimal::div_with_scale`] rounds the quotient to a given
+/// scale, and the `/` operator rounds it to at least 32 significant digits.
+///
+/// Comparisons are by value, so `1.0` and `1.00` are equal even though their mantissas and
+/// scales differ. Values format as plain decimals with [`Display`][fmt::Display], with as many
+/// fractional digits as the scale calls for, or the number given as the formatting precision.
+///
+/// # Examples
+///
+/// ```
+/// use num_bigint::BigDecimal;
+///
+/// let a: BigDecimal = "0.1".parse().unwrap();
+/// let b: BigDecimal = "0.2".parse().unwrap();
+/// assert_eq!(&a + &b, "0.3".parse().unwrap());
+///
+/// let third = BigDecimal::from(1).div_with_scale(&BigDecimal::from(3), -5);
+/// assert_eq!(third.to_string(), "0.33333");
+/// assert_eq!(format!("{:.2}", third), "0.33");
+/// ```
+#[derive(Clone, Default)]
+pub struct BigDecimal {
+    mantissa: BigInt,
+    scale: i64,
+}
+
+/// Returns `10^n`.
+fn pow10(n: u64) -> BigInt {
+    BigInt::from(Pow::pow(BigUint::from(10u32), n))
+}
+
+/// Returns `n / d` rounded to the nearest integer, with ties to even. `d` must not be zero.
+fn div_round(n: &BigInt, d: &BigInt) -> BigInt {
+    let (q, r) = n.div_rem(d);
+    if r.is_zero() {
+        return q;
+    }
+    match (r.magnitude() << 1u8).cmp(d.magnitude()) {
+        Ordering::Less => q,
+        Ordering::Equal if q.is_even() => q,
+        // The exact quotient is nonzero, so its sign is the product of the operands' signs.
+        _ if (n.sign() == Sign::Minus) != (d.sign() == Sign::Minus) => q - 1u32,
+        _ => q + 1u32,
+    }
+}
+
+impl BigDecimal {
+    /// Zero, with a scale of zero.
+    pub const ZERO: Self = BigDecimal {
+        mantissa: BigInt::ZERO,
+        scale: 0,
+    };
+
+    /// Creates the decimal `mantissa × 10^scale`.
+    ///
+    /// Any scale is accepted, but operations on values with very different scales, and
+    /// formatting values with a large scale, take time and memory proportional to the scale.
+    #[inline]
+    pub fn new(mantissa: BigInt, scale: i64) -> Self {
+        BigDecimal { mantissa, scale }
+    }
+
+    /// Returns the mantissa, the integer multiple of `10^scale`.
+    #[inline]
+    pub fn mantissa(&self) -> &BigInt {
+        &self.mantissa
+    }
+
+    /// Returns the scale, the power of ten that the mantissa is a multiple of. A negative scale
+    /// is the number of fractional digits.
+    #[inline]
+    pub fn scale(&self) -> i64 {
+        self.scale
+    }
+
+    /// Returns the mantissa and scale.
+    #[inline]
+    pub fn into_parts(self) -> (BigInt, i64) {
+        (self.mantissa, self.scale)
+    }
+
+    /// Returns the same value with no trailing zeros in its mantissa, and a scale of zero for
+    /// zero itself.
+    ///
+    /// ```
+    /// use num_bigint::{BigDecimal, BigInt};
+    ///
+    /// let x: BigDecimal = "1.2500".parse().unwrap();
+    /// assert_eq!(x.mantissa(), &BigInt::from(12500));
+    /// let x = x.normalized();
+    /// assert_eq!((x.mantissa(), x.scale()), (&BigInt::from(125), -2));
+    /// ```
+    pub fn normalized(&self) -> BigDecimal {
+        if self.mantissa.is_zero() {
+            return BigDecimal::ZERO;
+        }
+        let mut mantissa = self.mantissa.clone();
+        let mut scale = self.scale;
+        // Strip zeros 19 at a time, then one at a time, while the scale still fits.
+        for (step, divisor) in [(19, pow10(19)), (1, BigInt::from(10u32))] {
+            while scale <= i64::MAX - step {
+                let (q, r) = mantissa.div_rem(&divisor);
+                if !r.is_zero() {
+                    break;
+                }
+                mantissa = q;
+                scale += step;
+            }
+        }
+        BigDecimal { mantissa, scale }
+    }
+
+    /// Returns the value rounded to a multiple of `10^scale`, with that scale. Halfway cases
+    /// round to the even neighbor. A smaller scale than the current one just adds zeros to the
+    /// mantissa.
+    ///
+    /// ```
+    /// use num_bigint::BigDecimal;
+    ///
+    /// let x: BigDecimal = "2.345".parse().unwrap();
+    /// assert_eq!(x.round(-2).to_string(), "2.34");
+    /// assert_eq!(x.round(0).to_string(), "2");
+    /// assert_eq!(x.round(-4).to_string(), "2.3450");
+    /// assert_eq!((-x).round(-1).to_string(), "-2.3");
+    /// ```
+    pub fn round(&self, scale: i64) -> BigDecimal {
+        let mantissa = if scale <= self.scale {
+            &self.mantissa * pow10(self.scale.abs_diff(scale))
+        } else if self.magnitude_exponent() < i128::from(scale) - 1 {
+            // The value is below half of `10^scale`.
+            BigInt::ZERO
+        } else {
+            div_round(&self.mantissa, &pow10(self.scale.abs_diff(scale)))
+        };
+        BigDecimal { mantissa, scale }
+    }
+
+    /// Returns `self / rhs` rounded to a multiple of `10^scale`, with that scale. Halfway cases
+    /// round to the even neighbor. Panics if `rhs` is zero.
+    ///
+    /// ```
+    /// use num_bigint::BigDecimal;
+    ///
+    /// let x = BigDecimal::from(2);
+    /// let y = BigDecimal::from(3);
+    /// assert_eq!(x.div_with_scale(&y, -3).to_string(), "0.667");
+    /// assert_eq!(x.div_with_scale(&y, 0).to_string(), "1");
+    /// ```
+    pub fn div_with_scale(&self, rhs: &BigDecimal, scale: i64) -> BigDecimal {
+        assert!(!rhs.mantissa.is_zero(), "attempt to divide by zero");
+        // self / rhs / 10^scale = (self.mantissa / rhs.mantissa) × 10^e
+        let e = i128::from(self.scale) - i128::from(rhs.scale) - i128::from(scale);
+        let shift = u64::try_from(e.unsigned_abs()).expect("decimal scale overflow");
+        let mantissa = if e >= 0 {
+            div_round(&(&self.mantissa * pow10(shift)), &rhs.mantissa)
+        } else {
+            div_round(&self.mantissa, &(&rhs.mantissa * pow10(shift)))
+        };
+        BigDecimal { mantissa, scale }
+    }
+
+    /// Returns the nearest `f64` to the value, which may be infinite.
+    ///
+    /// ```
+    /// use num_bigint::BigDecimal;
+    ///
+    /// let x: BigDecimal = "0.1".parse().unwrap();
+    /// assert_eq!(x.to_f64(), 0.1);
+    /// ```
+    pub fn to_f64(&self) -> f64 {
+        // The standard parser rounds correctly, even with many digits.
+        format!("{}e{}", self.mantissa, self.scale).parse().unwrap()
+    }
+
+    /// Returns the exact value of a finite `f64`, or `None` for infinities and NaN.
+    ///
+    /// ```
+    /// use num_bigint::BigDecimal;
+    ///
+    /// assert_eq!(BigDecimal::from_f64(1.25).unwrap().to_string(), "1.25");
+    /// assert_eq!(
+    ///     BigDecimal::from_f64(0.1).unwrap().to_string(),
+    ///     "0.1000000000000000055511151231257827021181583404541015625",
+    /// );
+    /// ```
+    pub fn from_f64(n: f64) -> Option<BigDecimal> {
+        if !n.is_finite() {
+            return None;
+        }
+        let (m, e, sign) = num_traits::float::FloatCore::integer_decode(n);
+        let mut mantissa = BigInt::from(m);
+        if sign < 0 {
+            mantissa = -mantissa;
+        }
+        let x = if e >= 0 {
+            BigDecimal::new(mantissa << e, 0)
+        } else {
+            // m × 2^e = m × 5^-e × 10^e
+            let fives = Pow::pow(BigUint::from(5u32), e.unsigned_abs());
+            BigDecimal::new(mantissa * BigInt::from(fives), e.into())
+        };
+        Some(x.normalized())
+    }
+
+    /// Returns `e` such that the magnitude is in `[10^(e - 1), 10^e)`, which is the scale for
+    /// zero.
+    fn magnitude_exponent(&self) -> i128 {
+        let digits = if self.mantissa.is_zero() {
+            0
+        } else {
+            self.mantissa.magnitude().decimal_digits()
+        };
+        digits as i128 + i128::from(self.scale)
+    }
+
+    /// Returns the value truncated toward zero, or `None` if it's at least `10^39`, beyond the
+    /// range of any primitive integer.
+    fn to_small_integer(&self) -> Option<BigInt> {
+        if self.magnitude_exponent() > 39 {
+            None
+        } else if self.magnitude_exponent() <= 0 {
+            Some(BigInt::ZERO)
+        } else if self.scale >= 0 {
+            Some(&self.mantissa * pow10(self.scale as u64))
+        } else {
+            Some(&self.mantissa / pow10(self.scale.unsigned_abs()))
+        }
+    }
+}
+
+impl From<BigInt> for BigDecimal {
+    #[inline]
+    fn from(n: BigInt) -> Self {
+        BigDecimal::new(n, 0)
+    }
+}
+
+impl From<BigUint> for BigDecimal {
+    #[inline]
+    fn from(n: BigUint) -> Self {
+        BigDecimal::new(n.into(), 0)
+    }
+}
+
+macro_rules! impl_decimal_from_primitive {
+    ($($T:ty),*) => {$(
+        impl From<$T> for BigDecimal {
+            #[inline]
+            fn from(n: $T) -> Self {
+                BigDecimal::new(n.into(), 0)
+            }
+        }
+    )*};
+}
+
+impl_decimal_from_primitive!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl FromPrimitive for BigDecimal {
+    #[inline]
+    fn from_i64(n: i64) -> Option<Self> {
+        Some(n.into())
+    }
+
+    #[inline]
+    fn from_i128(n: i128) -> Option<Self> {
+        Some(n.into())
+    }
+
+    #[inline]
+    fn from_u64(n: u64) -> Option<Self> {
+        Some(n.into())
+    }
+
+    #[inline]
+    fn from_u128(n: u128) -> Option<Self> {
+        Some(n.into())
+    }
+
+    #[inline]
+    fn from_f64(n: f64) -> Option<Self> {
+        BigDecimal::from_f64(n)
+    }
+}
+
+/// Conversions to integers truncate toward zero, like those of the primitive floats.
+impl ToPrimitive for BigDecimal {
+    #[inline]
+    fn to_i64(&self) -> Option<i64> {
+        self.to_small_integer()?.to_i64()
+    }
+
+    #[inline]
+    fn to_i128(&self) -> Option<i128> {
+        self.to_small_integer()?.to_i128()
+    }
+
+    #[inline]
+    fn to_u64(&self) -> Option<u64> {
+        self.to_small_integer()?.to_u64()
+    }
+
+    #[inline]
+    fn to_u128(&self) -> Option<u128> {
+        self.to_small_integer()?.to_u128()
+    }
+
+    #[inline]
+    fn to_f64(&self) -> Option<f64> {
+        Some(BigDecimal::to_f64(self))
+    }
+}
+
+impl PartialEq for BigDecimal {
+    #[inline]
+    fn eq(&self, other: &BigDecimal) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for BigDecimal {}
+
+impl PartialOrd for BigDecimal {
+    #[inline]
+    fn partial_cmp(&self, other: &BigDecimal) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigDecimal {
+    fn cmp(&self, other: &BigDecimal) -> Ordering {
+        let sign = self.mantissa.sign();
+        if sign != other.mantissa.sign() {
+            return sign.cmp(&other.mantissa.sign());
+        }
+        // Compare the magnitudes by their number of digits before aligning the scales, so very
+        // different scales don't need huge powers of ten.
+        let magnitude = match self.magnitude_exponent().cmp(&other.magnitude_exponent()) {
+            _ if sign == Sign::NoSign => Ordering::Equal,
+            Ordering::Equal if self.scale <= other.scale => {
+                let shift = pow10(self.scale.abs_diff(other.scale));
+                let other = other.mantissa.magnitude() * shift.magnitude();
+                self.mantissa.magnitude().cmp(&other)
+            }
+            Ordering::Equal => {
+                let shift = pow10(self.scale.abs_diff(other.scale));
+                let this = self.mantissa.magnitude() * shift.magnitude();
+                this.cmp(other.mantissa.magnitude())
+            }
+            ord => ord,
+        };
+        if sign == Sign::Minus {
+            magnitude.reverse()
+        } else {
+            magnitude
+        }
+    }
+}
+
+impl hash::Hash for BigDecimal {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        let x = self.normalized();
+        x.mantissa.hash(state);
+        x.scale.hash(state);
+    }
+}
+
+impl Zero for BigDecimal {
+    #[inline]
+    fn zero() -> Self {
+        BigDecimal::ZERO
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.mantissa.is_zero()
+    }
+}
+
+impl One for BigDecimal {
+    #[inline]
+    fn one() -> Self {
+        BigDecimal::new(BigInt::one(), 0)
+    }
+}
+
+impl Neg for BigDecimal {
+    type Output = BigDecimal;
+
+    #[inline]
+    fn neg(self) -> BigDecimal {
+        BigDecimal::new(-self.mantissa, self.scale)
+    }
+}
+
+impl Neg for &BigDecimal {
+    type Output = BigDecimal;
+
+    #[inline]
+    fn neg(self) -> BigDecimal {
+        BigDecimal::new(-&self.mantissa, self.scale)
+    }
+}
+
+/// Returns the mantissas of `a` and `b` at the smaller of their scales, and that scale.
+fn align(a: &BigDecimal, b: &BigDecimal) -> (BigInt, BigInt, i64) {
+    let shift = pow10(a.scale.abs_diff(b.scale));
+    if a.scale <= b.scale {
+        (a.mantissa.clone(), &b.mantissa * shift, a.scale)
+    } else {
+        (&a.mantissa * shift, b.mantissa.clone(), b.scale)
+    }
+}
+
+forward_all_binop_to_ref_ref!(impl Add for BigDecimal, add);
+forward_all_binop_to_ref_ref!(impl Sub for BigDecimal, sub);
+forward_all_binop_to_ref_ref!(impl Mul for BigDecimal, mul);
+forward_all_binop_to_ref_ref!(impl Div for BigDecimal, div);
+
+impl Add<&BigDecimal> for &BigDecimal {
+    type Output = BigDecimal;
+
+    fn add(self, other: &BigDecimal) -> BigDecimal {
+        let (a, b, scale) = align(self, other);
+        BigDecimal::new(a + b, scale)
+    }
+}
+
+impl Sub<&BigDecimal> for &BigDecimal {
+    type Output = BigDecimal;
+
+    fn sub(self, other: &BigDecimal) -> BigDecimal {
+        let (a, b, scale) = align(self, other);
+        BigDecimal::new(a - b, scale)
+    }
+}
+
+impl Mul<&BigDecimal> for &BigDecimal {
+    type Output = BigDecimal;
+
+    fn mul(self, other: &BigDecimal) -> BigDecimal {
+        let scale = self
+            .scale
+            .checked_add(other.scale)
+            .expect("decimal scale overflow");
+        BigDecimal::new(&self.mantissa * &other.mantissa, scale)
+    }
+}
+
+impl Div<&BigDecimal> for &BigDecimal {
+    type Output = BigDecimal;
+
+    /// Returns the quotient rounded to at least 32 significant digits, as if by
+    /// [`BigDecimal::div_with_scale`].
+    fn div(self, other: &BigDecimal) -> BigDecimal {
+        // The quotient is below `10^(ea - eb + 1)`, and at least `10^(ea - eb - 1)`, so 32 more
+        // digits below `10^(ea - eb)` leave at least 32 significant digits.
+        let e = self.magnitude_exponent() - other.magnitude_exponent() - DIV_DIGITS;
+        let scale = e.clamp(i64::MIN.into(), i64::MAX.into()) as i64;
+        self.div_with_scale(other, scale)
+    }
+}
+
+forward_val_assign!(impl AddAssign for BigDecimal, add_assign);
+forward_val_assign!(impl SubAssign for BigDecimal, sub_assign);
+forward_val_assign!(impl MulAssign for BigDecimal, mul_assign);
+forward_val_assign!(impl DivAssign for BigDecimal, div_assign);
+
+impl AddAssign<&BigDecimal> for BigDecimal {
+    #[inline]
+    fn add_assign(&mut self, other: &BigDecimal) {
+        *self = &*self + other;
+    }
+}
+
+impl SubAssign<&BigDecimal> for BigDecimal {
+    #[inline]
+    fn sub_assign(&mut self, other: &BigDecimal) {
+        *self = &*self - other;
+    }
+}
+
+impl MulAssign<&BigDecimal> for BigDecimal {
+    #[inline]
+    fn mul_assign(&mut self, other: &BigDecimal) {
+        *self = &*self * other;
+    }
+}
+
+impl DivAssign<&BigDecimal> for BigDecimal {
+    #[inline]
+    fn div_assign(&mut self, other: &BigDecimal) {
+        *self = &*self / other;
+    }
+}
+
+impl_sum_iter_type!(BigDecimal);
+impl_product_iter_type!(BigDecimal);
+
+impl fmt::Debug for BigDecimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl fmt::Display for BigDecimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(precision) = f.precision() {
+            let scale = i64::try_from(precision).map_or(i64::MIN, |p| -p);
+            if scale != self.scale {
+                return fmt::Display::fmt(&self.round(scale), f);
+            }
+        }
+
+        let mut s = self.mantissa.magnitude().to_str_radix(10);
+        if self.scale >= 0 {
+            if !self.mantissa.is_zero() {
+                let zeros = usize::try_from(self.scale).expect("capacity overflow");
+                s.extend(core::iter::repeat('0').take(zeros));
+            }
+        } else {
+            let fraction = usize::try_from(self.scale.unsigned_abs()).expect("capacity overflow");
+            if s.len() > fraction {
+                s.insert(s.len() - fraction, '.');
+            } else {
+                let mut padded = String::with_capacity(fraction + 2);
+                padded.push_str("0.");
+                padded.extend(core::iter::repeat('0').take(fraction - s.len()));
+                padded.push_str(&s);
+                s = padded;
+            }
+        }
+        f.pad_integral(self.mantissa.sign() != Sign::Minus, "", &s)
+    }
+}
+
+impl FromStr for BigDecimal {
+    type Err = ParseBigIntError;
+
+    /// Parses an optional sign, decimal digits with an optional decimal point, and an optional
+    /// exponent after `e` or `E`, like `-12.5e-3`.
+    ///
+    /// The exponent must be within ±1,000,000, so that untrusted input like `1e-999999999999`
+    /// can't make later arithmetic or formatting allocate unbounded memory. Larger scales are
+    /// still available from [`BigDecimal::new`].
+    fn from_str(s: &str) -> Result<BigDecimal, ParseBigIntError> {
+        let bytes = s.as_bytes();
+        let start = match bytes.first() {
+            Some(b'+' | b'-') => 1,
+            _ => 0,
+        };
+        let end = bytes
+            .iter()
+            .position(|&b| b == b'e' || b == b'E')
+            .unwrap_or(bytes.len());
+
+        let mut digits = Vec::with_capacity(end - start);
+        let mut fraction = None;
+        for (i, &b) in bytes.iter().enumerate().take(end).skip(start) {
+            match b {
+                b'0'..=b'9' => digits.push(b - b'0'),
+                b'.' if fraction.is_none() => fraction = Some(0),
+                _ => return Err(ParseBigIntError::invalid(i)),
+            }
+            if b != b'.' {
+                if let Some(f) = &mut fraction {
+                    *f += 1;
+                }
+            }
+        }
+        if digits.is_empty() {
+            return Err(ParseBigIntError::empty(end));
+        }
+
+        let mut exponent = 0i64;
+        if end < bytes.len() {
+            let exp = &s[end + 1..];
+            exponent = match exp.as_bytes().first() {
+                None | Some(b'+' | b'-') if exp.len() <= 1 => {
+                    return Err(ParseBigIntError::empty(s.len()))
+                }
+                Some(b'0'..=b'9' | b'+' | b'-') => exp
+                    .parse()
+                    .ok()
+                    .filter(|e: &i64| e.abs() <= MAX_PARSE_EXPONENT)
+                    .ok_or_else(|| ParseBigIntError::invalid(end + 1))?,
+                _ => return Err(ParseBigIntError::invalid(end + 1)),
+            };
+        }
+
+        let magnitude = BigUint::from_radix_be(&digits, 10).unwrap();
+        let sign = if bytes[0] == b'-' {
+            Sign::Minus
+        } else {
+            Sign::Plus
+        };
+        let scale = exponent
+            .checked_sub(fraction.unwrap_or(0))
+            .ok_or_else(|| ParseBigIntError::invalid(end + 1))?;
+        Ok(BigDecimal::new(
+            BigInt::from_biguint(sign, magnitude),
+            scale,
+        ))
+    }
+}
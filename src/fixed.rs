@@ -0,0 +1,643 @@
+//! Fixed-width unsigned integers, stored inline without allocating
+
+use crate::{BigUint, Overflow, TryFromBigIntError};
+
+use core::cmp::{self, Ordering};
+use core::fmt::{self, Write};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Rem, RemAssign, Sub, SubAssign};
+use num_integer::Integer;
+use num_traits::{
+    CheckedAdd, CheckedDiv, CheckedMul, CheckedRem, CheckedSub, FromPrimitive, One, ToPrimitive,
+    Zero,
+};
+
+/// An unsigned integer of `N` 32-bit digits, stored in an array so that arithmetic and
+/// formatting never allocate. It holds values up to `2^(32 * N) - 1`.
+///
+/// [`try_add`][Self::try_add], [`try_sub`][Self::try_sub] and [`try_mul`][Self::try_mul]
+/// return an [`Overflow`] error when the result doesn't fit. The operators panic instead, like
+/// those of the primitive integers in debug builds, and [`CheckedAdd`], [`CheckedSub`],
+/// [`CheckedMul`], [`CheckedDiv`] and [`CheckedRem`] return `None`. Division can't overflow, so
+/// it only fails for a zero divisor.
+///
+/// This type doesn't allocate, but the rest of the crate still needs the `alloc` crate, and
+/// conversions to and from [`BigUint`] and `f64` allocate a [`BigUint`].
+///
+/// # Examples
+///
+/// ```
+/// use num_bigint::{BigUint, FixedBigUint};
+/// use num_traits::{CheckedAdd, FromPrimitive};
+///
+/// type U128 = FixedBigUint<4>;
+///
+/// let a = U128::from_u64(u64::MAX).unwrap();
+/// let b = a * a;
+/// assert_eq!(BigUint::from(b), BigUint::from(u64::MAX).pow(2));
+/// assert_eq!(b / a, a);
+/// assert_eq!(b.to_string(), "340282366920938463426481119284349108225");
+/// assert!(U128::MAX.try_add(&a).is_err());
+/// assert_eq!(U128::MAX.checked_add(&a), None);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FixedBigUint<const N: usize> {
+    digits: [u32; N],
+}
+
+impl<const N: usize> FixedBigUint<N> {
+    /// Zero.
+    pub const ZERO: Self = FixedBigUint { digits: [0; N] };
+
+    /// The largest value, `2^(32 * N) - 1`.
+    pub const MAX: Self = FixedBigUint {
+        digits: [u32::MAX; N],
+    };
+
+    /// The width in bits, `32 * N`.
+    pub const BITS: u64 = 32 * N as u64;
+
+    /// Creates a value from its `u32` digits, least significant first.
+    #[inline]
+    pub const fn from_u32_digits(digits: [u32; N]) -> Self {
+        FixedBigUint { digits }
+    }
+
+    /// Returns the `u32` digits, least significant first. Unlike those of a [`BigUint`], they
+    /// may end in zeros.
+    #[inline]
+    pub const fn as_u32_digits(&self) -> &[u32; N] {
+        &self.digits
+    }
+
+    /// Determines the fewest bits necessary to express the value, which is 0 for zero.
+    pub fn bits(&self) -> u64 {
+        match self.digits.iter().rposition(|&d| d != 0) {
+            Some(i) => (i as u64 + 1) * 32 - u64::from(self.digits[i].leading_zeros()),
+            None => 0,
+        }
+    }
+
+    /// Returns the quotient and remainder of `self / rhs`. Panics if `rhs` is zero.
+    pub fn divmod(&self, rhs: &Self) -> (Self, Self) {
+        assert!(!rhs.is_zero(), "attempt to divide by zero");
+        div_rem(&self.digits, &rhs.digits)
+    }
+
+    /// Returns `self + rhs`, or an [`Overflow`] error if it doesn't fit.
+    #[inline]
+    pub fn try_add(&self, rhs: &Self) -> Result<Self, Overflow> {
+        match self.overflowing_add(rhs) {
+            (sum, false) => Ok(sum),
+            (_, true) => Err(Overflow),
+        }
+    }
+
+    /// Returns `self - rhs`, or an [`Overflow`] error if `rhs` is greater than `self`.
+    #[inline]
+    pub fn try_sub(&self, rhs: &Self) -> Result<Self, Overflow> {
+        match self.overflowing_sub(rhs) {
+            (difference, false) => Ok(difference),
+            (_, true) => Err(Overflow),
+        }
+    }
+
+    /// Returns `self * rhs`, or an [`Overflow`] error if it doesn't fit.
+    pub fn try_mul(&self, rhs: &Self) -> Result<Self, Overflow> {
+        let (a, b) = (&self.digits, &rhs.digits);
+        let mut product = [0u32; N];
+        for (i, &ai) in a.iter().enumerate() {
+            if ai == 0 {
+                continue;
+            }
+            let mut carry = 0u64;
+            for (j, &bj) in b.iter().enumerate() {
+                let k = i + j;
+                if k >= N {
+                    // Anything more would go past the top digit.
+                    if carry != 0 || b[j..].iter().any(|&d| d != 0) {
+                        return Err(Overflow);
+                    }
+                    break;
+                }
+                let t = u64::from(product[k]) + u64::from(ai) * u64::from(bj) + carry;
+                product[k] = t as u32;
+                carry = t >> 32;
+            }
+            if carry != 0 {
+                return Err(Overflow);
+            }
+        }
+        Ok(FixedBigUint { digits: product })
+    }
+
+    /// Returns `self + rhs` and whether it overflowed, in which case the sum wraps around.
+    pub fn overflowing_add(&self, rhs: &Self) -> (Self, bool) {
+        let mut digits = self.digits;
+        let mut carry = 0u64;
+        for (a, &b) in digits.iter_mut().zip(&rhs.digits) {
+            let sum = u64::from(*a) + u64::from(b) + carry;
+            *a = sum as u32;
+            carry = sum >> 32;
+        }
+        (FixedBigUint { digits }, carry != 0)
+    }
+
+    /// Returns `self - rhs` and whether it overflowed, in which case the difference wraps
+    /// around.
+    pub fn overflowing_sub(&self, rhs: &Self) -> (Self, bool) {
+        let mut digits = self.digits;
+        let borrow = sub_assign(&mut digits, &rhs.digits);
+        (FixedBigUint { digits }, borrow)
+    }
+}
+
+/// Subtracts `b` from `a` in place, returning the final borrow.
+fn sub_assign(a: &mut [u32], b: &[u32]) -> bool {
+    let mut borrow = false;
+    for (a, &b) in a.iter_mut().zip(b) {
+        let (d, b1) = a.overflowing_sub(b);
+        let (d, b2) = d.overflowing_sub(u32::from(borrow));
+        *a = d;
+        borrow = b1 | b2;
+    }
+    borrow
+}
+
+/// Divides `a` by a nonzero `d`, returning the quotient and remainder.
+fn div_rem<const N: usize>(a: &[u32; N], d: &[u32; N]) -> (FixedBigUint<N>, FixedBigUint<N>) {
+    let (a, d) = (FixedBigUint { digits: *a }, FixedBigUint { digits: *d });
+    if a < d {
+        return (FixedBigUint::ZERO, a);
+    }
+
+    let mut q = [0u32; N];
+    if d.bits() <= 32 {
+        // Schoolbook division by a single digit.
+        let d = u64::from(d.digits[0]);
+        let mut r = 0u64;
+        for (qi, &ai) in q.iter_mut().zip(&a.digits).rev() {
+            let x = r << 32 | u64::from(ai);
+            *qi = (x / d) as u32;
+            r = x % d;
+        }
+        let mut rem = [0u32; N];
+        rem[0] = r as u32;
+        return (FixedBigUint { digits: q }, FixedBigUint { digits: rem });
+    }
+
+    // Binary long division, one bit of the quotient at a time.
+    let mut r = [0u32; N];
+    for i in (0..a.bits()).rev() {
+        let (digit, bit) = ((i / 32) as usize, i % 32);
+        // r = 2r + (bit i of a), which may carry out of the top digit.
+        let mut carry = (a.digits[digit] >> bit) & 1;
+        for x in r.iter_mut() {
+            let next = *x >> 31;
+            *x = *x << 1 | carry;
+            carry = next;
+        }
+        if carry != 0 || FixedBigUint::from_u32_digits(r) >= d {
+            // r < 2d, so one subtraction brings it back below d, and any borrow cancels the carry.
+            sub_assign(&mut r, &d.digits);
+            q[digit] |= 1 << bit;
+        }
+    }
+    (FixedBigUint { digits: q }, FixedBigUint { digits: r })
+}
+
+impl<const N: usize> Default for FixedBigUint<N> {
+    #[inline]
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<const N: usize> PartialOrd for FixedBigUint<N> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const N: usize> Ord for FixedBigUint<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.digits.iter().rev().cmp(other.digits.iter().rev())
+    }
+}
+
+impl<const N: usize> Zero for FixedBigUint<N> {
+    #[inline]
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.digits.iter().all(|&d| d == 0)
+    }
+}
+
+impl<const N: usize> One for FixedBigUint<N> {
+    /// Returns one. Panics if `N` is zero, since then one doesn't fit.
+    #[inline]
+    fn one() -> Self {
+        let mut digits = [0; N];
+        digits[0] = 1;
+        FixedBigUint { digits }
+    }
+}
+
+impl<const N: usize> CheckedAdd for FixedBigUint<N> {
+    #[inline]
+    fn checked_add(&self, v: &Self) -> Option<Self> {
+        self.try_add(v).ok()
+    }
+}
+
+impl<const N: usize> CheckedSub for FixedBigUint<N> {
+    #[inline]
+    fn checked_sub(&self, v: &Self) -> Option<Self> {
+        self.try_sub(v).ok()
+    }
+}
+
+impl<const N: usize> CheckedMul for FixedBigUint<N> {
+    #[inline]
+    fn checked_mul(&self, v: &Self) -> Option<Self> {
+        self.try_mul(v).ok()
+    }
+}
+
+impl<const N: usize> CheckedDiv for FixedBigUint<N> {
+    #[inline]
+    fn checked_div(&self, v: &Self) -> Option<Self> {
+        if v.is_zero() {
+            return None;
+        }
+        Some(self.divmod(v).0)
+    }
+}
+
+impl<const N: usize> CheckedRem for FixedBigUint<N> {
+    #[inline]
+    fn checked_rem(&self, v: &Self) -> Option<Self> {
+        if v.is_zero() {
+            return None;
+        }
+        Some(self.divmod(v).1)
+    }
+}
+
+macro_rules! impl_fixed_binop {
+    ($Op:ident :: $op:ident, $OpAssign:ident :: $op_assign:ident, $checked:ident, $msg:literal) => {
+        impl<const N: usize> $Op<FixedBigUint<N>> for FixedBigUint<N> {
+            type Output = FixedBigUint<N>;
+
+            #[inline]
+            fn $op(self, other: FixedBigUint<N>) -> FixedBigUint<N> {
+                self.$checked(&other).expect($msg)
+            }
+        }
+
+        impl<const N: usize> $Op<&FixedBigUint<N>> for FixedBigUint<N> {
+            type Output = FixedBigUint<N>;
+
+            #[inline]
+            fn $op(self, other: &FixedBigUint<N>) -> FixedBigUint<N> {
+                $Op::$op(self, *other)
+            }
+        }
+
+        impl<const N: usize> $Op<FixedBigUint<N>> for &FixedBigUint<N> {
+            type Output = FixedBigUint<N>;
+
+            #[inline]
+            fn $op(self, other: FixedBigUint<N>) -> FixedBigUint<N> {
+                $Op::$op(*self, other)
+            }
+        }
+
+        impl<const N: usize> $Op<&FixedBigUint<N>> for &FixedBigUint<N> {
+            type Output = FixedBigUint<N>;
+
+            #[inline]
+            fn $op(self, other: &FixedBigUint<N>) -> FixedBigUint<N> {
+                $Op::$op(*self, *other)
+            }
+        }
+
+        impl<const N: usize> $OpAssign<FixedBigUint<N>> for FixedBigUint<N> {
+            #[inline]
+            fn $op_assign(&mut self, other: FixedBigUint<N>) {
+                *self = $Op::$op(*self, other);
+            }
+        }
+
+        impl<const N: usize> $OpAssign<&FixedBigUint<N>> for FixedBigUint<N> {
+            #[inline]
+            fn $op_assign(&mut self, other: &FixedBigUint<N>) {
+                *self = $Op::$op(*self, *other);
+            }
+        }
+    };
+}
+
+impl_fixed_binop!(
+    Add::add,
+    AddAssign::add_assign,
+    checked_add,
+    "attempt to add with overflow"
+);
+impl_fixed_binop!(
+    Sub::sub,
+    SubAssign::sub_assign,
+    checked_sub,
+    "attempt to subtract with overflow"
+);
+impl_fixed_binop!(
+    Mul::mul,
+    MulAssign::mul_assign,
+    checked_mul,
+    "attempt to multiply with overflow"
+);
+impl_fixed_binop!(
+    Div::div,
+    DivAssign::div_assign,
+    checked_div,
+    "attempt to divide by zero"
+);
+impl_fixed_binop!(
+    Rem::rem,
+    RemAssign::rem_assign,
+    checked_rem,
+    "attempt to calculate the remainder with a divisor of zero"
+);
+
+impl<const N: usize> FromPrimitive for FixedBigUint<N> {
+    #[inline]
+    fn from_i64(n: i64) -> Option<Self> {
+        u64::try_from(n).ok().and_then(Self::from_u64)
+    }
+
+    #[inline]
+    fn from_i128(n: i128) -> Option<Self> {
+        u128::try_from(n).ok().and_then(Self::from_u128)
+    }
+
+    #[inline]
+    fn from_u64(n: u64) -> Option<Self> {
+        Self::from_u128(n.into())
+    }
+
+    fn from_u128(mut n: u128) -> Option<Self> {
+        let mut digits = [0u32; N];
+        for d in digits.iter_mut() {
+            *d = n as u32;
+            n >>= 32;
+        }
+        if n == 0 {
+            Some(FixedBigUint { digits })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn from_f64(n: f64) -> Option<Self> {
+        BigUint::from_f64(n).and_then(|x| Self::try_from(&x).ok())
+    }
+}
+
+impl<const N: usize> ToPrimitive for FixedBigUint<N> {
+    #[inline]
+    fn to_i64(&self) -> Option<i64> {
+        self.to_u64().and_then(|n| i64::try_from(n).ok())
+    }
+
+    #[inline]
+    fn to_i128(&self) -> Option<i128> {
+        self.to_u128().and_then(|n| i128::try_from(n).ok())
+    }
+
+    #[inline]
+    fn to_u64(&self) -> Option<u64> {
+        self.to_u128().and_then(|n| u64::try_from(n).ok())
+    }
+
+    fn to_u128(&self) -> Option<u128> {
+        if self.bits() > 128 {
+            return None;
+        }
+        let n = self
+            .digits
+            .iter()
+            .take(4)
+            .rev()
+            .fold(0u128, |n, &d| n << 32 | u128::from(d));
+        Some(n)
+    }
+
+    #[inline]
+    fn to_f64(&self) -> Option<f64> {
+        BigUint::from(*self).to_f64()
+    }
+}
+
+impl<const N: usize> From<FixedBigUint<N>> for BigUint {
+    #[inline]
+    fn from(n: FixedBigUint<N>) -> Self {
+        BigUint::from_slice(&n.digits)
+    }
+}
+
+impl<const N: usize> TryFrom<&BigUint> for FixedBigUint<N> {
+    type Error = TryFromBigIntError<()>;
+
+    fn try_from(value: &BigUint) -> Result<Self, TryFromBigIntError<()>> {
+        if value.bits() > Self::BITS {
+            return Err(TryFromBigIntError::new(()));
+        }
+        let mut digits = [0u32; N];
+        for (d, v) in digits.iter_mut().zip(value.iter_u32_digits()) {
+            *d = v;
+        }
+        Ok(FixedBigUint { digits })
+    }
+}
+
+impl<const N: usize> TryFrom<BigUint> for FixedBigUint<N> {
+    type Error = TryFromBigIntError<BigUint>;
+
+    #[inline]
+    fn try_from(value: BigUint) -> Result<Self, TryFromBigIntError<BigUint>> {
+        Self::try_from(&value).map_err(|_| TryFromBigIntError::new(value))
+    }
+}
+
+impl<const N: usize> FixedBigUint<N> {
+    /// Returns the `k` bits of `self` starting at bit `pos`, for `k` up to 32.
+    fn bits_at(&self, pos: u64, k: u32) -> u32 {
+        let (digit, bit) = ((pos / 32) as usize, (pos % 32) as u32);
+        let lo = u64::from(self.digits[digit]);
+        let hi = self.digits.get(digit + 1).map_or(0, |&d| u64::from(d));
+        ((hi << 32 | lo) >> bit) as u32 & (u32::MAX >> (32 - k))
+    }
+
+    /// Formats `self` in a power-of-two radix, with `k` bits per digit.
+    fn fmt_pow2(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        k: u32,
+        prefix: &str,
+        upper: bool,
+    ) -> fmt::Result {
+        let len = cmp::max(1, Integer::div_ceil(&self.bits(), &u64::from(k)));
+        let len = usize::try_from(len).unwrap();
+        pad_integral(f, prefix, len, |f| {
+            for i in (0..len as u64).rev() {
+                let digit = char::from_digit(self.bits_at(i * u64::from(k), k), 1 << k).unwrap();
+                f.write_char(if upper {
+                    digit.to_ascii_uppercase()
+                } else {
+                    digit
+                })?;
+            }
+            Ok(())
+        })
+    }
+}
+
+/// The largest power of ten that fits in a `u64`, `10^19`.
+const DECIMAL_CHUNK: u64 = 10_000_000_000_000_000_000;
+
+impl<const N: usize> fmt::Display for FixedBigUint<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The value in base 10^19, least significant first. Each chunk holds more than 63 bits,
+        // so there are never more chunks than 32-bit digits.
+        let mut chunks = [0u64; N];
+        let mut len = 0;
+        let mut n = self.digits;
+        while n.iter().any(|&d| d != 0) {
+            let mut r = 0u128;
+            for d in n.iter_mut().rev() {
+                let x = r << 32 | u128::from(*d);
+                *d = (x / u128::from(DECIMAL_CHUNK)) as u32;
+                r = x % u128::from(DECIMAL_CHUNK);
+            }
+            chunks[len] = r as u64;
+            len += 1;
+        }
+
+        let chunks = &chunks[..len];
+        let digits = match chunks.last() {
+            Some(top) => digit_count(*top) + 19 * (len - 1),
+            None => 1,
+        };
+        pad_integral(f, "", digits, |f| match chunks.split_last() {
+            Some((top, rest)) => {
+                write!(f, "{}", top)?;
+                rest.iter()
+                    .rev()
+                    .try_for_each(|chunk| write!(f, "{:019}", chunk))
+            }
+            None => f.write_char('0'),
+        })
+    }
+}
+
+impl<const N: usize> fmt::Debug for FixedBigUint<N> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl<const N: usize> fmt::Binary for FixedBigUint<N> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_pow2(f, 1, "0b", false)
+    }
+}
+
+impl<const N: usize> fmt::Octal for FixedBigUint<N> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_pow2(f, 3, "0o", false)
+    }
+}
+
+impl<const N: usize> fmt::LowerHex for FixedBigUint<N> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_pow2(f, 4, "0x", false)
+    }
+}
+
+impl<const N: usize> fmt::UpperHex for FixedBigUint<N> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_pow2(f, 4, "0x", true)
+    }
+}
+
+/// Returns the number of decimal digits of `n`, which is 1 for zero.
+fn digit_count(mut n: u64) -> usize {
+    let mut count = 1;
+    while n >= 10 {
+        n /= 10;
+        count += 1;
+    }
+    count
+}
+
+/// Writes `len` digits with `write_digits`, padded by the flags of `f` like
+/// [`fmt::Formatter::pad_integral`] for a non-negative number, but without collecting the digits
+/// into a string first.
+fn pad_integral(
+    f: &mut fmt::Formatter<'_>,
+    prefix: &str,
+    len: usize,
+    write_digits: impl FnOnce(&mut fmt::Formatter<'_>) -> fmt::Result,
+) -> fmt::Result {
+    let prefix = if f.alternate() { prefix } else { "" };
+    let sign = if f.sign_plus() { "+" } else { "" };
+    let padding = f.width().map_or(0, |width| {
+        width.saturating_sub(sign.len() + prefix.len() + len)
+    });
+
+    if f.sign_aware_zero_pad() {
+        f.write_str(sign)?;
+        f.write_str(prefix)?;
+        for _ in 0..padding {
+            f.write_char('0')?;
+        }
+        return write_digits(f);
+    }
+
+    let (before, after) = match f.align() {
+        Some(fmt::Alignment::Left) => (0, padding),
+        Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        Some(fmt::Alignment::Right) | None => (padding, 0),
+    };
+    let fill = f.fill();
+    for _ in 0..before {
+        f.write_char(fill)?;
+    }
+    f.write_str(sign)?;
+    f.write_str(prefix)?;
+    write_digits(f)?;
+    for _ in 0..after {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
+
+#[test]
+fn test_div_rem_top_bit() {
+    // A divisor with its top bit set makes the running remainder carry out of the top digit.
+    let d = FixedBigUint::from_u32_digits([1, u32::MAX]);
+    let a = FixedBigUint::MAX;
+    let (q, r) = a.divmod(&d);
+    assert_eq!(q, FixedBigUint::from_u32_digits([1, 0]));
+    assert_eq!(r, FixedBigUint::from_u32_digits([u32::MAX - 1, 0]));
+}
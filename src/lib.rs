@@ -58,12 +58,33 @@
 //!
 //! See the "Features" section for instructions for enabling random number generation.
 //!
+//! ## Operators and Allocation
+//!
+//! The arithmetic and bitwise operators are implemented for both owned values and references,
+//! on either side. An owned left operand is reused for the result, so `a + &b` adds into the
+//! storage of `a`, while `&a + &b` has to allocate a new value. The assignment operators,
+//! `+=`, `-=`, `*=`, `/=`, `%=` and the rest, also accept a reference on the right, so a loop
+//! can accumulate into one value without cloning each term:
+//!
+//! ```rust
+//! use num_bigint::BigUint;
+//!
+//! let terms: Vec<BigUint> = (1u32..=100).map(|i| BigUint::from(i).pow(20)).collect();
+//! let mut sum = BigUint::ZERO;
+//! for term in &terms {
+//!     sum += term;
+//! }
+//! assert_eq!(sum, terms.iter().sum());
+//! ```
+//!
 //! ## Features
 //!
 //! The `std` crate feature is enabled by default, which enables [`std::error::Error`]
 //! implementations and some internal use of floating point approximations. This can be disabled by
 //! depending on `num-bigint` with `default-features = false`. Either way, the `alloc` crate is
-//! always required for heap allocation of the `BigInt`/`BigUint` digits.
+//! always required for heap allocation of the `BigInt`/`BigUint` digits. For arithmetic that
+//! doesn't allocate, though the crate still needs `alloc`, [`FixedBigUint`] stores a fixed
+//! number of digits inline.
 //!
 //! ### Random Generation
 //!
@@ -86,16 +107,59 @@
 //!
 //! ### Serialization
 //!
-//! The `serde` feature adds implementations of [`Serialize`][serde::Serialize] and
-//! [`Deserialize`][serde::Deserialize] for both `BigInt` and `BigUint`. Their serialized data is
+//! The `serde` feature adds implementations of [`Serialize`][::serde::Serialize] and
+//! [`Deserialize`][::serde::Deserialize] for both `BigInt` and `BigUint`. Their serialized data is
 //! generated portably, regardless of platform differences like the internal digit size.
 //!
+//! The default format is a sequence of base-2^32 digits, which is compact but unreadable in text.
+//! For formats like JSON, individual fields can be written as strings with
+//! `#[serde(with = "...")]`, using the [`serde::decimal_str`] or [`serde::hex_str`] modules.
+//!
+//! A length-prefixed byte string, which takes a fraction of the space for small values, is
+//! available per field with [`serde::compact`]. The default format doesn't change, so data
+//! written before can still be read, and [`serde::upgrade_serde_v1_to_v2`] converts it.
+//!
+//! ### Decimal Numbers
+//!
+//! The `decimal` feature adds [`BigDecimal`], an arbitrary-precision decimal number represented
+//! as a `BigInt` mantissa times a power of ten, with exact addition, subtraction and
+//! multiplication, and division rounded to a chosen scale.
+//!
+//! ### Multiplication
+//!
+//! The `ntt-mul` feature enables multiplication by number-theoretic transform for very large
+//! operands, which is asymptotically faster than the default Toom-3 multiplication. The choice of
+//! algorithm is still made automatically from the operand sizes.
+//!
+//! ### Number Theory
+//!
+//! The `number-theory` feature adds functions of a number's prime factorization, such as
+//! [`BigUint::euler_totient`]. These factor by trial division, which is only practical for
+//! numbers with small prime factors. It also adds [`BigUint::next_prime`] and
+//! [`BigUint::prev_prime`] to search for the nearest primes, and [`BigUint::prime_sieve`] to list
+//! all the primes up to a limit. Together with `rand`, it adds `BigUint::factorize`, which also
+//! uses Pollard's rho algorithm to split larger factors.
+//!
+//! ### Zeroizing Secrets
+//!
+//! The `zeroize` feature implements [`Zeroize`][::zeroize::Zeroize] for `BigInt` and `BigUint`,
+//! to clear secret values like private keys from memory, along with [`ZeroizingBigInt`] and
+//! [`ZeroizingBigUint`] to do so when they're dropped.
+//!
+//! ### Custom Allocators
+//!
+//! The `allocator-api` feature requires a nightly compiler, for the unstable `allocator_api`.
+//! It adds `BigUintIn` and `BigIntIn`, which store their digits with a custom
+//! [`Allocator`][alloc::alloc::Allocator], such as an arena or a pool, and convert to and from
+//! `BigUint` and `BigInt` to compute with them.
+//!
 //!
 //! ## Compatibility
 //!
 //! The `num-bigint` crate is tested for rustc 1.60 and greater.
 
 #![cfg_attr(docsrs, feature(doc_cfg))]
+#![cfg_attr(feature = "allocator-api", feature(allocator_api))]
 #![doc(html_root_url = "https://docs.rs/num-bigint/0.4")]
 #![warn(rust_2018_idioms)]
 #![no_std]
@@ -114,6 +178,10 @@ mod macros;
 mod bigint;
 mod bigrand;
 mod biguint;
+mod decimal;
+mod fixed;
+
+pub mod serde;
 
 #[cfg(target_pointer_width = "32")]
 type UsizePromotion = u32;
@@ -125,37 +193,90 @@ type IsizePromotion = i32;
 #[cfg(target_pointer_width = "64")]
 type IsizePromotion = i64;
 
+/// The error type returned when parsing a [`BigInt`] or [`BigUint`] from a string fails.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ParseBigIntError {
-    kind: BigIntErrorKind,
+    kind: ParseBigIntErrorKind,
+    position: usize,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-enum BigIntErrorKind {
+/// The reason a string could not be parsed as a big integer, returned by
+/// [`ParseBigIntError::kind`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseBigIntErrorKind {
+    /// The string has no digits, after any sign.
     Empty,
+    /// The string contains a character that is not a digit in any radix, such as a second
+    /// sign or a leading underscore.
     InvalidDigit,
+    /// The string contains a digit that is too large for the radix, such as `'9'` in octal.
+    DigitOutOfRange,
 }
 
 impl ParseBigIntError {
     fn __description(&self) -> &str {
-        use crate::BigIntErrorKind::*;
+        use crate::ParseBigIntErrorKind::*;
         match self.kind {
             Empty => "cannot parse integer from empty string",
             InvalidDigit => "invalid digit found in string",
+            DigitOutOfRange => "digit out of range for radix found in string",
+        }
+    }
+
+    fn empty(position: usize) -> Self {
+        ParseBigIntError {
+            kind: ParseBigIntErrorKind::Empty,
+            position,
         }
     }
 
-    fn empty() -> Self {
+    fn invalid(position: usize) -> Self {
         ParseBigIntError {
-            kind: BigIntErrorKind::Empty,
+            kind: ParseBigIntErrorKind::InvalidDigit,
+            position,
         }
     }
 
-    fn invalid() -> Self {
+    fn out_of_range(position: usize) -> Self {
         ParseBigIntError {
-            kind: BigIntErrorKind::InvalidDigit,
+            kind: ParseBigIntErrorKind::DigitOutOfRange,
+            position,
         }
     }
+
+    /// Moves the position right by `offset` bytes, for an error in a suffix of the input.
+    fn shifted(mut self, offset: usize) -> Self {
+        self.position += offset;
+        self
+    }
+
+    /// Returns the reason that parsing failed.
+    ///
+    /// ```
+    /// use num_bigint::{BigUint, ParseBigIntErrorKind};
+    /// use num_traits::Num;
+    ///
+    /// let err = BigUint::from_str_radix("178", 8).unwrap_err();
+    /// assert_eq!(*err.kind(), ParseBigIntErrorKind::DigitOutOfRange);
+    /// ```
+    pub fn kind(&self) -> &ParseBigIntErrorKind {
+        &self.kind
+    }
+
+    /// Returns the byte offset in the input string of the first invalid character, or of the
+    /// end of the input if it had no digits.
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    /// use num_traits::Num;
+    ///
+    /// assert_eq!(BigInt::from_str_radix("-12x4", 10).unwrap_err().position(), 3);
+    /// assert_eq!(BigInt::from_str_radix("-", 10).unwrap_err().position(), 1);
+    /// ```
+    pub fn position(&self) -> usize {
+        self.position
+    }
 }
 
 impl fmt::Display for ParseBigIntError {
@@ -211,19 +332,180 @@ impl<T> fmt::Display for TryFromBigIntError<T> {
     }
 }
 
+/// The error type returned when decoding a [`BigUint`] from LEB128 bytes fails, because the
+/// input ended before the last byte of the integer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Leb128Error {
+    len: usize,
+}
+
+impl Leb128Error {
+    fn __description(&self) -> &str {
+        "LEB128 integer is truncated"
+    }
+}
+
+impl fmt::Display for Leb128Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: no final byte in {} bytes of input",
+            self.__description(),
+            self.len
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for Leb128Error {
+    fn description(&self) -> &str {
+        self.__description()
+    }
+}
+
+/// The error type returned when the result of an operation on a [`FixedBigUint`] doesn't fit
+/// in its width.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overflow;
+
+impl Overflow {
+    fn __description(&self) -> &str {
+        "integer overflow"
+    }
+}
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.__description().fmt(f)
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for Overflow {
+    fn description(&self) -> &str {
+        self.__description()
+    }
+}
+
+/// The error type returned when decoding a big integer from an ASN.1 DER `INTEGER` fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DerError {
+    kind: DerErrorKind,
+}
+
+/// The reason that bytes could not be decoded as a DER `INTEGER`, returned by
+/// [`DerError::kind`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DerErrorKind {
+    /// The tag is not `0x02`, for `INTEGER`.
+    UnexpectedTag,
+    /// The input ends before the length or the contents.
+    Truncated,
+    /// The length is in the indefinite or a non-minimal form, or is too large.
+    InvalidLength,
+    /// The contents are empty, or have a redundant leading `0x00` or `0xff` byte.
+    NonMinimal,
+    /// The integer is negative, but was decoded as a [`BigUint`].
+    Negative,
+    /// There are more bytes after the integer.
+    TrailingData,
+}
+
+impl DerError {
+    fn __description(&self) -> &str {
+        use crate::DerErrorKind::*;
+        match self.kind {
+            UnexpectedTag => "DER value is not an INTEGER",
+            Truncated => "DER value is truncated",
+            InvalidLength => "invalid DER length",
+            NonMinimal => "DER INTEGER is not minimally encoded",
+            Negative => "DER INTEGER is negative",
+            TrailingData => "trailing data after DER value",
+        }
+    }
+
+    fn new(kind: DerErrorKind) -> Self {
+        DerError { kind }
+    }
+
+    /// Returns the reason that decoding failed.
+    ///
+    /// ```
+    /// use num_bigint::{BigUint, DerErrorKind};
+    ///
+    /// let err = BigUint::from_der_integer(&[0x02, 0x01, 0xff]).unwrap_err();
+    /// assert_eq!(*err.kind(), DerErrorKind::Negative);
+    /// ```
+    pub fn kind(&self) -> &DerErrorKind {
+        &self.kind
+    }
+}
+
+impl fmt::Display for DerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.__description().fmt(f)
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for DerError {
+    fn description(&self) -> &str {
+        self.__description()
+    }
+}
+
+pub use crate::biguint::BarrettReducer;
 pub use crate::biguint::BigUint;
+pub use crate::biguint::BigUintBuilder;
+pub use crate::biguint::BigUintRange;
+pub use crate::biguint::BigUintStepRange;
+pub use crate::biguint::Bits;
+pub use crate::biguint::CatalanIter;
+pub use crate::biguint::ConvergentIter;
+pub use crate::biguint::Digit;
+pub use crate::biguint::DigitsMut;
+pub use crate::biguint::ModularArithmetic;
+pub use crate::biguint::MontgomeryForm;
+pub use crate::biguint::MontgomeryInt;
+pub use crate::biguint::ProductTree;
+pub use crate::biguint::SeparatedDisplay;
 pub use crate::biguint::ToBigUint;
 pub use crate::biguint::U32Digits;
 pub use crate::biguint::U64Digits;
+pub use crate::biguint::WrappingBigUint;
 
 pub use crate::bigint::BigInt;
 pub use crate::bigint::Sign;
 pub use crate::bigint::ToBigInt;
 
+pub use crate::fixed::FixedBigUint;
+
+#[cfg(feature = "decimal")]
+#[cfg_attr(docsrs, doc(cfg(feature = "decimal")))]
+pub use crate::decimal::BigDecimal;
+
 #[cfg(feature = "rand")]
 #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
 pub use crate::bigrand::{RandBigInt, RandomBits, UniformBigInt, UniformBigUint};
 
+#[cfg(feature = "allocator-api")]
+#[cfg_attr(docsrs, doc(cfg(feature = "allocator-api")))]
+pub use crate::bigint::BigIntIn;
+#[cfg(feature = "allocator-api")]
+#[cfg_attr(docsrs, doc(cfg(feature = "allocator-api")))]
+pub use crate::biguint::BigUintIn;
+
+#[cfg(feature = "zeroize")]
+#[cfg_attr(docsrs, doc(cfg(feature = "zeroize")))]
+pub use crate::bigint::ZeroizingBigInt;
+#[cfg(feature = "zeroize")]
+#[cfg_attr(docsrs, doc(cfg(feature = "zeroize")))]
+pub use crate::biguint::ZeroizingBigUint;
+
 mod big_digit {
     // A [`BigDigit`] is a [`BigUint`]'s composing element.
     cfg_digit!(
@@ -0,0 +1,496 @@
+//! Alternative serde formats for [`BigInt`][crate::BigInt] and [`BigUint`] fields, to use with
+//! the `#[serde(with = "...")]` attribute.
+//!
+//! The default implementations of `Serialize` and `Deserialize` use a compact sequence of `u32`
+//! digits. [`hex_str`] and [`decimal_str`] use strings instead, which suit text formats and APIs
+//! that expect them, and [`u64_digits`] uses `u64` digits, which halves the number of elements.
+//!
+//! ```ignore
+//! use num_bigint::{BigInt, BigUint};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Transaction {
+//!     #[serde(with = "num_bigint::serde::hex_str")]
+//!     value: BigUint,
+//!     #[serde(with = "num_bigint::serde::decimal_str")]
+//!     balance: BigInt,
+//!     #[serde(with = "num_bigint::serde::u64_digits")]
+//!     nonce: BigUint,
+//! }
+//! ```
+//!
+//! [`human_readable`] uses a decimal string only in human-readable formats like JSON, and the
+//! default format in others.
+//!
+//! [`compact`] uses a length-prefixed byte string instead, which takes a fraction of the space
+//! for small values, and [`upgrade_serde_v1_to_v2`] converts data from the default format to it.
+//!
+//! None of these modules requires the `std` feature.
+#![cfg(feature = "serde")]
+#![cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+
+use crate::BigUint;
+
+use ::serde::de::{Error, SeqAccess, Unexpected, Visitor};
+use ::serde::ser::SerializeSeq;
+use ::serde::{Deserializer, Serializer};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use core::{cmp, fmt, mem};
+use num_traits::Num;
+
+mod private {
+    use crate::{BigInt, BigUint, Sign};
+    use alloc::vec::Vec;
+
+    /// The integer types that can be written as strings.
+    pub trait StrRadix: Sized {
+        fn to_str_radix(&self, radix: u32) -> alloc::string::String;
+        fn from_magnitude(negative: bool, magnitude: BigUint) -> Option<Self>;
+    }
+
+    impl StrRadix for BigUint {
+        fn to_str_radix(&self, radix: u32) -> alloc::string::String {
+            BigUint::to_str_radix(self, radix)
+        }
+
+        fn from_magnitude(negative: bool, magnitude: BigUint) -> Option<Self> {
+            if negative && !magnitude.is_zero() {
+                None
+            } else {
+                Some(magnitude)
+            }
+        }
+    }
+
+    impl StrRadix for BigInt {
+        fn to_str_radix(&self, radix: u32) -> alloc::string::String {
+            BigInt::to_str_radix(self, radix)
+        }
+
+        fn from_magnitude(negative: bool, magnitude: BigUint) -> Option<Self> {
+            let x = BigInt::from(magnitude);
+            Some(if negative { -x } else { x })
+        }
+    }
+
+    /// The integer types that can be written in the compact byte format.
+    pub trait Compact: Sized {
+        fn to_compact_bytes(&self) -> Option<Vec<u8>>;
+        fn from_compact_bytes(bytes: &[u8]) -> Option<Self>;
+    }
+
+    impl Compact for BigUint {
+        fn to_compact_bytes(&self) -> Option<Vec<u8>> {
+            let mut bytes = Vec::new();
+            push_compact_bytes(&mut bytes, self)?;
+            Some(bytes)
+        }
+
+        fn from_compact_bytes(bytes: &[u8]) -> Option<Self> {
+            if bytes.len() < 4 {
+                return None;
+            }
+            let (len, bytes) = bytes.split_at(4);
+            let len = u32::from_be_bytes([len[0], len[1], len[2], len[3]]);
+            if usize::try_from(len).ok()? != bytes.len() || bytes.first() == Some(&0) {
+                return None;
+            }
+            Some(BigUint::from_bytes_be(bytes))
+        }
+    }
+
+    impl Compact for BigInt {
+        fn to_compact_bytes(&self) -> Option<Vec<u8>> {
+            let sign = match self.sign() {
+                Sign::Minus => 0xff,
+                Sign::NoSign => 0,
+                Sign::Plus => 1,
+            };
+            let mut bytes = vec![sign];
+            push_compact_bytes(&mut bytes, self.magnitude())?;
+            Some(bytes)
+        }
+
+        fn from_compact_bytes(bytes: &[u8]) -> Option<Self> {
+            let (&sign, magnitude) = bytes.split_first()?;
+            let sign = match sign {
+                0xff => Sign::Minus,
+                0 => Sign::NoSign,
+                1 => Sign::Plus,
+                _ => return None,
+            };
+            let magnitude = BigUint::from_compact_bytes(magnitude)?;
+            if (sign == Sign::NoSign) != magnitude.is_zero() {
+                return None;
+            }
+            Some(BigInt::from_biguint(sign, magnitude))
+        }
+    }
+
+    /// Appends the length of `x` in bytes as a big-endian `u32`, then its minimal big-endian
+    /// bytes, or returns `None` if the length doesn't fit.
+    fn push_compact_bytes(bytes: &mut Vec<u8>, x: &BigUint) -> Option<()> {
+        let len = u32::try_from(x.byte_count()).ok()?;
+        bytes.reserve(4 + len as usize);
+        bytes.extend_from_slice(&len.to_be_bytes());
+        if len > 0 {
+            bytes.extend_from_slice(&x.to_bytes_be());
+        }
+        Some(())
+    }
+}
+
+use self::private::{Compact, StrRadix};
+
+/// Parses a string with an optional `-`, then an optional `0x` or `0X` if `hex` is set, then
+/// digits in the radix.
+fn parse<T: StrRadix>(s: &str, hex: bool) -> Option<T> {
+    let (negative, s) = match s.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, s),
+    };
+    let (radix, s) = if hex {
+        let s = s
+            .strip_prefix("0x")
+            .or_else(|| s.strip_prefix("0X"))
+            .unwrap_or(s);
+        (16, s)
+    } else {
+        (10, s)
+    };
+    // `from_str_radix` allows its own sign, which would come after ours.
+    if s.starts_with(['+', '-']) {
+        return None;
+    }
+    let magnitude = BigUint::from_str_radix(s, radix).ok()?;
+    T::from_magnitude(negative, magnitude)
+}
+
+struct StrVisitor<T> {
+    hex: bool,
+    marker: PhantomData<T>,
+}
+
+impl<'de, T: StrRadix> Visitor<'de> for StrVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.hex {
+            formatter.write_str("a hexadecimal integer string")
+        } else {
+            formatter.write_str("a decimal integer string")
+        }
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        parse(v, self.hex).ok_or_else(|| E::invalid_value(Unexpected::Str(v), &self))
+    }
+}
+
+fn deserialize_str<'de, T, D>(deserializer: D, hex: bool) -> Result<T, D::Error>
+where
+    T: StrRadix,
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_str(StrVisitor {
+        hex,
+        marker: PhantomData,
+    })
+}
+
+/// Serializes a [`BigInt`][crate::BigInt] or [`BigUint`] as a lowercase hexadecimal string with
+/// a `0x` prefix, like `"0x1a2b3c"` or `"-0xff"`.
+///
+/// Deserializing accepts the prefix in either case or no prefix, and digits in either case.
+pub mod hex_str {
+    use super::*;
+
+    /// Serializes `value` as a hexadecimal string with a `0x` prefix.
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: StrRadix,
+        S: Serializer,
+    {
+        let digits = value.to_str_radix(16);
+        let mut s = String::with_capacity(digits.len() + 2);
+        match digits.strip_prefix('-') {
+            Some(digits) => {
+                s.push_str("-0x");
+                s.push_str(digits);
+            }
+            None => {
+                s.push_str("0x");
+                s.push_str(&digits);
+            }
+        }
+        serializer.serialize_str(&s)
+    }
+
+    /// Deserializes a hexadecimal string, with or without a `0x` prefix.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: StrRadix,
+        D: Deserializer<'de>,
+    {
+        deserialize_str(deserializer, true)
+    }
+}
+
+/// Serializes a [`BigInt`][crate::BigInt] or [`BigUint`] as a decimal string, like
+/// `"-12345678901234567890"`.
+pub mod decimal_str {
+    use super::*;
+
+    /// Serializes `value` as a decimal string.
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: StrRadix,
+        S: Serializer,
+    {
+        serializer.serialize_str(&value.to_str_radix(10))
+    }
+
+    /// Deserializes a decimal string.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: StrRadix,
+        D: Deserializer<'de>,
+    {
+        deserialize_str(deserializer, false)
+    }
+}
+
+/// Serializes a [`BigInt`][crate::BigInt] or [`BigUint`] as a decimal string in human-readable
+/// formats like JSON, and in the default format of `u32` digits in others.
+///
+/// Deserializing a human-readable format accepts either a decimal string or the default format,
+/// so a field can switch to this module without converting data that was already written.
+pub mod human_readable {
+    use super::*;
+    use ::serde::de::value::SeqAccessDeserializer;
+    use ::serde::{Deserialize, Serialize};
+
+    /// Serializes `value` as a decimal string if the format is human-readable, or in the default
+    /// format otherwise.
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: StrRadix + Serialize,
+        S: Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&value.to_str_radix(10))
+        } else {
+            value.serialize(serializer)
+        }
+    }
+
+    /// Deserializes a decimal string or the default format if the format is human-readable, or
+    /// the default format otherwise.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: StrRadix + Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(StrOrSeqVisitor {
+                marker: PhantomData,
+            })
+        } else {
+            T::deserialize(deserializer)
+        }
+    }
+
+    struct StrOrSeqVisitor<T> {
+        marker: PhantomData<T>,
+    }
+
+    impl<'de, T: StrRadix + Deserialize<'de>> Visitor<'de> for StrOrSeqVisitor<T> {
+        type Value = T;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("a decimal integer string or a sequence")
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            parse(v, false).ok_or_else(|| E::invalid_value(Unexpected::Str(v), &self))
+        }
+
+        fn visit_seq<S>(self, seq: S) -> Result<Self::Value, S::Error>
+        where
+            S: SeqAccess<'de>,
+        {
+            T::deserialize(SeqAccessDeserializer::new(seq))
+        }
+    }
+}
+
+/// Serializes a [`BigUint`] as a sequence of `u64` digits, least significant first, like
+/// `[0, 1]` for `2^64`.
+///
+/// This takes half as many elements as the default format of `u32` digits, and matches the
+/// 64-bit words of other libraries. Deserializing rejects a last digit of zero, so each value has
+/// exactly one encoding, and zero is the empty sequence.
+pub mod u64_digits {
+    use super::*;
+
+    /// Serializes `value` as a sequence of `u64` digits.
+    pub fn serialize<S>(value: &BigUint, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let digits = value.iter_u64_digits();
+        let mut seq = serializer.serialize_seq(Some(digits.len()))?;
+        for digit in digits {
+            seq.serialize_element(&digit)?;
+        }
+        seq.end()
+    }
+
+    /// Deserializes a sequence of `u64` digits without trailing zeros.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<BigUint, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(U64Visitor)
+    }
+
+    struct U64Visitor;
+
+    impl<'de> Visitor<'de> for U64Visitor {
+        type Value = BigUint;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("a sequence of unsigned 64-bit numbers with a nonzero last element")
+        }
+
+        fn visit_seq<S>(self, mut seq: S) -> Result<Self::Value, S::Error>
+        where
+            S: SeqAccess<'de>,
+        {
+            // Don't trust the size hint with more than a megabyte, as serde itself doesn't.
+            const MAX_PREALLOC: usize = 1024 * 1024 / mem::size_of::<u64>();
+            let mut digits =
+                Vec::with_capacity(cmp::min(seq.size_hint().unwrap_or(0), MAX_PREALLOC));
+            while let Some(digit) = seq.next_element::<u64>()? {
+                digits.push(digit);
+            }
+            if digits.last() == Some(&0) {
+                return Err(S::Error::invalid_value(Unexpected::Unsigned(0), &self));
+            }
+            Ok(BigUint::from_u64_digits(&digits))
+        }
+    }
+}
+
+/// Serializes a [`BigInt`][crate::BigInt] or [`BigUint`] as a byte string: the length of the
+/// magnitude in bytes as a big-endian `u32`, then its minimal big-endian bytes, after a sign byte
+/// of `0xff`, `0`, or `1` for a `BigInt`.
+///
+/// Small values take a fraction of the space of the default format, which spends four bytes on
+/// each `u32` digit. Deserializing rejects leading zeros and a sign that disagrees with the
+/// magnitude, so each value has exactly one encoding. Formats without a bytes type may write
+/// them as a sequence of `u8`, which is also accepted.
+///
+/// This is not compatible with the default format, so changing a field to use it needs its
+/// stored data converted first, as with [`upgrade_serde_v1_to_v2`].
+pub mod compact {
+    use super::*;
+    use ::serde::ser::Error as _;
+
+    /// Serializes `value` as its compact bytes.
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Compact,
+        S: Serializer,
+    {
+        match value.to_compact_bytes() {
+            Some(bytes) => serializer.serialize_bytes(&bytes),
+            None => Err(S::Error::custom(
+                "integer has too many bytes for the compact format",
+            )),
+        }
+    }
+
+    /// Deserializes the compact bytes of a value.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: Compact,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_bytes(CompactVisitor {
+            marker: PhantomData,
+        })
+    }
+
+    struct CompactVisitor<T> {
+        marker: PhantomData<T>,
+    }
+
+    impl<'de, T: Compact> Visitor<'de> for CompactVisitor<T> {
+        type Value = T;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("a big-endian length and magnitude in bytes")
+        }
+
+        fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            T::from_compact_bytes(v).ok_or_else(|| E::invalid_value(Unexpected::Bytes(v), &self))
+        }
+
+        fn visit_seq<S>(self, mut seq: S) -> Result<Self::Value, S::Error>
+        where
+            S: SeqAccess<'de>,
+        {
+            // Don't trust the size hint with more than a megabyte, as serde itself doesn't.
+            const MAX_PREALLOC: usize = 1024 * 1024;
+            let mut bytes =
+                Vec::with_capacity(cmp::min(seq.size_hint().unwrap_or(0), MAX_PREALLOC));
+            while let Some(byte) = seq.next_element::<u8>()? {
+                bytes.push(byte);
+            }
+            self.visit_bytes(&bytes)
+        }
+    }
+}
+
+/// Reads a [`BigInt`][crate::BigInt] or [`BigUint`] in the default format from `deserializer`,
+/// and returns the bytes that [`compact`] serializes it as.
+///
+/// This converts stored data for a field that moves to the compact format. The input is whatever
+/// the format wrote for the default implementation, including its length prefix for the digit
+/// sequence, so this works with any format that can read it back.
+///
+/// # Examples
+///
+/// ```
+/// use num_bigint::BigUint;
+/// use num_bigint::serde::upgrade_serde_v1_to_v2;
+/// use serde::de::value::{Error, SeqDeserializer};
+///
+/// // The digits `[0x0506_0708, 0x0000_0001]`, which are 2^32 + 0x05060708.
+/// let v1 = SeqDeserializer::<_, Error>::new([0x0506_0708u32, 1].into_iter());
+/// let v2 = upgrade_serde_v1_to_v2::<BigUint, _>(v1).unwrap();
+/// assert_eq!(v2, [0, 0, 0, 5, 1, 5, 6, 7, 8]);
+/// ```
+pub fn upgrade_serde_v1_to_v2<'de, T, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+where
+    T: Compact + ::serde::Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    let value = T::deserialize(deserializer)?;
+    value
+        .to_compact_bytes()
+        .ok_or_else(|| D::Error::custom("integer has too many bytes for the compact format"))
+}
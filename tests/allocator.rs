@@ -0,0 +1,96 @@
+#![cfg(feature = "allocator-api")]
+#![feature(allocator_api)]
+
+use num_bigint::{BigInt, BigIntIn, BigUint, BigUintIn, Sign};
+use num_traits::{One, Zero};
+
+use std::alloc::{AllocError, Allocator, Global, Layout};
+use std::cell::Cell;
+use std::ptr::NonNull;
+
+/// Forwards to the global allocator, counting the bytes currently allocated.
+#[derive(Clone, Copy)]
+struct Counting<'a>(&'a Cell<usize>);
+
+unsafe impl Allocator for Counting<'_> {
+    fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        self.0.set(self.0.get() + layout.size());
+        Global.allocate(layout)
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        self.0.set(self.0.get() - layout.size());
+        Global.deallocate(ptr, layout)
+    }
+}
+
+#[test]
+fn test_biguint_in() {
+    let bytes = Cell::new(0);
+    let alloc = Counting(&bytes);
+
+    let zero = BigUintIn::new_in(alloc);
+    assert!(zero.is_zero());
+    assert_eq!(zero.bits(), 0);
+    assert_eq!(bytes.get(), 0);
+
+    let n = BigUint::from(3u32).pow(500);
+    let stored = BigUintIn::from_biguint(n.clone(), alloc);
+    let size = bytes.get();
+    assert!(size * 8 >= n.bits() as usize);
+    assert_eq!(stored, n);
+    assert_eq!(stored.bits(), n.bits());
+    assert_eq!(stored.to_string(), n.to_string());
+    assert_eq!(format!("{:?}", stored), n.to_string());
+    assert!(zero < stored);
+
+    let copy = stored.clone();
+    assert_eq!(bytes.get(), 2 * size);
+    assert_eq!(copy, stored);
+    assert_eq!(copy.into_biguint(), n);
+    assert_eq!(stored.to_biguint(), n);
+    drop(stored);
+    assert_eq!(bytes.get(), 0);
+
+    let small = BigUintIn::from_biguint(BigUint::one(), alloc);
+    let large = BigUintIn::from_biguint(BigUint::one() << 100u32, alloc);
+    assert!(small < large && large > small);
+    assert_eq!(BigUintIn::<Global>::default(), BigUint::zero());
+}
+
+#[test]
+fn test_bigint_in() {
+    let bytes = Cell::new(0);
+    let alloc = Counting(&bytes);
+
+    let values = [
+        -(BigInt::one() << 200u32),
+        BigInt::from(-5),
+        BigInt::zero(),
+        BigInt::from(5),
+        BigInt::one() << 200u32,
+    ];
+    let stored: Vec<_> = values
+        .iter()
+        .map(|n| BigIntIn::from_bigint(n.clone(), alloc))
+        .collect();
+    assert!(bytes.get() > 0);
+    for (i, (a, x)) in values.iter().zip(&stored).enumerate() {
+        assert_eq!(x, a);
+        assert_eq!(x.sign(), a.sign());
+        assert_eq!(x.magnitude(), a.magnitude());
+        assert_eq!(x.is_zero(), a.is_zero());
+        assert_eq!(x.to_string(), a.to_string());
+        assert_eq!(x.to_bigint(), *a);
+        for (b, y) in values.iter().zip(&stored) {
+            assert_eq!(x.cmp(y), a.cmp(b), "{}", i);
+        }
+    }
+    drop(stored);
+    assert_eq!(bytes.get(), 0);
+
+    let zero = BigIntIn::new_in(alloc);
+    assert_eq!(zero.sign(), Sign::NoSign);
+    assert_eq!(zero.into_bigint(), BigInt::zero());
+    assert_eq!(BigIntIn::<Global>::default(), BigInt::zero());
+}
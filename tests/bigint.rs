@@ -1,6 +1,6 @@
 use num_bigint::BigUint;
 use num_bigint::Sign::{Minus, NoSign, Plus};
-use num_bigint::{BigInt, ToBigInt};
+use num_bigint::{BigInt, ParseBigIntError, ParseBigIntErrorKind, ToBigInt};
 
 use std::cmp::Ordering::{Equal, Greater, Less};
 use std::collections::hash_map::RandomState;
@@ -144,6 +144,14 @@ fn test_to_signed_bytes_be() {
     check("-8388608", vec![128, 0, 0]);
     check("-192", vec![0xff, 0x40]);
     check("128", vec![0, 0x80]);
+
+    // The sign bit needs an extra byte at each byte boundary
+    check("127", vec![0x7f]);
+    check("255", vec![0, 0xff]);
+    check("-128", vec![0x80]);
+    check("-129", vec![0xff, 0x7f]);
+    check("32768", vec![0, 0x80, 0]);
+    check("-32769", vec![0xff, 0x7f, 0xff]);
 }
 
 #[test]
@@ -164,6 +172,10 @@ fn test_from_signed_bytes_be() {
     check(&[128, 0, 0], "-8388608");
     check(&[255; 10], "-1");
     check(&[0xff, 0x40], "-192");
+    check(&[0, 0xff], "255");
+    check(&[0xff], "-1");
+    check(&[0xff, 0x7f], "-129");
+    check(&[0, 0, 0x80], "128");
 }
 
 #[test]
@@ -182,6 +194,53 @@ fn test_signed_bytes_le_round_trip() {
     }
 }
 
+#[test]
+#[cfg(feature = "std")]
+fn test_le_bytes_io() {
+    use std::io;
+
+    let values: Vec<BigInt> = (0..200u32)
+        .flat_map(|i| {
+            let x = BigInt::from(3).pow(i);
+            [-&x, x.clone() - 1, x]
+        })
+        .collect();
+    let mut buf = Vec::new();
+    let mut lens = Vec::new();
+    for x in &values {
+        lens.push(x.write_to_le_bytes(&mut buf).unwrap());
+    }
+    assert_eq!(lens.iter().sum::<usize>(), buf.len());
+
+    let mut r = &buf[..];
+    for (x, &len) in values.iter().zip(&lens) {
+        assert_eq!(&BigInt::read_from_le_bytes(&mut r, len).unwrap(), x);
+    }
+    assert!(r.is_empty());
+
+    // Sign-extended to a fixed width
+    for x in [-300, -129, -1, 0, 1, 127, 300] {
+        let mut r: &[u8] = &i64::to_le_bytes(x);
+        assert_eq!(
+            BigInt::read_from_le_bytes(&mut r, 8).unwrap(),
+            BigInt::from(x)
+        );
+    }
+    assert_eq!(
+        BigInt::read_from_le_bytes(&mut &[][..], 0).unwrap(),
+        BigInt::zero()
+    );
+
+    let mut r: &[u8] = &[0xff, 0xff];
+    let err = BigInt::read_from_le_bytes(&mut r, 3).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+
+    // A length far beyond the data fails without allocating it up front.
+    let mut r: &[u8] = &[0xff, 0xff];
+    let err = BigInt::read_from_le_bytes(&mut r, usize::MAX).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+}
+
 #[test]
 fn test_cmp() {
     let vs: [&[u32]; 4] = [&[2_u32], &[1, 1], &[2, 1], &[1, 1, 1]];
@@ -231,6 +290,126 @@ fn hash<T: Hash>(x: &T) -> u64 {
     hasher.finish()
 }
 
+#[test]
+fn test_parts() {
+    let values = [
+        BigInt::ZERO,
+        BigInt::from(1),
+        BigInt::from(-1),
+        BigInt::from(i64::MIN),
+        BigInt::from(u128::MAX) << 100u32,
+        -(BigInt::from(u128::MAX) << 100u32),
+    ];
+    for n in values {
+        let (sign, magnitude) = n.clone().into_parts();
+        assert_eq!(sign, n.sign());
+        assert_eq!(&magnitude, n.magnitude());
+        assert_eq!(BigInt::from_parts(sign, magnitude), n);
+    }
+
+    // Zero is always `NoSign`, and `NoSign` is always zero.
+    for sign in [Minus, NoSign, Plus] {
+        assert_eq!(
+            BigInt::from_parts(sign, BigUint::ZERO).into_parts(),
+            (NoSign, BigUint::ZERO)
+        );
+    }
+    assert_eq!(
+        BigInt::from_parts(NoSign, BigUint::from(7u32)),
+        BigInt::ZERO
+    );
+}
+
+#[test]
+fn test_unsigned_abs() {
+    for (n, expected) in [
+        (-12345i64, 12345u64),
+        (0, 0),
+        (6789, 6789),
+        (i64::MIN, 1 << 63),
+    ] {
+        let n = BigInt::from(n);
+        let expected = BigUint::from(expected);
+        assert_eq!(n.unsigned_abs_ref(), expected);
+        assert_eq!(BigInt::from(n.unsigned_abs_ref()), n.abs());
+        assert_eq!(n.unsigned_abs(), expected);
+    }
+}
+
+#[test]
+fn test_default() {
+    #[derive(Default)]
+    struct Account {
+        balance: BigInt,
+        history: [BigInt; 2],
+    }
+
+    assert!(BigInt::default().is_zero());
+    assert_eq!(BigInt::default().sign(), NoSign);
+    let account = Account::default();
+    assert!(account.balance.is_zero());
+    assert!(account.history.iter().all(BigInt::is_zero));
+    let parsed = BigInt::parse_bytes(b"?", 10);
+    assert!(parsed.unwrap_or_default().is_zero());
+}
+
+#[test]
+fn test_cmp_primitive() {
+    let values = [
+        i128::MIN,
+        i64::MIN as i128 - 1,
+        i64::MIN as i128,
+        -256,
+        -1,
+        0,
+        1,
+        255,
+        u32::MAX as i128,
+        i64::MAX as i128,
+        u64::MAX as i128,
+        i128::MAX,
+    ];
+    for &a in &values {
+        let big = BigInt::from(a);
+        for &b in &values {
+            assert_eq!(big.cmp_i128(b), a.cmp(&b));
+        }
+    }
+
+    let big = BigInt::one() << 128u32;
+    assert_eq!(big.cmp_i128(i128::MAX), Greater);
+    assert_eq!((-big).cmp_i128(i128::MIN), Less);
+    assert_eq!(BigInt::zero().cmp_i128(-1), Greater);
+}
+
+#[test]
+fn test_clamp() {
+    let min = -(BigInt::one() << 100u32);
+    let max = BigInt::from(10);
+    for (x, expected) in [
+        (-(BigInt::one() << 200u32), &min),
+        (&min - 1, &min),
+        (min.clone(), &min),
+        (max.clone(), &max),
+        (BigInt::from(11), &max),
+        (BigInt::one() << 200u32, &max),
+    ] {
+        assert_eq!(&x.clamp_ref(&min, &max), expected);
+        assert_eq!(&Ord::clamp(x, min.clone(), max.clone()), expected);
+    }
+    for x in [-5, 0, 10] {
+        let x = BigInt::from(x);
+        assert_eq!(x.clamp_ref(&min, &max), x);
+    }
+
+    assert_eq!(BigInt::zero().clamp_to_u64(), 0);
+    assert_eq!(BigInt::from(-1).clamp_to_u64(), 0);
+    assert_eq!((-(BigInt::one() << 100u32)).clamp_to_u64(), 0);
+    assert_eq!(BigInt::from(12345).clamp_to_u64(), 12345);
+    assert_eq!(BigInt::from(u64::MAX).clamp_to_u64(), u64::MAX);
+    assert_eq!((BigInt::one() << 64u32).clamp_to_u64(), u64::MAX);
+}
+
 #[test]
 fn test_hash() {
     let a = BigInt::new(NoSign, vec![]);
@@ -360,6 +539,36 @@ fn test_convert_u128() {
     );
 }
 
+#[test]
+fn test_try_from_primitive() {
+    use std::convert::TryFrom;
+
+    macro_rules! check {
+        ($($T:ty),*) => {$(
+            let (min, max) = (BigInt::from(<$T>::MIN), BigInt::from(<$T>::MAX));
+            assert_eq!(<$T>::try_from(&min), Ok(<$T>::MIN));
+            assert_eq!(<$T>::try_from(&max), Ok(<$T>::MAX));
+            assert_eq!(<$T>::try_from(BigInt::zero()), Ok(0));
+
+            let outside: [BigInt; 2] = [&min - 1, &max + 1];
+            for out in outside {
+                assert!(<$T>::try_from(&out).is_err());
+                assert_eq!(<$T>::try_from(out.clone()).unwrap_err().into_original(), out);
+            }
+        )*};
+    }
+    check!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+    assert_eq!(BigUint::try_from(BigInt::from(5)), Ok(BigUint::from(5u32)));
+    let minus = BigInt::from(-5);
+    assert_eq!(
+        BigUint::try_from(minus.clone())
+            .unwrap_err()
+            .into_original(),
+        minus
+    );
+}
+
 #[test]
 #[allow(clippy::float_cmp)]
 fn test_convert_f32() {
@@ -712,6 +921,20 @@ fn test_sub() {
         assert_assign_op!(a -= nb == c);
         assert_assign_op!(nc -= na == nb);
         assert_assign_op!(a -= a == BigInt::zero());
+
+        for (x, y, diff) in [
+            (&c, &a, &b),
+            (&a, &c, &b),
+            (&nb, &a, &c),
+            (&a, &nb, &c),
+            (&nc, &na, &b),
+            (&na, &nc, &b),
+            (&a, &a, &BigInt::zero()),
+        ] {
+            assert_eq!(x.abs_diff(y), diff.magnitude().clone());
+        }
+        assert_eq!(BigInt::zero().abs_diff(&na), a.magnitude().clone());
+        assert_eq!(na.abs_diff(&BigInt::zero()), a.magnitude().clone());
     }
 }
 
@@ -812,6 +1035,7 @@ fn test_div_mod_floor() {
 fn test_div_rem() {
     fn check_sub(a: &BigInt, b: &BigInt, ans_q: &BigInt, ans_r: &BigInt) {
         let (q, r) = a.div_rem(b);
+        assert_eq!(a.divmod(b), (q.clone(), r.clone()));
         if !r.is_zero() {
             assert_eq!(r.sign(), a.sign());
         }
@@ -1223,6 +1447,94 @@ fn test_from_str_radix() {
     let _y = x.to_string();
 }
 
+#[test]
+fn test_from_str_radix_error() {
+    use num_bigint::ParseBigIntErrorKind::*;
+
+    fn check(s: &str, radix: u32, kind: ParseBigIntErrorKind, position: usize) {
+        let err = BigInt::from_str_radix(s, radix).unwrap_err();
+        assert_eq!((*err.kind(), err.position()), (kind, position), "{:?}", s);
+    }
+    check("", 10, Empty, 0);
+    check("-", 10, Empty, 1);
+    check("+", 10, Empty, 1);
+    check("--7", 10, InvalidDigit, 1);
+    check("-+3", 10, InvalidDigit, 0);
+    check("+-9", 10, InvalidDigit, 1);
+    check("-_1", 2, InvalidDigit, 1);
+    check("-102", 2, DigitOutOfRange, 3);
+    check("+7a", 10, DigitOutOfRange, 2);
+}
+
+#[test]
+fn test_from_str_prefixed() {
+    use num_bigint::ParseBigIntErrorKind::*;
+
+    assert_eq!(BigInt::from_str_prefixed("-0x10"), Ok(BigInt::from(-16)));
+    assert_eq!(BigInt::from_str_prefixed("+0o10"), Ok(BigInt::from(8)));
+    assert_eq!(BigInt::from_str_prefixed("-0b11"), Ok(BigInt::from(-3)));
+    assert_eq!(BigInt::from_str_prefixed("-007"), Ok(BigInt::from(-7)));
+    assert_eq!(BigInt::from_str_prefixed("-0x0"), Ok(BigInt::zero()));
+    assert_eq!(
+        BigInt::from_str_prefixed_with_underscores("-0x_ffff_ffff"),
+        Ok(BigInt::from(-0xffff_ffff_i64))
+    );
+
+    let err = |s: &str, kind: ParseBigIntErrorKind, position: usize| {
+        let e = BigInt::from_str_prefixed(s).unwrap_err();
+        assert_eq!((*e.kind(), e.position()), (kind, position), "{:?}", s);
+    };
+    err("-", Empty, 1);
+    err("-0x", Empty, 3);
+    err("--0x1", InvalidDigit, 1);
+    err("-+0x1", InvalidDigit, 0);
+    err("0x-1", InvalidDigit, 2);
+    err("-0x1_0", InvalidDigit, 4);
+    err("-0b2", DigitOutOfRange, 3);
+}
+
+#[test]
+fn test_from_str_hex_octal_binary() {
+    use num_bigint::ParseBigIntErrorKind::*;
+
+    assert_eq!(BigInt::from_str_hex("-0xff"), Ok(BigInt::from(-255)));
+    assert_eq!(BigInt::from_str_hex("+0XfF"), Ok(BigInt::from(255)));
+    assert_eq!(BigInt::from_str_hex("-ff"), Ok(BigInt::from(-255)));
+    assert_eq!(BigInt::from_str_hex("-0x0"), Ok(BigInt::zero()));
+    assert_eq!(BigInt::from_str_octal("-0o10"), Ok(BigInt::from(-8)));
+    assert_eq!(BigInt::from_str_octal("10"), Ok(BigInt::from(8)));
+    assert_eq!(BigInt::from_str_binary("-0B11"), Ok(BigInt::from(-3)));
+    assert_eq!(BigInt::from_str_binary("1_1"), Ok(BigInt::from(3)));
+    let big = -(BigInt::one() << 130u32);
+    assert_eq!(
+        BigInt::from_str_hex(&format!("{:#x}", big)),
+        Ok(big.clone())
+    );
+    assert_eq!(
+        BigInt::from_str_octal(&format!("{:#o}", big)),
+        Ok(big.clone())
+    );
+    assert_eq!(BigInt::from_str_binary(&format!("{:#b}", big)), Ok(big));
+
+    let err = |e: ParseBigIntError, kind: ParseBigIntErrorKind, position: usize| {
+        assert_eq!((*e.kind(), e.position()), (kind, position));
+    };
+    err(BigInt::from_str_hex("-").unwrap_err(), Empty, 1);
+    err(BigInt::from_str_hex("-0x").unwrap_err(), Empty, 3);
+    err(BigInt::from_str_hex("--1").unwrap_err(), InvalidDigit, 1);
+    err(BigInt::from_str_hex("0x-1").unwrap_err(), InvalidDigit, 2);
+    err(
+        BigInt::from_str_octal("-0o9").unwrap_err(),
+        DigitOutOfRange,
+        3,
+    );
+    err(
+        BigInt::from_str_binary("-0b12").unwrap_err(),
+        DigitOutOfRange,
+        4,
+    );
+}
+
 #[test]
 fn test_lower_hex() {
     let a = BigInt::parse_bytes(b"A", 16).unwrap();
@@ -1276,6 +1588,67 @@ fn test_display() {
     assert_eq!(format!("{:♥>+#8}", a), "♥♥♥♥♥+10");
 }
 
+#[test]
+fn test_display_with_separator() {
+    let hello = BigInt::parse_bytes(b"-22405534230753963835153736737", 10).unwrap();
+    assert_eq!(
+        hello.with_separator('_', 3).to_string(),
+        "-22_405_534_230_753_963_835_153_736_737"
+    );
+    assert_eq!(
+        format!("{:?}", (-hello).with_separator(',', 10)),
+        "224055342,3075396383,5153736737"
+    );
+    assert_eq!(BigInt::zero().with_separator(',', 3).to_string(), "0");
+    assert_eq!(
+        format!("{:>8}", BigInt::from(-1000).with_separator(',', 3)),
+        "  -1,000"
+    );
+    assert_eq!(
+        format!("{:+}", BigInt::from(1000).with_separator(',', 3)),
+        "+1,000"
+    );
+}
+
+#[test]
+fn test_fmt_flags_match_primitive() {
+    macro_rules! check {
+        ($x:expr, $($fmt:literal),*) => {$(
+            assert_eq!(format!($fmt, BigInt::from($x)), format!($fmt, $x), "{}", $fmt);
+        )*}
+    }
+
+    for &x in &[0i64, 1, -1, 10, -10, i64::MIN, i64::MAX] {
+        check!(x, "{}", "{:20}", "{:<20}", "{:^20}", "{:*>+20}", "{:020}", "{:+020}");
+    }
+
+    // Primitives format negative numbers in hex as two's complement, but we use a sign.
+    for &x in &[1i64, 10, 0xdead_beef, i64::MAX] {
+        check!(
+            x,
+            "{:x}",
+            "{:#x}",
+            "{:#020x}",
+            "{:+#X}",
+            "{:#b}",
+            "{:^+#70b}",
+            "{:#o}"
+        );
+        let neg = BigInt::from(-x);
+        assert_eq!(format!("{:x}", neg), format!("-{:x}", x));
+        assert_eq!(format!("{:#020x}", neg), format!("-{:#019x}", x));
+        assert_eq!(format!("{:#X}", neg), format!("-{:#X}", x));
+        assert_eq!(
+            format!("{:>#70b}", neg),
+            format!("{:>70}", format!("-{:#b}", x))
+        );
+        assert_eq!(
+            format!("{:<#30o}", neg),
+            format!("{:<30}", format!("-{:#o}", x))
+        );
+    }
+}
+
 #[test]
 fn test_neg() {
     assert!(-BigInt::new(Plus, vec![1, 1, 1]) == BigInt::new(Minus, vec![1, 1, 1]));
@@ -1328,6 +1701,19 @@ fn test_iter_product() {
     assert_eq!(result, data.into_iter().product::<BigInt>());
 }
 
+#[test]
+fn test_iter_sum_product_empty() {
+    let data: Vec<BigInt> = Vec::new();
+    assert!(data.iter().sum::<BigInt>().is_zero());
+    assert!(data.iter().product::<BigInt>().is_one());
+    assert!(data.into_iter().sum::<BigInt>().is_zero());
+    assert!(Vec::<BigInt>::new()
+        .into_iter()
+        .product::<BigInt>()
+        .is_one());
+    assert!(std::iter::empty::<u32>().product::<BigInt>().is_one());
+}
+
 #[test]
 fn test_iter_sum_generic() {
     let result: BigInt = FromPrimitive::from_isize(-1234567).unwrap();
@@ -1381,6 +1767,81 @@ fn test_pow() {
     assert!(manual_1e10000 == pow_1e10000);
 }
 
+#[test]
+fn test_count_ones_zeros() {
+    // Check against the shortest two's complement of primitives
+    fn check(x: i64) {
+        let (ones, zeros) = if x < 0 {
+            let width = 64 - x.leading_ones() + 1;
+            let low = x as u64 & (u64::MAX >> (64 - width));
+            (low.count_ones(), low.count_zeros() - (64 - width))
+        } else {
+            let width = 64 - x.leading_zeros();
+            (x.count_ones(), x.count_zeros() - (64 - width))
+        };
+        let big = BigInt::from(x);
+        assert_eq!(big.count_ones(), u64::from(ones), "{}", x);
+        assert_eq!(big.count_zeros(), u64::from(zeros), "{}", x);
+    }
+
+    for x in -1000..1000 {
+        check(x);
+    }
+    for shift in 0..63 {
+        check(1 << shift);
+        check(-(1 << shift));
+        check((1 << shift) - 1);
+        check(-(1 << shift) + 1);
+        check(0x5555_5555_5555_5555 >> shift);
+        check(-(0x5555_5555_5555_5555 >> shift));
+    }
+    check(i64::MIN);
+
+    let x = -(BigInt::one() << 200u32);
+    assert_eq!(x.count_ones(), 1);
+    assert_eq!(x.count_zeros(), 200);
+    let x: BigInt = -(BigInt::one() << 200u32) + 1;
+    assert_eq!(x.count_ones(), 2);
+    assert_eq!(x.count_zeros(), 199);
+}
+
+#[test]
+fn test_hamming_distance() {
+    let values = [
+        i64::MIN,
+        -(1 << 40) - 3,
+        -1000,
+        -8,
+        -5,
+        -1,
+        0,
+        1,
+        5,
+        8,
+        1000,
+        1 << 40,
+        i64::MAX,
+    ];
+    for &x in &values {
+        for &y in &values {
+            let expected = if (x < 0) == (y < 0) {
+                Some(u64::from((x ^ y).count_ones()))
+            } else {
+                None
+            };
+            let (a, b) = (BigInt::from(x), BigInt::from(y));
+            assert_eq!(a.hamming_distance(&b), expected, "{} {}", x, y);
+        }
+    }
+
+    let x = -(BigInt::one() << 200u32);
+    let y: BigInt = -(BigInt::one() << 200u32) + 1;
+    assert_eq!(x.hamming_distance(&y), Some(1));
+    assert_eq!(x.hamming_distance(&BigInt::from(-1)), Some(200));
+    assert_eq!(x.hamming_distance(&x), Some(0));
+    assert_eq!(x.hamming_distance(&BigInt::zero()), None);
+}
+
 #[test]
 fn test_bit() {
     // 12 = (1100)_2
@@ -1473,3 +1934,127 @@ fn test_set_bit() {
     x.set_bit(0, false);
     assert_eq!(x, BigInt::from_biguint(Minus, BigUint::one() << 200));
 }
+
+#[test]
+fn test_jacobi_symbol() {
+    // A negative `a` gives the same symbol as its least non-negative residue.
+    for n in (1u32..50).step_by(2) {
+        let n = BigUint::from(n);
+        for a in -50i32..50 {
+            let a = BigInt::from(a);
+            let r = a.mod_floor(&BigInt::from(n.clone())).into_parts().1;
+            assert_eq!(a.jacobi_symbol(&n), r.jacobi_symbol(&n), "({}/{})", a, n);
+        }
+    }
+    assert_eq!(BigInt::from(-1).jacobi_symbol(&BigUint::from(3u32)), -1);
+    assert_eq!(BigInt::from(-1).jacobi_symbol(&BigUint::from(5u32)), 1);
+}
+
+#[test]
+fn test_kronecker_symbol() {
+    let k = |a: i64, n: i64| BigInt::from(a).kronecker_symbol(&BigInt::from(n));
+
+    // The Jacobi symbol for odd positive n
+    for n in (1u32..60).step_by(2) {
+        for a in -60i64..60 {
+            let jacobi = BigInt::from(a).jacobi_symbol(&BigUint::from(n));
+            assert_eq!(k(a, n.into()), jacobi, "({}/{})", a, n);
+        }
+    }
+
+    for (a, n, expected) in [
+        (0, 0, 0),
+        (1, 0, 1),
+        (-1, 0, 1),
+        (2, 0, 0),
+        (0, 1, 1),
+        (0, -1, 1),
+        (-5, -1, -1),
+        (5, -1, 1),
+        (1, 2, 1),
+        (3, 2, -1),
+        (5, 2, -1),
+        (7, 2, 1),
+        (-1, 2, 1),
+        (-3, 2, -1),
+        (6, 2, 0),
+        (3, 4, 1),
+        (3, 8, -1),
+        (-1, -8, -1),
+    ] {
+        assert_eq!(k(a, n), expected, "({}/{})", a, n);
+    }
+
+    // Multiplicative in both arguments, for nonzero factors
+    for a in -20i64..20 {
+        for p in (-20i64..20).filter(|&p| p != 0) {
+            for q in (-20i64..20).filter(|&q| q != 0) {
+                assert_eq!(k(a, p * q), k(a, p) * k(a, q), "({}/{}*{})", a, p, q);
+                assert_eq!(k(p * q, a), k(p, a) * k(q, a), "({}*{}/{})", p, q, a);
+            }
+        }
+    }
+
+    let big = BigInt::from(3).pow(100u32);
+    let two_big = &big << 7u32;
+    assert_eq!(
+        BigInt::from(7).kronecker_symbol(&two_big),
+        k(7, 2) * BigInt::from(7).jacobi_symbol(big.magnitude())
+    );
+}
+
+#[test]
+fn test_der_integer() {
+    let cases: &[(i64, &[u8])] = &[
+        (0, &[0x02, 0x01, 0x00]),
+        (1, &[0x02, 0x01, 0x01]),
+        (-1, &[0x02, 0x01, 0xff]),
+        (127, &[0x02, 0x01, 0x7f]),
+        (128, &[0x02, 0x02, 0x00, 0x80]),
+        (-128, &[0x02, 0x01, 0x80]),
+        (-129, &[0x02, 0x02, 0xff, 0x7f]),
+        (256, &[0x02, 0x02, 0x01, 0x00]),
+        (-256, &[0x02, 0x02, 0xff, 0x00]),
+        (i64::MIN, &[0x02, 0x08, 0x80, 0, 0, 0, 0, 0, 0, 0]),
+    ];
+    for &(x, der) in cases {
+        let x = BigInt::from(x);
+        assert_eq!(x.to_der_integer(), der, "{}", x);
+        assert_eq!(BigInt::from_der_integer(der), Ok(x));
+    }
+
+    for bits in [7u32, 8, 63, 64, 127, 128, 1015, 1016, 5000] {
+        let power = BigInt::one() << bits;
+        for x in [&power - 1, power.clone(), &power + 1] {
+            for x in [-&x, x] {
+                let der = x.to_der_integer();
+                assert_eq!(BigInt::from_der_integer(&der), Ok(x.clone()));
+                if let Some(u) = x.to_biguint() {
+                    assert_eq!(u.to_der_integer(), der);
+                }
+            }
+        }
+    }
+
+    // Redundant sign bytes
+    assert!(BigInt::from_der_integer(&[0x02, 0x02, 0x00, 0x7f]).is_err());
+    assert!(BigInt::from_der_integer(&[0x02, 0x02, 0xff, 0x80]).is_err());
+}
+
+#[test]
+fn test_huge_shift() {
+    // Shifts beyond `u32::MAX` bits, which don't fit in `usize` on 32-bit targets
+    let n = BigInt::parse_bytes(b"123456789abcdef0123456789abcdef", 16).unwrap();
+    for shift in [1u64 << 32, u64::MAX] {
+        assert!((&n >> shift).is_zero());
+        assert_eq!(-&n >> shift, -BigInt::one());
+    }
+    assert!((&n >> u128::MAX).is_zero());
+    assert_eq!(-&n >> u128::MAX, -BigInt::one());
+
+    let mut m = -n;
+    m >>= u128::MAX;
+    assert_eq!(m, -BigInt::one());
+
+    assert!((BigInt::zero() << u128::MAX).is_zero());
+}
@@ -1,9 +1,12 @@
 use num_bigint::Sign::Plus;
 use num_bigint::{BigInt, ToBigInt};
-use num_bigint::{BigUint, ToBigUint};
+use num_bigint::{
+    BigUint, BigUintBuilder, ConvergentIter, ParseBigIntError, ParseBigIntErrorKind, ProductTree,
+    ToBigUint, TryFromBigIntError,
+};
 use num_integer::Integer;
 
-use std::cmp::Ordering::{Equal, Greater, Less};
+use std::cmp::Ordering::{self, Equal, Greater, Less};
 use std::collections::hash_map::RandomState;
 use std::hash::{BuildHasher, Hash, Hasher};
 use std::iter::repeat;
@@ -11,8 +14,8 @@ use std::str::FromStr;
 use std::{f32, f64};
 
 use num_traits::{
-    pow, CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Euclid, FromBytes, FromPrimitive, Num,
-    One, Pow, ToBytes, ToPrimitive, Zero,
+    pow, CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Euclid, FromBytes, FromPrimitive, MulAdd,
+    Num, One, Pow, ToBytes, ToPrimitive, Zero,
 };
 
 mod consts;
@@ -87,6 +90,24 @@ fn test_to_bytes_le() {
     assert_eq!(b.to_bytes_le(), [0, 2, 0, 0, 0, 0, 0, 0, 1]);
 }
 
+#[test]
+fn test_from_u32_u64_digits() {
+    assert!(BigUint::from_u32_digits(&[]).is_zero());
+    assert!(BigUint::from_u64_digits(&[0, 0]).is_zero());
+
+    let digits32: &[u32] = &[1, 2, 3, 4, 5];
+    let digits64: &[u64] = &[(2 << 32) | 1, (4 << 32) | 3, 5];
+    let x = BigUint::from_u32_digits(digits32);
+    assert_eq!(x, BigUint::from_u64_digits(digits64));
+    assert_eq!(x.to_u32_digits(), digits32);
+    assert_eq!(x.to_u64_digits(), digits64);
+    assert_eq!(BigUint::from_u32_digits(&[1, 2, 3, 4, 5, 0, 0]), x);
+    assert_eq!(
+        BigUint::from_u64_digits(&[(2 << 32) | 1, (4 << 32) | 3, 5, 0]),
+        x
+    );
+}
+
 #[test]
 fn test_cmp() {
     let data: [&[_]; 7] = [&[], &[1], &[2], &[!0], &[0, 1], &[2, 1], &[1, 1, 1]];
@@ -130,6 +151,82 @@ fn hash<T: Hash>(x: &T) -> u64 {
     hasher.finish()
 }
 
+#[test]
+fn test_default() {
+    #[derive(Default)]
+    struct Account {
+        balance: BigUint,
+        history: [BigUint; 2],
+    }
+
+    assert!(BigUint::default().is_zero());
+    let account = Account::default();
+    assert!(account.balance.is_zero());
+    assert!(account.history.iter().all(BigUint::is_zero));
+    let parsed = BigUint::parse_bytes(b"?", 10);
+    assert!(parsed.unwrap_or_default().is_zero());
+}
+
+#[test]
+fn test_cmp_primitive() {
+    let values = [
+        0u128,
+        1,
+        255,
+        256,
+        u32::MAX as u128,
+        1 << 32,
+        u64::MAX as u128,
+        u128::MAX,
+    ];
+    for &a in &values {
+        let big = BigUint::from(a);
+        for &b in &values {
+            assert_eq!(big.cmp_u128(b), a.cmp(&b));
+        }
+    }
+
+    let big = BigUint::one() << 128u32;
+    assert_eq!(big.cmp_u128(u128::MAX), Greater);
+    assert_eq!(BigUint::zero().cmp_u128(1), Less);
+}
+
+#[test]
+fn test_clamp() {
+    let min = BigUint::from(10u32);
+    let max = BigUint::one() << 100u32;
+    for (x, expected) in [
+        (BigUint::zero(), &min),
+        (BigUint::from(9u32), &min),
+        (min.clone(), &min),
+        (max.clone(), &max),
+        (&max + 1u32, &max),
+        (BigUint::one() << 200u32, &max),
+    ] {
+        assert_eq!(&x.clamp_ref(&min, &max), expected);
+        assert_eq!(&Ord::clamp(x, min.clone(), max.clone()), expected);
+    }
+    let x = BigUint::from(u64::MAX);
+    assert_eq!(x.clamp_ref(&min, &max), x);
+    assert_eq!(x.clamp_ref(&x, &x), x);
+
+    // `Ord::clamp` on references still returns a reference.
+    let (r, lo, hi): (&BigUint, &BigUint, &BigUint) = (&x, &min, &max);
+    assert_eq!(r.clamp(lo, hi), r);
+
+    assert_eq!(BigUint::zero().clamp_to_u64(), 0);
+    assert_eq!(BigUint::from(12345u32).clamp_to_u64(), 12345);
+    assert_eq!(BigUint::from(u64::MAX).clamp_to_u64(), u64::MAX);
+    assert_eq!((BigUint::one() << 64u32).clamp_to_u64(), u64::MAX);
+    assert_eq!((BigUint::one() << 1000u32).clamp_to_u64(), u64::MAX);
+}
+
+#[test]
+#[should_panic]
+fn test_clamp_inverted() {
+    let _ = BigUint::one().clamp_ref(&BigUint::from(2u32), &BigUint::one());
+}
+
 #[test]
 fn test_hash() {
     use crate::hash;
@@ -145,6 +242,18 @@ fn test_hash() {
     assert!(hash(&d) != hash(&e));
 }
 
+#[test]
+fn test_hash_primitive() {
+    use crate::hash;
+
+    for x in [0u64, 1, 42, u64::from(u32::MAX), 1 << 32, u64::MAX] {
+        assert_eq!(hash(&BigUint::from(x)), hash(&x));
+    }
+    for x in [1u128 << 64, u128::from(u64::MAX) + 2, u128::MAX] {
+        assert_eq!(hash(&BigUint::from(x)), hash(&x));
+    }
+}
+
 // LEFT, RIGHT, AND, OR, XOR
 const BIT_TESTS: &[(&[u32], &[u32], &[u32], &[u32], &[u32])] = &[
     (&[], &[], &[], &[], &[]),
@@ -527,6 +636,43 @@ fn test_convert_i64() {
     assert_eq!(BigUint::new(vec![N1, N1, N1]).to_i64(), None);
 }
 
+#[test]
+fn test_mul_div_pow2() {
+    let n = BigUint::from_str_radix("123456789abcdef0123456789abcdef", 16).unwrap();
+    for bits in [0, 1, 31, 32, 33, 63, 64, 65, 200] {
+        assert_eq!(n.mul_pow2(bits), &n << bits);
+        assert_eq!(n.div_pow2(bits), &n >> bits);
+        assert_eq!(n.mul_pow2(bits).div_pow2(bits), n);
+    }
+    assert_eq!(n.div_pow2(n.bits() - 1), BigUint::one());
+    assert!(n.div_pow2(n.bits()).is_zero());
+    assert!(n.div_pow2(u64::MAX).is_zero());
+    assert!(BigUint::zero().mul_pow2(u64::MAX).is_zero());
+}
+
+#[test]
+fn test_huge_shift() {
+    // Shifts beyond `u32::MAX` bits, which don't fit in `usize` on 32-bit targets
+    let n = BigUint::from_str_radix("123456789abcdef0123456789abcdef", 16).unwrap();
+    assert!((&n >> (1u64 << 32)).is_zero());
+    assert!((&n >> u64::MAX).is_zero());
+    assert!((&n >> u128::MAX).is_zero());
+    assert!((&n >> i128::MAX).is_zero());
+
+    let mut m = n.clone();
+    m >>= u128::MAX;
+    assert!(m.is_zero());
+
+    assert!((BigUint::zero() << u64::MAX).is_zero());
+    assert!((BigUint::zero() << u128::MAX).is_zero());
+}
+
+#[test]
+#[should_panic(expected = "capacity overflow")]
+fn test_huge_shl() {
+    let _ = BigUint::one() << u128::MAX;
+}
+
 #[test]
 fn test_convert_i128() {
     fn check(b1: BigUint, i: i128) {
@@ -598,6 +744,40 @@ fn test_convert_u128() {
     assert_eq!(BigUint::new(vec![N1, N1, N1, N1, N1]).to_u128(), None);
 }
 
+#[test]
+fn test_try_from_primitive() {
+    use std::convert::TryFrom;
+
+    macro_rules! check {
+        ($($T:ty),*) => {$(
+            let max = BigUint::from(<$T>::MAX as u128);
+            assert_eq!(<$T>::try_from(&max), Ok(<$T>::MAX));
+            assert_eq!(<$T>::try_from(BigUint::zero()), Ok(0));
+
+            let over = &max + 1u32;
+            assert!(<$T>::try_from(&over).is_err());
+            assert_eq!(<$T>::try_from(over.clone()).unwrap_err().into_original(), over);
+        )*};
+    }
+    check!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+    fn sum(values: Vec<BigUint>) -> Result<u32, TryFromBigIntError<BigUint>> {
+        let mut total = 0;
+        for value in values {
+            total += u32::try_from(value)?;
+        }
+        Ok(total)
+    }
+    assert_eq!(sum(vec![BigUint::from(1u32), BigUint::from(2u32)]), Ok(3));
+    let big = BigUint::from(1u64 << 32);
+    assert_eq!(
+        sum(vec![BigUint::one(), big.clone()])
+            .unwrap_err()
+            .into_original(),
+        big
+    );
+}
+
 #[test]
 #[allow(clippy::float_cmp)]
 fn test_convert_f32() {
@@ -769,6 +949,79 @@ fn test_convert_f64() {
     assert_eq!((BigUint::one() << 1024u16).to_f64(), Some(f64::INFINITY));
 }
 
+#[test]
+fn test_convert_f64_rounding() {
+    let cases: &[(f64, [Option<u32>; 4])] = &[
+        // value: [trunc, floor, ceil, round]
+        (0.0, [Some(0), Some(0), Some(0), Some(0)]),
+        (-0.0, [Some(0), Some(0), Some(0), Some(0)]),
+        (0.4, [Some(0), Some(0), Some(1), Some(0)]),
+        (0.5, [Some(0), Some(0), Some(1), Some(0)]),
+        (0.49999999999999994, [Some(0), Some(0), Some(1), Some(0)]),
+        (1.5, [Some(1), Some(1), Some(2), Some(2)]),
+        (2.5, [Some(2), Some(2), Some(3), Some(2)]),
+        (2.500001, [Some(2), Some(2), Some(3), Some(3)]),
+        (7.0, [Some(7), Some(7), Some(7), Some(7)]),
+        (-0.5, [Some(0), None, Some(0), Some(0)]),
+        (-0.7, [Some(0), None, Some(0), None]),
+        (-1.0, [None, None, None, None]),
+        (-1.5, [None, None, None, None]),
+        (f64::NAN, [None, None, None, None]),
+        (f64::INFINITY, [None, None, None, None]),
+        (f64::NEG_INFINITY, [None, None, None, None]),
+    ];
+    for &(f, expected) in cases {
+        let actual = [
+            BigUint::from_f64_trunc(f),
+            BigUint::from_f64_floor(f),
+            BigUint::from_f64_ceil(f),
+            BigUint::from_f64_round(f),
+        ];
+        assert_eq!(actual, expected.map(|x| x.map(BigUint::from)), "{}", f);
+    }
+
+    // Large values have no fractional part.
+    let big = 2f64.powi(100) * 3.0;
+    let expected = BigUint::from(3u32) << 100u32;
+    assert_eq!(BigUint::from_f64_round(big), Some(expected.clone()));
+    assert_eq!(BigUint::from_f64_ceil(big), Some(expected));
+    assert_eq!(
+        BigUint::from_f64_round(2f64.powi(52) + 1.0),
+        Some(BigUint::from((1u64 << 52) + 1))
+    );
+}
+
+#[test]
+fn test_to_f64_with_rounding() {
+    let check = |n: BigUint, ordering: Ordering| {
+        let (f, actual) = n.to_f64_with_rounding();
+        assert_eq!(Some(f), n.to_f64());
+        assert_eq!(actual, ordering, "{}", n);
+        assert_eq!(n.to_f64_exact(), Some(f).filter(|_| ordering == Equal));
+    };
+
+    check(BigUint::zero(), Equal);
+    check(BigUint::from(12345u32), Equal);
+    check(BigUint::from((1u64 << 53) - 1), Equal);
+    check(BigUint::from((1u64 << 54) + 1), Less);
+    check(BigUint::from((1u64 << 54) + 2), Less);
+    check(BigUint::from((1u64 << 54) + 3), Greater);
+    check(BigUint::from((1u64 << 54) + 4), Equal);
+    check(BigUint::from((1u64 << 54) + 6), Greater);
+
+    // Only a low bit far below the mantissa breaks the tie.
+    let n = (BigUint::one() << 200u32) + (BigUint::one() << 147u32);
+    check(n.clone(), Less);
+    check(n + 1u32, Greater);
+
+    // The largest finite `f64`, and values that round up to infinity
+    let max = BigUint::from_f64(f64::MAX).unwrap();
+    check(max.clone(), Equal);
+    check(&max + 1u32, Less);
+    check(BigUint::one() << 1024u32, Greater);
+    assert_eq!((BigUint::one() << 1024u32).to_f64_exact(), None);
+}
+
 #[test]
 fn test_convert_to_bigint() {
     fn check(n: BigUint, ans: BigInt) {
@@ -831,6 +1084,11 @@ fn test_sub() {
         assert_op!(c - b == a);
         assert_assign_op!(c -= a == b);
         assert_assign_op!(c -= b == a);
+
+        assert_eq!(c.abs_diff(&a), b);
+        assert_eq!(a.abs_diff(&c), b);
+        assert_eq!(b.abs_diff(&c), a);
+        assert_eq!(c.abs_diff(&c), BigUint::zero());
     }
 }
 
@@ -867,6 +1125,40 @@ fn test_mul() {
     }
 }
 
+#[test]
+fn test_mul_add() {
+    for elm in DIV_REM_QUADRUPLES.iter() {
+        let (a_vec, b_vec, c_vec, d_vec) = *elm;
+        let a = BigUint::from_slice(a_vec);
+        let b = BigUint::from_slice(b_vec);
+        let c = BigUint::from_slice(c_vec);
+        let d = BigUint::from_slice(d_vec);
+
+        assert_eq!(b.mul_add(&c, &d), a);
+        assert_eq!(MulAdd::mul_add(&c, &b, &d), a);
+        let mut acc = d.clone();
+        acc.mul_add_assign(&b, &c);
+        assert_eq!(acc, a);
+    }
+
+    // Operands long enough for Karatsuba and Toom-3, with accumulators shorter and longer than
+    // the product, and carries that ripple through all of them.
+    let max = |digits: u32| (BigUint::one() << (32 * digits)) - 1u32;
+    for (x, y) in [(1, 1), (3, 70), (40, 50), (200, 300), (500, 500)] {
+        let (x, y) = (max(x), max(y));
+        for acc in [0, 1, 2, x.bits() as u32 / 32 + y.bits() as u32 / 32, 2000] {
+            let acc = max(acc);
+            let expected = &x * &y + &acc;
+            assert_eq!(x.mul_add(&y, &acc), expected);
+            let mut sum = acc.clone();
+            sum.mul_add_assign(&x, &y);
+            assert_eq!(sum, expected);
+            sum.mul_add_assign(&BigUint::zero(), &y);
+            assert_eq!(sum, expected);
+        }
+    }
+}
+
 #[test]
 fn test_div_rem() {
     for elm in MUL_TRIPLES.iter() {
@@ -881,6 +1173,7 @@ fn test_div_rem() {
             assert_assign_op!(c /= a == b);
             assert_assign_op!(c %= a == BigUint::zero());
             assert_eq!(c.div_rem(&a), (b.clone(), BigUint::zero()));
+            assert_eq!(c.divmod(&a), (b.clone(), BigUint::zero()));
         }
         if !b.is_zero() {
             assert_op!(c / b == a);
@@ -888,6 +1181,7 @@ fn test_div_rem() {
             assert_assign_op!(c /= b == a);
             assert_assign_op!(c %= b == BigUint::zero());
             assert_eq!(c.div_rem(&b), (a.clone(), BigUint::zero()));
+            assert_eq!(c.divmod(&b), (a.clone(), BigUint::zero()));
         }
     }
 
@@ -903,11 +1197,18 @@ fn test_div_rem() {
             assert_op!(a % b == d);
             assert_assign_op!(a /= b == c);
             assert_assign_op!(a %= b == d);
+            assert_eq!(a.divmod(&b), (c.clone(), d.clone()));
             assert!(a.div_rem(&b) == (c, d));
         }
     }
 }
 
+#[test]
+#[should_panic(expected = "attempt to divide by zero")]
+fn test_divmod_zero() {
+    let _ = BigUint::from(1u32).divmod(&BigUint::zero());
+}
+
 #[test]
 fn test_div_rem_big_multiple() {
     let a = BigUint::from(3u32).pow(100u32);
@@ -1073,6 +1374,279 @@ fn test_mul_overflow_2() {
     }
 }
 
+#[test]
+fn test_checked_bounded() {
+    for elm in SUM_TRIPLES.iter() {
+        let (a_vec, b_vec, c_vec) = *elm;
+        let a = BigUint::from_slice(a_vec);
+        let b = BigUint::from_slice(b_vec);
+        let c = BigUint::from_slice(c_vec);
+
+        assert_eq!(a.checked_add_bounded(&b, c.bits()), Some(c.clone()));
+        assert_eq!(b.checked_add_bounded(&a, c.bits() + 1), Some(c.clone()));
+        if !c.is_zero() {
+            assert_eq!(a.checked_add_bounded(&b, c.bits() - 1), None);
+        }
+    }
+
+    for elm in MUL_TRIPLES.iter() {
+        let (a_vec, b_vec, c_vec) = *elm;
+        let a = BigUint::from_slice(a_vec);
+        let b = BigUint::from_slice(b_vec);
+        let c = BigUint::from_slice(c_vec);
+
+        assert_eq!(a.checked_mul_bounded(&b, c.bits()), Some(c.clone()));
+        assert_eq!(b.checked_mul_bounded(&a, c.bits() + 1), Some(c.clone()));
+        if !c.is_zero() {
+            assert_eq!(a.checked_mul_bounded(&b, c.bits() - 1), None);
+        }
+    }
+
+    // Both possible lengths of a product.
+    let (two, three) = (BigUint::from(2u32), BigUint::from(3u32));
+    assert_eq!(two.checked_mul_bounded(&two, 3), Some(BigUint::from(4u32)));
+    assert_eq!(
+        three.checked_mul_bounded(&three, 4),
+        Some(BigUint::from(9u32))
+    );
+    assert_eq!(three.checked_mul_bounded(&three, 3), None);
+
+    let big: BigUint = BigUint::one() << 1000;
+    assert_eq!(big.checked_add_bounded(&BigUint::zero(), 1000), None);
+    assert_eq!(
+        big.checked_mul_bounded(&BigUint::zero(), 0),
+        Some(BigUint::zero())
+    );
+    assert_eq!(
+        BigUint::zero().checked_add_bounded(&BigUint::zero(), 0),
+        Some(BigUint::zero())
+    );
+}
+
+#[test]
+fn test_modinv_unchecked() {
+    let m = (BigUint::one() << 127u32) - 1u32;
+    for a in [
+        BigUint::one(),
+        BigUint::from(2u32),
+        &m - 1u32,
+        BigUint::from(12345u32) << 64u32,
+    ] {
+        let x = a.modinv_unchecked(&m);
+        assert_eq!(Some(x.clone()), a.modinv(&m));
+        assert!((a * x % &m).is_one());
+    }
+}
+
+#[test]
+#[should_panic(expected = "not coprime")]
+fn test_modinv_unchecked_not_coprime() {
+    BigUint::from(6u32).modinv_unchecked(&BigUint::from(9u32));
+}
+
+#[test]
+fn test_crt() {
+    let big = |n: u64| BigUint::from(n);
+
+    // Mersenne primes 2^p - 1 are pairwise coprime.
+    let moduli: Vec<BigUint> = [61u32, 89, 107, 127]
+        .iter()
+        .map(|&p| (BigUint::one() << p) - 1u32)
+        .collect();
+    let product: BigUint = moduli.iter().product();
+    let x = BigUint::from_str_radix("123456789abcdef0123456789abcdef0123456789abcdef", 16).unwrap();
+    for x in [
+        BigUint::zero(),
+        BigUint::one(),
+        x.clone(),
+        &product - 1u32,
+        x.pow(3u32) % &product,
+    ] {
+        let residues: Vec<BigUint> = moduli.iter().map(|m| &x % m).collect();
+        assert_eq!(BigUint::crt(&residues, &moduli), Some(x.clone()));
+
+        // Residues don't need to be reduced.
+        let residues: Vec<BigUint> = residues
+            .iter()
+            .zip(&moduli)
+            .map(|(r, m)| r + m * 5u32)
+            .collect();
+        assert_eq!(BigUint::crt(&residues, &moduli), Some(x));
+    }
+
+    // A modulus of one places no constraint.
+    assert_eq!(
+        BigUint::crt(&[big(0), big(4)], &[big(1), big(7)]),
+        Some(big(4))
+    );
+    assert_eq!(BigUint::crt(&[], &[]), Some(big(0)));
+
+    // Moduli with a common factor, and mismatched lengths.
+    assert_eq!(BigUint::crt(&[big(1), big(2)], &[big(6), big(10)]), None);
+    assert_eq!(
+        BigUint::garner_coefficients(&[big(6), big(35), big(15)]),
+        None
+    );
+    assert_eq!(BigUint::crt(&[big(1)], &[big(3), big(5)]), None);
+}
+
+#[test]
+fn test_batch_reduce() {
+    let n = BigUint::factorial(300) + 12345u32;
+    let mut moduli: Vec<BigUint> = (1u32..=37).map(|i| BigUint::from(i * i + 1)).collect();
+    moduli.push(BigUint::one());
+    moduli.push((BigUint::one() << 127u32) - 1u32);
+    moduli.push(BigUint::factorial(100));
+
+    // Every count of moduli, to cover trees with odd nodes carried up at each level.
+    for k in 0..=moduli.len() {
+        let moduli = &moduli[..k];
+        let expected: Vec<BigUint> = moduli.iter().map(|m| &n % m).collect();
+        assert_eq!(BigUint::batch_reduce(&n, moduli), expected);
+
+        let tree = ProductTree::new(moduli);
+        assert_eq!(tree.moduli(), moduli);
+        assert_eq!(*tree.product(), moduli.iter().product::<BigUint>());
+        assert_eq!(tree.reduce(&n), expected);
+        let small = BigUint::from(1000u32);
+        let expected: Vec<BigUint> = moduli.iter().map(|m| &small % m).collect();
+        assert_eq!(tree.reduce(&small), expected);
+    }
+
+    // Repeated moduli are fine too.
+    let moduli = [7u32, 7, 7].map(BigUint::from);
+    assert_eq!(
+        BigUint::batch_reduce(&BigUint::from(100u32), &moduli),
+        [2u32, 2, 2].map(BigUint::from)
+    );
+}
+
+#[test]
+#[should_panic(expected = "zero modulus")]
+fn test_batch_reduce_zero_modulus() {
+    BigUint::batch_reduce(&BigUint::one(), &[BigUint::one(), BigUint::zero()]);
+}
+
+#[test]
+fn test_continued_fraction() {
+    fn check(p: u64, q: u64, expected: &[u64]) {
+        let (p, q) = (BigUint::from(p), BigUint::from(q));
+        let expected: Vec<BigUint> = expected.iter().map(|&a| BigUint::from(a)).collect();
+        assert_eq!(BigUint::continued_fraction(&p, &q), expected);
+
+        let g = p.gcd(&q);
+        assert_eq!(
+            BigUint::from_continued_fraction(&expected),
+            (p / &g, q / &g)
+        );
+    }
+
+    check(0, 1, &[0]);
+    check(0, 7, &[0]);
+    check(5, 1, &[5]);
+    check(1, 2, &[0, 2]);
+    check(6, 4, &[1, 2]);
+    check(415, 93, &[4, 2, 6, 7]);
+    check(93, 415, &[0, 4, 2, 6, 7]);
+    check(355, 113, &[3, 7, 16]);
+    check(u64::MAX, 2, &[u64::MAX / 2, 2]);
+
+    // Consecutive Fibonacci numbers expand to all ones, canonically ending in two.
+    let (mut a, mut b) = (BigUint::one(), BigUint::one());
+    for _ in 0..200 {
+        let c = &a + &b;
+        a = b;
+        b = c;
+    }
+    let coefficients = BigUint::continued_fraction(&b, &a);
+    assert_eq!(coefficients.len(), 200);
+    assert!(coefficients[..199].iter().all(BigUint::is_one));
+    assert_eq!(coefficients[199], BigUint::from(2u32));
+    assert_eq!(BigUint::from_continued_fraction(&coefficients), (b, a));
+
+    let big = BigUint::from(3u32).pow(300u32);
+    let coefficients = BigUint::continued_fraction(&big, &(&big - 1u32));
+    assert_eq!(coefficients.len(), 2);
+    assert_eq!(
+        BigUint::from_continued_fraction(&coefficients),
+        (big.clone(), big - 1u32)
+    );
+
+    // Non-canonical expansions still evaluate to their value.
+    let ones = [1u32, 1].map(BigUint::from);
+    assert_eq!(
+        BigUint::from_continued_fraction(&ones),
+        (BigUint::from(2u32), BigUint::one())
+    );
+    assert_eq!(
+        BigUint::from_continued_fraction(&[]),
+        (BigUint::one(), BigUint::zero())
+    );
+}
+
+#[test]
+fn test_convergent_iter() {
+    // e = [2; 1, 2, 1, 1, 4, 1, 1, 6, ...]
+    let e = (0u32..).map(|i| match i {
+        0 => BigUint::from(2u32),
+        _ if i % 3 == 2 => BigUint::from(2 * (i + 1) / 3),
+        _ => BigUint::one(),
+    });
+    let mut convergents = ConvergentIter::new(e);
+    assert_eq!(convergents.size_hint(), (usize::MAX, None));
+    let expected = [
+        (2u32, 1u32),
+        (3, 1),
+        (8, 3),
+        (11, 4),
+        (19, 7),
+        (87, 32),
+        (106, 39),
+    ];
+    for &(p, q) in &expected {
+        assert_eq!(
+            convergents.next(),
+            Some((BigUint::from(p), BigUint::from(q)))
+        );
+    }
+
+    // Consecutive convergents differ by exactly `1 / (q(k) q(k-1))`.
+    let (mut p_prev, mut q_prev) = convergents.next().unwrap();
+    for (p, q) in convergents.take(100) {
+        let (a, b) = (&p * &q_prev, &p_prev * &q);
+        assert!(a.abs_diff(&b).is_one());
+        p_prev = p;
+        q_prev = q;
+    }
+
+    let coefficients = BigUint::continued_fraction(&BigUint::from(415u32), &BigUint::from(93u32));
+    let mut convergents = ConvergentIter::new(&coefficients);
+    assert_eq!(convergents.len(), 4);
+    convergents.next();
+    assert_eq!(convergents.len(), 3);
+    let all: Vec<_> = ConvergentIter::new(coefficients.iter()).collect();
+    assert_eq!(all.len(), 4);
+    assert_eq!(
+        ConvergentIter::new(coefficients.clone()).last(),
+        Some((BigUint::from(415u32), BigUint::from(93u32)))
+    );
+    assert_eq!(
+        all.last(),
+        ConvergentIter::new(&coefficients).last().as_ref()
+    );
+
+    let mut empty = ConvergentIter::new(&[] as &[BigUint]);
+    assert_eq!(empty.next(), None);
+    assert_eq!(empty.next(), None);
+    assert_eq!(ConvergentIter::new(Vec::<BigUint>::new()).last(), None);
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide by zero")]
+fn test_continued_fraction_zero_denominator() {
+    BigUint::continued_fraction(&BigUint::one(), &BigUint::zero());
+}
+
 #[test]
 fn test_checked_div() {
     for elm in MUL_TRIPLES.iter() {
@@ -1110,6 +1684,38 @@ fn test_gcd() {
     check(56, 42, 14);
 }
 
+#[test]
+fn test_extended_gcd() {
+    fn check(a: u64, b: u64, g: u64, x: i64, y: i64) {
+        let big_a = BigUint::from(a);
+        let big_b = BigUint::from(b);
+
+        let (big_g, big_x, big_y) = big_a.extended_gcd(&big_b);
+        assert_eq!(big_g, BigUint::from(g));
+        assert_eq!(big_x, BigInt::from(x));
+        assert_eq!(big_y, BigInt::from(y));
+        assert_eq!(
+            BigInt::from(big_a) * big_x + BigInt::from(big_b) * big_y,
+            BigInt::from(big_g)
+        );
+    }
+
+    check(0, 0, 0, 1, 0);
+    check(0, 5, 5, 0, 1);
+    check(5, 0, 5, 1, 0);
+    check(1, 1, 1, 0, 1);
+    check(240, 46, 2, -9, 47);
+    check(46, 240, 2, 47, -9);
+    check(99, 78, 3, -11, 14);
+    check(17, 3120, 1, -367, 2);
+
+    let a = BigUint::from(2u32).pow(521u32) - 1u32;
+    let b = BigUint::from(3u32).pow(200u32);
+    let (g, x, y) = a.extended_gcd(&b);
+    assert_eq!(g, a.gcd(&b));
+    assert_eq!(BigInt::from(a) * x + BigInt::from(b) * y, BigInt::from(g));
+}
+
 #[test]
 fn test_lcm() {
     fn check(a: usize, b: usize, c: usize) {
@@ -1130,6 +1736,32 @@ fn test_lcm() {
     check(99, 17, 1683);
 }
 
+#[test]
+fn test_gcd_lcm_all() {
+    let values: Vec<BigUint> = (1u32..=20).map(BigUint::from).collect();
+    assert_eq!(BigUint::gcd_all(&values), BigUint::one());
+    assert_eq!(BigUint::lcm_all(&values), BigUint::from(232_792_560u32));
+
+    // The same as folding pairwise, for any number of values
+    let p = BigUint::from(2u32).pow(127u32) - 1u32;
+    let values: Vec<BigUint> = (1u32..12)
+        .map(|i| &p * (BigUint::from(i) << 70u32))
+        .collect();
+    for n in 0..=values.len() {
+        let values = &values[..n];
+        let gcd = values.iter().fold(BigUint::zero(), |g, x| g.gcd(x));
+        let lcm = values.iter().fold(BigUint::one(), |l, x| l.lcm(x));
+        assert_eq!(BigUint::gcd_all(values), gcd);
+        assert_eq!(BigUint::lcm_all(values), lcm);
+    }
+    assert_eq!(BigUint::gcd_all(&values), &p << 70u32);
+
+    let with_zero = [BigUint::from(6u32), BigUint::zero(), BigUint::from(4u32)];
+    assert_eq!(BigUint::gcd_all(&with_zero), BigUint::from(2u32));
+    assert!(BigUint::lcm_all(&with_zero).is_zero());
+    assert!(BigUint::gcd_all(&[BigUint::zero()]).is_zero());
+}
+
 #[test]
 fn test_is_multiple_of() {
     assert!(BigUint::from(0u32).is_multiple_of(&BigUint::from(0u32)));
@@ -1306,75 +1938,514 @@ fn test_to_str_radix() {
 }
 
 #[test]
-fn test_from_and_to_radix() {
-    const GROUND_TRUTH: &[(&[u8], u32, &[u8])] = &[
-        (b"0", 42, &[0]),
-        (
-            b"ffffeeffbb",
-            2,
-            &[
-                1, 1, 0, 1, 1, 1, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 1, 1, 1, 0, 1, 1, 1, 1, 1, 1, 1,
-                1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
-            ],
-        ),
-        (
-            b"ffffeeffbb",
-            3,
-            &[
-                2, 2, 1, 1, 2, 1, 1, 2, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 1, 0, 0, 2, 2, 0, 1,
-            ],
-        ),
-        (
-            b"ffffeeffbb",
-            4,
-            &[3, 2, 3, 2, 3, 3, 3, 3, 2, 3, 2, 3, 3, 3, 3, 3, 3, 3, 3, 3],
-        ),
-        (
-            b"ffffeeffbb",
-            5,
-            &[0, 4, 3, 3, 1, 4, 2, 4, 1, 4, 4, 2, 3, 0, 0, 1, 2, 1],
-        ),
-        (
-            b"ffffeeffbb",
-            6,
-            &[5, 5, 4, 5, 5, 0, 0, 1, 2, 5, 3, 0, 1, 0, 2, 2],
-        ),
-        (
-            b"ffffeeffbb",
-            7,
-            &[4, 2, 3, 6, 0, 1, 6, 1, 6, 2, 0, 3, 2, 4, 1],
-        ),
-        (
-            b"ffffeeffbb",
-            8,
-            &[3, 7, 6, 7, 7, 5, 3, 7, 7, 7, 7, 7, 7, 1],
-        ),
-        (b"ffffeeffbb", 9, &[8, 4, 5, 7, 0, 0, 3, 2, 0, 3, 0, 8, 3]),
-        (b"ffffeeffbb", 10, &[5, 9, 5, 3, 1, 5, 0, 1, 5, 9, 9, 0, 1]),
-        (b"ffffeeffbb", 11, &[10, 7, 6, 5, 2, 0, 3, 3, 3, 4, 9, 3]),
-        (b"ffffeeffbb", 12, &[11, 8, 5, 10, 1, 10, 3, 1, 1, 9, 5, 1]),
-        (b"ffffeeffbb", 13, &[0, 5, 7, 4, 6, 5, 6, 11, 8, 12, 7]),
-        (b"ffffeeffbb", 14, &[11, 4, 4, 11, 8, 4, 6, 0, 3, 11, 3]),
-        (b"ffffeeffbb", 15, &[5, 11, 13, 2, 1, 10, 2, 0, 9, 13, 1]),
-        (b"ffffeeffbb", 16, &[11, 11, 15, 15, 14, 14, 15, 15, 15, 15]),
-        (b"ffffeeffbb", 17, &[0, 2, 14, 12, 2, 14, 8, 10, 4, 9]),
-        (b"ffffeeffbb", 18, &[17, 15, 5, 13, 10, 16, 16, 13, 9, 5]),
-        (b"ffffeeffbb", 19, &[14, 13, 2, 8, 9, 0, 1, 14, 7, 3]),
-        (b"ffffeeffbb", 20, &[15, 19, 3, 14, 0, 17, 19, 18, 2, 2]),
-        (b"ffffeeffbb", 21, &[11, 5, 4, 13, 5, 18, 9, 1, 8, 1]),
-        (b"ffffeeffbb", 22, &[21, 3, 7, 21, 15, 12, 17, 0, 20]),
-        (b"ffffeeffbb", 23, &[21, 21, 6, 9, 10, 7, 21, 0, 14]),
-        (b"ffffeeffbb", 24, &[11, 10, 19, 14, 22, 11, 17, 23, 9]),
-        (b"ffffeeffbb", 25, &[20, 18, 21, 22, 21, 14, 3, 5, 7]),
-        (b"ffffeeffbb", 26, &[13, 15, 24, 11, 17, 6, 23, 6, 5]),
-        (b"ffffeeffbb", 27, &[17, 16, 7, 0, 21, 0, 3, 24, 3]),
-        (b"ffffeeffbb", 28, &[11, 16, 11, 15, 14, 18, 13, 25, 2]),
-        (b"ffffeeffbb", 29, &[6, 8, 7, 19, 14, 13, 21, 5, 2]),
-        (b"ffffeeffbb", 30, &[5, 13, 18, 11, 10, 7, 8, 20, 1]),
-        (b"ffffeeffbb", 31, &[22, 26, 15, 19, 8, 27, 29, 8, 1]),
-        (b"ffffeeffbb", 32, &[27, 29, 31, 29, 30, 31, 31, 31]),
-        (b"ffffeeffbb", 33, &[32, 20, 27, 12, 1, 12, 26, 25]),
-        (b"ffffeeffbb", 34, &[17, 9, 16, 33, 13, 25, 31, 20]),
+fn test_digit_count() {
+    for i in 0u32..=10000 {
+        let n = BigUint::from(i);
+        for radix in 2..=36 {
+            assert_eq!(n.digit_count(radix), n.to_str_radix(radix).len());
+        }
+        assert_eq!(n.decimal_digits(), i.to_string().len());
+    }
+
+    assert_eq!(BigUint::factorial(100).decimal_digits(), 158);
+    assert_eq!(BigUint::factorial(1000).decimal_digits(), 2568);
+    for radix in 2..=36 {
+        for k in [10u32, 100, 1000] {
+            let n = BigUint::from(radix).pow(k);
+            assert_eq!(n.digit_count(radix), k as usize + 1);
+            assert_eq!((n - 1u32).digit_count(radix), k as usize);
+        }
+        let n = BigUint::factorial(500);
+        assert_eq!(n.digit_count(radix), n.to_str_radix(radix).len());
+    }
+}
+
+#[test]
+fn test_digital_root() {
+    fn iterated(n: &BigUint, base: u32) -> BigUint {
+        let mut n = n.clone();
+        while n >= BigUint::from(base) {
+            n = n.to_radix_le(base).into_iter().map(u32::from).sum();
+        }
+        n
+    }
+
+    let mut n = BigUint::factorial(60) + 12345u32;
+    for i in 0u32..1000 {
+        for base in [2, 3, 7, 10, 16, 36, 255, 256] {
+            let small = BigUint::from(i);
+            assert_eq!(small.digital_root(base), iterated(&small, base));
+            assert_eq!(n.digital_root(base), iterated(&n, base));
+        }
+        let r = n.mod_floor(&BigUint::from(9u32));
+        if r.is_zero() {
+            assert_eq!(n.digital_root(10), BigUint::from(9u32));
+        } else {
+            assert_eq!(n.digital_root(10), r);
+        }
+        n = n * 7u32 + i;
+    }
+
+    // Either side of the switch from summing `u64` digits to the remainder formula
+    for n in [u64::MAX - 1, u64::MAX] {
+        let n = BigUint::from(n);
+        for base in [2, 10, 256] {
+            assert_eq!(n.digital_root(base), iterated(&n, base));
+            assert_eq!((&n + 2u32).digital_root(base), iterated(&(&n + 2u32), base));
+        }
+    }
+
+    assert_eq!(BigUint::ZERO.digital_root(u32::MAX), BigUint::ZERO);
+    let n = BigUint::from(u32::MAX - 1).pow(5u32);
+    assert_eq!(n.digital_root(u32::MAX), BigUint::from(u32::MAX - 1));
+}
+
+#[test]
+#[should_panic(expected = "The base must be at least 2")]
+fn test_digital_root_base_1() {
+    BigUint::from(5u32).digital_root(1);
+}
+
+#[test]
+fn test_digit_sum_product() {
+    fn naive_digits(n: &BigUint, base: u32) -> Vec<BigUint> {
+        let mut n = n.clone();
+        let mut digits = vec![&n % base];
+        n /= base;
+        while !n.is_zero() {
+            digits.push(&n % base);
+            n /= base;
+        }
+        digits
+    }
+
+    let bases = [2, 3, 7, 10, 16, 36, 255, 256, 257, 1000, 65536, u32::MAX];
+    let mut n = BigUint::factorial(40) + 12345u32;
+    for i in 0u32..200 {
+        for &base in &bases {
+            for x in [BigUint::from(i), n.clone()] {
+                let digits = naive_digits(&x, base);
+                assert_eq!(x.digit_sum(base), digits.iter().sum::<BigUint>());
+                assert_eq!(x.digit_product(base), digits.iter().product::<BigUint>());
+            }
+        }
+        assert_eq!(n.digit_sum(10) % 9u32, &n % 9u32);
+        assert_eq!(n.digit_sum(256) % 255u32, &n % 255u32);
+        n = n * 11u32 + i;
+    }
+
+    // Large enough for the subquadratic decimal conversion.
+    let n = BigUint::from(7u32).pow(5000u32);
+    assert_eq!(n.digit_sum(10) % 9u32, &n % 9u32);
+    let digits = n.to_str_radix(10);
+    let sum: u32 = digits.bytes().map(|b| u32::from(b - b'0')).sum();
+    assert_eq!(n.digit_sum(10), BigUint::from(sum));
+
+    let nines = BigUint::from(10u32).pow(50u32) - 1u32;
+    assert_eq!(nines.digit_sum(10), BigUint::from(450u32));
+    assert_eq!(nines.digit_product(10), BigUint::from(9u32).pow(50u32));
+    assert_eq!(BigUint::ZERO.digit_sum(10), BigUint::ZERO);
+    assert_eq!(BigUint::ZERO.digit_product(10), BigUint::ZERO);
+    assert_eq!(BigUint::one().digit_product(u32::MAX), BigUint::one());
+}
+
+#[test]
+#[should_panic(expected = "The base must be at least 2")]
+fn test_digit_sum_base_1() {
+    BigUint::from(5u32).digit_sum(1);
+}
+
+#[test]
+fn test_to_str_radix_options() {
+    let r = to_str_pairs();
+    for (n, rs) in r.iter() {
+        for (radix, str) in rs.iter() {
+            assert_eq!(n.to_str_radix_options(*radix, false, None), *str);
+            assert_eq!(
+                n.to_str_radix_options(*radix, true, None),
+                str.to_ascii_uppercase()
+            );
+        }
+    }
+
+    let n = BigUint::parse_bytes(b"deadbeefcafe", 16).unwrap();
+    assert_eq!(
+        n.to_str_radix_options(16, true, Some(('_', 4))),
+        "DEAD_BEEF_CAFE"
+    );
+    assert_eq!(
+        n.to_str_radix_options(16, false, Some(('_', 5))),
+        "de_adbee_fcafe"
+    );
+    assert_eq!(
+        n.to_str_radix_options(16, false, Some(('_', 12))),
+        "deadbeefcafe"
+    );
+    assert_eq!(
+        n.to_str_radix_options(16, false, Some(('\u{2009}', 8))),
+        "dead\u{2009}beefcafe"
+    );
+    assert_eq!(
+        n.to_str_radix_options(2, false, Some((' ', 1))).len(),
+        48 + 47
+    );
+    assert_eq!(
+        BigUint::zero().to_str_radix_options(10, false, Some((',', 3))),
+        "0"
+    );
+    assert_eq!(
+        BigUint::from(100u32).to_str_radix_options(10, false, Some((',', 3))),
+        "100"
+    );
+    assert_eq!(
+        BigUint::from(1000u32).to_str_radix_options(10, false, Some((',', 3))),
+        "1,000"
+    );
+}
+
+#[test]
+fn test_to_hex_octal_binary_str() {
+    for n in [
+        BigUint::zero(),
+        BigUint::from(0xdead_beef_u32),
+        BigUint::from(u64::MAX),
+        BigUint::factorial(40),
+    ] {
+        assert_eq!(n.to_hex_str(), format!("{:x}", n));
+        assert_eq!(n.to_hex_str_upper(), format!("{:X}", n));
+        assert_eq!(n.to_octal_str(), format!("{:o}", n));
+        assert_eq!(n.to_binary_str(), format!("{:b}", n));
+        assert_eq!(n.to_hex_str_prefixed(), format!("{:#x}", n));
+        assert_eq!(n.to_octal_str_prefixed(), format!("{:#o}", n));
+        assert_eq!(n.to_binary_str_prefixed(), format!("{:#b}", n));
+
+        assert_eq!(
+            BigUint::from_str_hex(&n.to_hex_str_prefixed()),
+            Ok(n.clone())
+        );
+        assert_eq!(BigUint::from_str_hex(&n.to_hex_str_upper()), Ok(n.clone()));
+        assert_eq!(
+            BigUint::from_str_octal(&n.to_octal_str_prefixed()),
+            Ok(n.clone())
+        );
+        assert_eq!(BigUint::from_str_binary(&n.to_binary_str_prefixed()), Ok(n));
+    }
+    assert_eq!(BigUint::zero().to_hex_str_prefixed(), "0x0");
+    assert_eq!(BigUint::from(255u32).to_hex_str_upper(), "FF");
+}
+
+#[test]
+fn test_leb128() {
+    // Values around the 7-bit group and digit boundaries, with their encoded lengths.
+    let mut values = vec![(BigUint::zero(), 1)];
+    for bits in [7u32, 14, 63, 64, 70, 127, 128, 1000] {
+        let power = BigUint::one() << bits;
+        let len = (bits as usize + 6) / 7;
+        values.push((&power - 1u32, len));
+        values.push((power.clone(), bits as usize / 7 + 1));
+        values.push((&power + 1u32, bits as usize / 7 + 1));
+    }
+    values.push((
+        BigUint::from_str_radix("123456789abcdef0123456789abcdef", 16).unwrap(),
+        18,
+    ));
+
+    for (x, len) in &values {
+        let bytes = x.to_leb128();
+        assert_eq!(bytes.len(), *len, "{}", x);
+        assert!(bytes[..len - 1].iter().all(|b| b & 0x80 != 0));
+        assert!(bytes[len - 1] & 0x80 == 0);
+        assert_eq!(BigUint::from_leb128(&bytes), Ok((x.clone(), *len)));
+
+        // Trailing bytes are left alone, and every truncation fails.
+        let mut longer = bytes.clone();
+        longer.extend_from_slice(&[0x85, 0x01]);
+        assert_eq!(BigUint::from_leb128(&longer), Ok((x.clone(), *len)));
+        for i in 0..*len {
+            let err = BigUint::from_leb128(&bytes[..i]).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                format!(
+                    "LEB128 integer is truncated: no final byte in {} bytes of input",
+                    i
+                )
+            );
+        }
+    }
+
+    // Padding with redundant zero groups
+    assert_eq!(
+        BigUint::from_leb128(&[0x81, 0x80, 0x80, 0x00]),
+        Ok((BigUint::one(), 4))
+    );
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_leb128_io() {
+    use std::io::{self, Read};
+
+    let values: Vec<BigUint> = (0..300u32).map(|i| BigUint::from(3u32).pow(i)).collect();
+    let mut buf = Vec::new();
+    let mut total = 0;
+    for x in &values {
+        total += x.write_leb128(&mut buf).unwrap();
+    }
+    assert_eq!(total, buf.len());
+
+    let mut r = &buf[..];
+    for x in &values {
+        assert_eq!(&BigUint::read_leb128(&mut r).unwrap(), x);
+    }
+    assert!(r.is_empty());
+
+    // The reader stops at the end of each value.
+    let mut r = io::Cursor::new([0x96, 0x01, 0x2a]);
+    assert_eq!(BigUint::read_leb128(&mut r).unwrap(), BigUint::from(150u32));
+    let mut rest = Vec::new();
+    r.read_to_end(&mut rest).unwrap();
+    assert_eq!(rest, [0x2a]);
+
+    let mut r: &[u8] = &[0x96, 0x81];
+    let err = BigUint::read_leb128(&mut r).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+    assert_eq!(
+        err.to_string(),
+        "LEB128 integer is truncated: no final byte in 2 bytes of input"
+    );
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_le_bytes_io() {
+    use std::io;
+
+    let values: Vec<BigUint> = (0..300u32).map(|i| BigUint::from(3u32).pow(i)).collect();
+    let mut buf = Vec::new();
+    let mut lens = Vec::new();
+    for x in &values {
+        lens.push(x.write_to_le_bytes(&mut buf).unwrap());
+    }
+    assert_eq!(lens.iter().sum::<usize>(), buf.len());
+
+    let mut r = &buf[..];
+    for (x, &len) in values.iter().zip(&lens) {
+        assert_eq!(&BigUint::read_from_le_bytes(&mut r, len).unwrap(), x);
+    }
+    assert!(r.is_empty());
+
+    // Zero-padded to a fixed width
+    let mut r: &[u8] = &u128::to_le_bytes(0x1234_5678_9abc);
+    assert_eq!(
+        BigUint::read_from_le_bytes(&mut r, 16).unwrap(),
+        BigUint::from(0x1234_5678_9abcu64)
+    );
+    assert_eq!(
+        BigUint::read_from_le_bytes(&mut &[][..], 0).unwrap(),
+        BigUint::zero()
+    );
+
+    let mut r: &[u8] = &[1, 2];
+    let err = BigUint::read_from_le_bytes(&mut r, 3).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+
+    // A length far beyond the data fails without allocating it up front.
+    let mut r: &[u8] = &[1, 2];
+    let err = BigUint::read_from_le_bytes(&mut r, usize::MAX).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+}
+
+fn from_hex(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+#[test]
+fn test_der_integer() {
+    let cases: &[(BigUint, &str)] = &[
+        (BigUint::zero(), "020100"),
+        (BigUint::one(), "020101"),
+        (BigUint::from(127u32), "02017f"),
+        (BigUint::from(128u32), "02020080"),
+        (BigUint::from(256u32), "02020100"),
+        (BigUint::from(65537u32), "0203010001"),
+        (
+            (BigUint::one() << 128u32) - 1u32,
+            "021100ffffffffffffffffffffffffffffffff",
+        ),
+    ];
+    for (x, hex) in cases {
+        let der = from_hex(hex);
+        assert_eq!(x.to_der_integer(), der, "{}", x);
+        assert_eq!(BigUint::from_der_integer(&der), Ok(x.clone()));
+    }
+
+    // The longest contents in the short form, and the shortest in the long form
+    let x = (BigUint::one() << 1000u32) - 1u32;
+    let mut der = vec![0x02, 0x7e, 0x00];
+    der.extend_from_slice(&[0xff; 125]);
+    assert_eq!(x.to_der_integer(), der);
+    assert_eq!(BigUint::from_der_integer(&der), Ok(x));
+
+    let x = BigUint::one() << 1016u32;
+    let mut der = vec![0x02, 0x81, 0x80, 0x01];
+    der.extend_from_slice(&[0x00; 127]);
+    assert_eq!(x.to_der_integer(), der);
+    assert_eq!(BigUint::from_der_integer(&der), Ok(x));
+
+    // The RSA public key of Amazon Root CA 1, as a SEQUENCE of the modulus and the exponent
+    let rsa_key = from_hex(
+        "3082010a0282010100b2788071ca78d5e371af478050747d6ed8d78876f49968f7582160f97484012fac\
+         022d86d3a0437a4eb2a4d036ba01be8ddb48c80717364cf4ee8823c73eeb37f5b519f84968b0ded7b976\
+         381d619ea4fe8236a5e54a56e445e1f9fdb416fa74da9c9b35392ffab02050066c7ad080b2a6f9afec47\
+         198f503807dca2873958f8bad5a9f948673096ee94785e6f89a351c0308666a14566ba54eba3c391f948\
+         dcffd1e8302d7d2d747035d78824f79ec4596ebb738717f2324628b843fab71daacab4f29f240e2d4bf7\
+         715c5e69ffea9502cb388aae50386fdbfb2d621bc5c71e54e177e067c80f9c8723d63f40207f2080c480\
+         4c3e3b24268e04ae6c9ac8aa0d0203010001",
+    );
+    let (modulus_der, exponent_der) = rsa_key[4..].split_at(4 + 257);
+    let modulus = BigUint::from_der_integer(modulus_der).unwrap();
+    assert_eq!(modulus.bits(), 2048);
+    assert_eq!(modulus.to_der_integer(), modulus_der);
+    assert_eq!(&modulus.to_bytes_be()[..], &modulus_der[5..]);
+    assert_eq!(
+        BigUint::from_der_integer(exponent_der),
+        Ok(BigUint::from(65537u32))
+    );
+}
+
+#[test]
+fn test_der_integer_error() {
+    use num_bigint::DerErrorKind::*;
+
+    let cases: &[(&str, _)] = &[
+        ("", Truncated),
+        ("02", Truncated),
+        ("0202", Truncated),
+        ("020200", Truncated),
+        ("0282", Truncated),
+        ("030100", UnexpectedTag),
+        ("0200", NonMinimal),
+        ("02020001", NonMinimal),
+        ("0202ff80", NonMinimal),
+        ("020180", Negative),
+        ("0202ff7f", Negative),
+        ("02010000", TrailingData),
+        ("028000", InvalidLength),
+        ("02810100", InvalidLength),
+        ("0282000101", InvalidLength),
+        ("02890100000000000000000000", InvalidLength),
+    ];
+    for (hex, kind) in cases {
+        let err = BigUint::from_der_integer(&from_hex(hex)).unwrap_err();
+        assert_eq!(err.kind(), kind, "{}", hex);
+    }
+}
+
+#[test]
+fn test_to_str_radix_10_large() {
+    // Sizes around the divide-and-conquer and reciprocal thresholds.
+    for &digits in &[500, 617, 1000, 1234, 5000, 10_000, 30_000] {
+        let ten: BigUint = Pow::pow(BigUint::from(10u32), digits as u32);
+        let nines = &ten - 1u32;
+        assert_eq!(ten.to_str_radix(10), format!("1{}", "0".repeat(digits)));
+        assert_eq!(nines.to_str_radix(10), "9".repeat(digits));
+        assert_eq!(
+            (&ten + 1u32).to_str_radix(10),
+            format!("1{}1", "0".repeat(digits - 1))
+        );
+
+        // Runs of zeros in the middle must be kept when the halves are joined.
+        let padded = &ten * &ten + &nines;
+        assert_eq!(
+            padded.to_str_radix(10),
+            format!("1{}{}", "0".repeat(digits), "9".repeat(digits))
+        );
+
+        // 7 × (10^digits - 1) / 3 = 233...331
+        let x = &nines * 7u32 / 3u32;
+        assert_eq!(x.to_str_radix(10), format!("2{}1", "3".repeat(digits - 1)));
+
+        // All ten digits, parsed from the expected string.
+        let s: String = "1234567890".chars().cycle().take(digits).collect();
+        let s = format!("{}{}", s, "987654321".repeat(3));
+        let x = BigUint::from_str_radix(&s, 10).unwrap();
+        assert_eq!(x.to_str_radix(10), s);
+    }
+}
+
+#[test]
+fn test_from_and_to_radix() {
+    const GROUND_TRUTH: &[(&[u8], u32, &[u8])] = &[
+        (b"0", 42, &[0]),
+        (
+            b"ffffeeffbb",
+            2,
+            &[
+                1, 1, 0, 1, 1, 1, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 1, 1, 1, 0, 1, 1, 1, 1, 1, 1, 1,
+                1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            ],
+        ),
+        (
+            b"ffffeeffbb",
+            3,
+            &[
+                2, 2, 1, 1, 2, 1, 1, 2, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 1, 0, 0, 2, 2, 0, 1,
+            ],
+        ),
+        (
+            b"ffffeeffbb",
+            4,
+            &[3, 2, 3, 2, 3, 3, 3, 3, 2, 3, 2, 3, 3, 3, 3, 3, 3, 3, 3, 3],
+        ),
+        (
+            b"ffffeeffbb",
+            5,
+            &[0, 4, 3, 3, 1, 4, 2, 4, 1, 4, 4, 2, 3, 0, 0, 1, 2, 1],
+        ),
+        (
+            b"ffffeeffbb",
+            6,
+            &[5, 5, 4, 5, 5, 0, 0, 1, 2, 5, 3, 0, 1, 0, 2, 2],
+        ),
+        (
+            b"ffffeeffbb",
+            7,
+            &[4, 2, 3, 6, 0, 1, 6, 1, 6, 2, 0, 3, 2, 4, 1],
+        ),
+        (
+            b"ffffeeffbb",
+            8,
+            &[3, 7, 6, 7, 7, 5, 3, 7, 7, 7, 7, 7, 7, 1],
+        ),
+        (b"ffffeeffbb", 9, &[8, 4, 5, 7, 0, 0, 3, 2, 0, 3, 0, 8, 3]),
+        (b"ffffeeffbb", 10, &[5, 9, 5, 3, 1, 5, 0, 1, 5, 9, 9, 0, 1]),
+        (b"ffffeeffbb", 11, &[10, 7, 6, 5, 2, 0, 3, 3, 3, 4, 9, 3]),
+        (b"ffffeeffbb", 12, &[11, 8, 5, 10, 1, 10, 3, 1, 1, 9, 5, 1]),
+        (b"ffffeeffbb", 13, &[0, 5, 7, 4, 6, 5, 6, 11, 8, 12, 7]),
+        (b"ffffeeffbb", 14, &[11, 4, 4, 11, 8, 4, 6, 0, 3, 11, 3]),
+        (b"ffffeeffbb", 15, &[5, 11, 13, 2, 1, 10, 2, 0, 9, 13, 1]),
+        (b"ffffeeffbb", 16, &[11, 11, 15, 15, 14, 14, 15, 15, 15, 15]),
+        (b"ffffeeffbb", 17, &[0, 2, 14, 12, 2, 14, 8, 10, 4, 9]),
+        (b"ffffeeffbb", 18, &[17, 15, 5, 13, 10, 16, 16, 13, 9, 5]),
+        (b"ffffeeffbb", 19, &[14, 13, 2, 8, 9, 0, 1, 14, 7, 3]),
+        (b"ffffeeffbb", 20, &[15, 19, 3, 14, 0, 17, 19, 18, 2, 2]),
+        (b"ffffeeffbb", 21, &[11, 5, 4, 13, 5, 18, 9, 1, 8, 1]),
+        (b"ffffeeffbb", 22, &[21, 3, 7, 21, 15, 12, 17, 0, 20]),
+        (b"ffffeeffbb", 23, &[21, 21, 6, 9, 10, 7, 21, 0, 14]),
+        (b"ffffeeffbb", 24, &[11, 10, 19, 14, 22, 11, 17, 23, 9]),
+        (b"ffffeeffbb", 25, &[20, 18, 21, 22, 21, 14, 3, 5, 7]),
+        (b"ffffeeffbb", 26, &[13, 15, 24, 11, 17, 6, 23, 6, 5]),
+        (b"ffffeeffbb", 27, &[17, 16, 7, 0, 21, 0, 3, 24, 3]),
+        (b"ffffeeffbb", 28, &[11, 16, 11, 15, 14, 18, 13, 25, 2]),
+        (b"ffffeeffbb", 29, &[6, 8, 7, 19, 14, 13, 21, 5, 2]),
+        (b"ffffeeffbb", 30, &[5, 13, 18, 11, 10, 7, 8, 20, 1]),
+        (b"ffffeeffbb", 31, &[22, 26, 15, 19, 8, 27, 29, 8, 1]),
+        (b"ffffeeffbb", 32, &[27, 29, 31, 29, 30, 31, 31, 31]),
+        (b"ffffeeffbb", 33, &[32, 20, 27, 12, 1, 12, 26, 25]),
+        (b"ffffeeffbb", 34, &[17, 9, 16, 33, 13, 25, 31, 20]),
         (b"ffffeeffbb", 35, &[25, 32, 2, 25, 11, 4, 3, 17]),
         (b"ffffeeffbb", 36, &[35, 34, 5, 6, 32, 3, 1, 14]),
         (b"ffffeeffbb", 37, &[16, 21, 18, 4, 33, 19, 21, 11]),
@@ -1622,10 +2693,101 @@ fn test_from_and_to_radix() {
     }
 
     assert!(BigUint::from_radix_le(&[10, 100, 10], 50).is_none());
+    assert!(BigUint::from_radix_be(&[10, 100, 10], 50).is_none());
+    assert!(BigUint::from_radix_be(&[1, 0, 2], 2).is_none());
+    assert!(BigUint::from_radix_le(&[0, 10], 10).is_none());
     assert_eq!(BigUint::from_radix_le(&[], 2), Some(BigUint::zero()));
     assert_eq!(BigUint::from_radix_be(&[], 2), Some(BigUint::zero()));
 }
 
+#[test]
+fn test_from_radix_raw_digits() {
+    // Base 256 digits are just bytes, without any ASCII layer.
+    let bytes = [0u8, 0, 1, 2, 3, 0xfe, 0xff, 0];
+    assert_eq!(
+        BigUint::from_radix_be(&bytes, 256),
+        Some(BigUint::from_bytes_be(&bytes))
+    );
+    assert_eq!(
+        BigUint::from_radix_le(&bytes, 256),
+        Some(BigUint::from_bytes_le(&bytes))
+    );
+
+    // Leading zero digits don't change the value, and the highest digit is still checked.
+    let n = BigUint::factorial(30);
+    for radix in [2, 3, 10, 36, 100, 255, 256] {
+        let mut be = vec![0; 3];
+        be.extend(n.to_radix_be(radix));
+        assert_eq!(BigUint::from_radix_be(&be, radix).as_ref(), Some(&n));
+        be.reverse();
+        assert_eq!(BigUint::from_radix_le(&be, radix).as_ref(), Some(&n));
+        if radix < 256 {
+            be.push(radix as u8);
+            assert_eq!(BigUint::from_radix_le(&be, radix), None);
+        }
+    }
+}
+
+#[test]
+fn test_from_decimal_digits() {
+    let n = BigUint::factorial(50);
+    let s = n.to_string();
+    let digits = s.bytes().map(|b| b - b'0');
+    assert_eq!(BigUint::from_decimal_digits(digits).unwrap(), n);
+    let leading_zeros = repeat(0).take(30).chain([4, 2]);
+    assert_eq!(
+        BigUint::from_decimal_digits(leading_zeros).unwrap(),
+        BigUint::from(42u32)
+    );
+    assert!(BigUint::from_decimal_digits([0]).unwrap().is_zero());
+
+    let err = BigUint::from_decimal_digits(None).unwrap_err();
+    assert_eq!(*err.kind(), ParseBigIntErrorKind::Empty);
+    assert_eq!(err.position(), 0);
+    let err = BigUint::from_decimal_digits([1, 2, 10, 3]).unwrap_err();
+    assert_eq!(*err.kind(), ParseBigIntErrorKind::InvalidDigit);
+    assert_eq!(err.position(), 2);
+}
+
+#[test]
+fn test_builder() {
+    let n = BigUint::factorial(40);
+    for radix in [2, 3, 7, 10, 16, 36, 255, 256] {
+        let mut builder = BigUintBuilder::new();
+        for digit in n.to_radix_be(radix) {
+            builder.push_digit(digit, radix);
+        }
+        assert_eq!(builder.finish(), n, "radix {}", radix);
+    }
+    assert!(BigUintBuilder::new().finish().is_zero());
+    assert!(BigUintBuilder::default().finish().is_zero());
+
+    let mut builder = BigUintBuilder::default();
+    for digit in [1, 2, 3] {
+        builder.push_digit(digit, 10);
+    }
+    assert_eq!(builder.finish(), BigUint::from(123u32));
+
+    // The radix can change between digits, like a mixed-radix clock: 3 days, 7 hours, 5 minutes.
+    let mut builder = BigUintBuilder::new();
+    builder.push_digit(3, 256);
+    builder.push_digit(7, 24);
+    builder.push_digit(5, 60);
+    let mut partial = builder.clone();
+    assert_eq!(builder.finish(), BigUint::from((3 * 24 + 7) * 60 + 5u32));
+    for _ in 0..40 {
+        partial.push_digit(0, 10);
+    }
+    let expected = BigUint::from((3 * 24 + 7) * 60 + 5u32) * BigUint::from(10u32).pow(40u32);
+    assert_eq!(partial.finish(), expected);
+}
+
+#[test]
+#[should_panic(expected = "The digit must be less than the radix")]
+fn test_builder_invalid_digit() {
+    BigUintBuilder::new().push_digit(10, 10);
+}
+
 #[test]
 fn test_from_str_radix() {
     let r = to_str_pairs();
@@ -1657,6 +2819,236 @@ fn test_from_str_radix() {
     assert_eq!(ff, Some(BigUint::from_slice(&[0xff])));
 }
 
+#[test]
+fn test_from_str_radix_error() {
+    use num_bigint::ParseBigIntErrorKind::*;
+
+    fn check(s: &str, radix: u32, kind: ParseBigIntErrorKind, position: usize) {
+        let err = BigUint::from_str_radix(s, radix).unwrap_err();
+        assert_eq!((*err.kind(), err.position()), (kind, position), "{:?}", s);
+    }
+    check("", 10, Empty, 0);
+    check("+", 10, Empty, 1);
+    check("_1", 2, InvalidDigit, 0);
+    check("+_1", 2, InvalidDigit, 1);
+    check("++1", 10, InvalidDigit, 0);
+    check("-1", 10, InvalidDigit, 0);
+    check("0+2", 10, InvalidDigit, 1);
+    check("12 3", 10, InvalidDigit, 2);
+    check("1é", 16, InvalidDigit, 1);
+    check("Z", 10, DigitOutOfRange, 0);
+    check("+1_1_2", 2, DigitOutOfRange, 5);
+    check("fF0g", 16, DigitOutOfRange, 3);
+
+    // The position isn't part of the message, which stays the same as before it was tracked.
+    let err = "12 3".parse::<BigUint>().unwrap_err();
+    assert_eq!(err.to_string(), "invalid digit found in string");
+}
+
+#[test]
+fn test_from_str_prefixed() {
+    use num_bigint::ParseBigIntErrorKind::*;
+
+    let ok = |s: &str, n: u64| {
+        assert_eq!(
+            BigUint::from_str_prefixed(s),
+            Ok(BigUint::from(n)),
+            "{:?}",
+            s
+        );
+        assert_eq!(
+            BigUint::from_str_prefixed_with_underscores(s),
+            Ok(BigUint::from(n)),
+            "{:?}",
+            s
+        );
+    };
+    ok("0", 0);
+    ok("0x0", 0);
+    ok("0b0", 0);
+    ok("123", 123);
+    ok("000123", 123);
+    ok("+123", 123);
+    ok("0xdeadBEEF", 0xdead_beef);
+    ok("0XFF", 0xff);
+    ok("0o777", 0o777);
+    ok("0O10", 8);
+    ok("0b1011", 0b1011);
+    ok("+0B1", 1);
+    ok("0x00ff", 0xff);
+
+    let big = "0x1_0000_0000_0000_0000_0000_0000_0000_0000";
+    assert_eq!(
+        BigUint::from_str_prefixed_with_underscores(big),
+        Ok(BigUint::from(1u32) << 128)
+    );
+
+    let under = |s: &str, n: u64| {
+        assert_eq!(
+            BigUint::from_str_prefixed_with_underscores(s),
+            Ok(BigUint::from(n)),
+            "{:?}",
+            s
+        );
+        let err = BigUint::from_str_prefixed(s).unwrap_err();
+        assert_eq!(*err.kind(), InvalidDigit, "{:?}", s);
+        assert_eq!(err.position(), s.find('_').unwrap(), "{:?}", s);
+    };
+    under("1_000", 1000);
+    under("1__0__", 10);
+    under("0x_ff", 0xff);
+    under("0b__1_0", 2);
+    under("+0o_1_", 1);
+
+    let err = |s: &str, kind: ParseBigIntErrorKind, position: usize| {
+        for &underscores in &[false, true] {
+            let e = if underscores {
+                BigUint::from_str_prefixed_with_underscores(s)
+            } else {
+                BigUint::from_str_prefixed(s)
+            }
+            .unwrap_err();
+            assert_eq!((*e.kind(), e.position()), (kind, position), "{:?}", s);
+        }
+    };
+    err("", Empty, 0);
+    err("+", Empty, 1);
+    err("0x", Empty, 2);
+    err("+0b", Empty, 3);
+    err("0x+1", InvalidDigit, 2);
+    err("0x-1", InvalidDigit, 2);
+    err("++1", InvalidDigit, 1);
+    err("-1", InvalidDigit, 0);
+    err("_1", InvalidDigit, 0);
+    err("0x0x1", DigitOutOfRange, 3);
+    err("0b102", DigitOutOfRange, 4);
+    err("0o8", DigitOutOfRange, 2);
+    err("12a", DigitOutOfRange, 2);
+    err(" 0x1", InvalidDigit, 0);
+    let e = BigUint::from_str_prefixed_with_underscores("0x__").unwrap_err();
+    assert_eq!((*e.kind(), e.position()), (Empty, 4));
+}
+
+#[test]
+fn test_from_str_hex_octal_binary() {
+    use num_bigint::ParseBigIntErrorKind::*;
+
+    type Parse = fn(&str) -> Result<BigUint, ParseBigIntError>;
+    let hex: Parse = BigUint::from_str_hex;
+    let octal: Parse = BigUint::from_str_octal;
+    let binary: Parse = BigUint::from_str_binary;
+    for (parse, s, n) in [
+        (hex, "ff", 0xff_u64),
+        (hex, "0xff", 0xff),
+        (hex, "0XfF", 0xff),
+        (hex, "+0x10", 0x10),
+        (hex, "0b1", 0xb1),
+        (hex, "dead_beef", 0xdead_beef),
+        (hex, "0", 0),
+        (octal, "0o17", 0o17),
+        (octal, "0O17", 0o17),
+        (octal, "017", 0o17),
+        (octal, "+7_7", 0o77),
+        (binary, "0b1011", 0b1011),
+        (binary, "0B1", 1),
+        (binary, "0", 0),
+        (binary, "+1_0", 2),
+    ] {
+        assert_eq!(parse(s), Ok(BigUint::from(n)), "{:?}", s);
+    }
+
+    let big = BigUint::from_str_hex(&format!("0x1{}", "0".repeat(64))).unwrap();
+    assert_eq!(big, BigUint::one() << 256u32);
+    assert_eq!(BigUint::from_str_hex(&big.to_str_radix(16)), Ok(big));
+
+    for (parse, s, kind, position) in [
+        (hex, "", Empty, 0),
+        (hex, "0x", Empty, 2),
+        (hex, "+", Empty, 1),
+        (hex, "0x+1", InvalidDigit, 2),
+        (hex, "0x_1", InvalidDigit, 2),
+        (hex, "-1", InvalidDigit, 0),
+        (hex, "0xg", DigitOutOfRange, 2),
+        (octal, "0x1", DigitOutOfRange, 1),
+        (octal, "0o8", DigitOutOfRange, 2),
+        (binary, "0b102", DigitOutOfRange, 4),
+        (binary, "++1", InvalidDigit, 1),
+    ] {
+        let e = parse(s).unwrap_err();
+        assert_eq!((*e.kind(), e.position()), (kind, position), "{:?}", s);
+    }
+}
+
+#[test]
+fn test_from_str_lax() {
+    use num_bigint::ParseBigIntErrorKind::*;
+
+    for (s, radix, n) in [
+        ("0", 10, 0_u64),
+        ("  42", 10, 42),
+        ("42\n", 10, 42),
+        ("\t 1_000_000 \r\n", 10, 1_000_000),
+        ("+0017", 10, 17),
+        ("0017", 8, 0o17),
+        (" 000 ", 2, 0),
+        ("\u{a0}ff_FF\u{3000}", 16, 0xffff),
+        ("1__2_", 10, 12),
+        (" zz ", 36, 36 * 36 - 1),
+    ] {
+        assert_eq!(
+            BigUint::from_str_lax(s, radix),
+            Ok(BigUint::from(n)),
+            "{:?}",
+            s
+        );
+    }
+
+    for (s, kind, position) in [
+        ("", Empty, 0),
+        ("   ", Empty, 3),
+        (" + ", Empty, 2),
+        ("  _1", InvalidDigit, 2),
+        (" -1", InvalidDigit, 1),
+        ("1 000", InvalidDigit, 1),
+        ("+ 1", InvalidDigit, 1),
+        ("\u{a0}12a", DigitOutOfRange, 4),
+    ] {
+        let e = BigUint::from_str_lax(s, 10).unwrap_err();
+        assert_eq!((*e.kind(), e.position()), (kind, position), "{:?}", s);
+    }
+
+    // Arbitrary bytes never panic, and parse the same as the trimmed string.
+    let mut state = 0x9e37_79b9_7f4a_7c15u64;
+    let mut next = || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    };
+    let alphabet = b" \t\n+-_019azAZ\xc2\xa0\xff";
+    for _ in 0..10_000 {
+        let len = next() % 12;
+        let bytes: Vec<u8> = (0..len)
+            .map(|_| match next() % 4 {
+                0 => next() as u8,
+                _ => alphabet[next() as usize % alphabet.len()],
+            })
+            .collect();
+        let s = String::from_utf8_lossy(&bytes);
+        let radix = 2 + (next() % 35) as u32;
+        let lax = BigUint::from_str_lax(&s, radix);
+        let strict = BigUint::from_str_radix(s.trim(), radix);
+        match (&lax, strict) {
+            (Ok(a), Ok(b)) => assert_eq!(*a, b, "{:?}", s),
+            (Err(a), Err(b)) => {
+                assert_eq!(a.kind(), b.kind(), "{:?}", s);
+                assert!(a.position() <= s.len(), "{:?}", s);
+            }
+            _ => panic!("{:?} parsed as {:?}", s, lax),
+        }
+    }
+}
+
 #[test]
 fn test_all_str_radix() {
     let n = BigUint::new((0..10).collect());
@@ -1734,6 +3126,55 @@ fn test_display() {
     assert_eq!(format!("{:♥>+#8}", a), "♥♥♥♥♥+10");
 }
 
+#[test]
+fn test_display_with_separator() {
+    let hello = BigUint::parse_bytes(b"22405534230753963835153736737", 10).unwrap();
+    assert_eq!(
+        hello.with_separator('_', 3).to_string(),
+        "22_405_534_230_753_963_835_153_736_737"
+    );
+    assert_eq!(
+        format!("{:?}", hello.with_separator('\'', 4)),
+        "2'2405'5342'3075'3963'8351'5373'6737"
+    );
+    assert_eq!(
+        hello.with_separator(',', 100).to_string(),
+        hello.to_string()
+    );
+    for n in [0u64, 7, 999, 1000, 123456, u64::MAX] {
+        let grouped = BigUint::from(n).with_separator(' ', 3).to_string();
+        assert_eq!(grouped.replace(' ', ""), n.to_string());
+        assert!(grouped.split(' ').skip(1).all(|g| g.len() == 3));
+    }
+
+    let n = BigUint::from(1234567u32);
+    let x = n.with_separator(',', 3);
+    assert_eq!(format!("{:+}", x), "+1,234,567");
+    assert_eq!(format!("{:*^13}", x), "**1,234,567**");
+    assert_eq!(format!("{:<10}|", x), "1,234,567 |");
+}
+
+#[test]
+#[should_panic(expected = "group size must not be zero")]
+fn test_display_with_separator_zero_group() {
+    let _ = BigUint::one().with_separator(',', 0);
+}
+
+#[test]
+fn test_fmt_flags_match_primitive() {
+    macro_rules! check {
+        ($x:expr, $($fmt:literal),*) => {$(
+            assert_eq!(format!($fmt, BigUint::from($x)), format!($fmt, $x), "{}", $fmt);
+        )*}
+    }
+
+    for &x in &[0u64, 1, 10, 255, 0xdead_beef, u64::MAX] {
+        check!(x, "{}", "{:20}", "{:<20}", "{:^20}", "{:*>+20}", "{:020}", "{:+020}");
+        check!(x, "{:x}", "{:#x}", "{:#020x}", "{:<#20x}", "{:X}", "{:#X}", "{:^#20X}");
+        check!(x, "{:b}", "{:#b}", "{:#070b}", "{:+#b}", "{:o}", "{:#o}", "{:>#30o}");
+    }
+}
+
 #[test]
 fn test_factor() {
     fn factor(n: usize) -> BigUint {
@@ -1774,6 +3215,76 @@ fn test_bits() {
     assert_eq!((one << 426u16).bits(), 427);
 }
 
+#[test]
+fn test_byte_count() {
+    assert_eq!(BigUint::zero().byte_count(), 0);
+    for bits in 1u32..300 {
+        for n in [
+            BigUint::one() << (bits - 1),
+            (BigUint::one() << bits) - 1u32,
+        ] {
+            assert_eq!(n.bit_count(), u64::from(bits));
+            assert_eq!(n.byte_count() as u64, (n.bits() + 7) / 8);
+            assert_eq!(n.byte_count(), n.to_bytes_be().len());
+            assert_eq!(n.byte_count(), n.to_bytes_le().len());
+        }
+    }
+}
+
+#[test]
+fn test_ilog() {
+    assert_eq!(BigUint::zero().checked_ilog2(), None);
+    assert_eq!(BigUint::zero().checked_ilog10(), None);
+    assert_eq!(BigUint::zero().checked_ilog(&BigUint::from(3u32)), None);
+    assert_eq!(BigUint::from(9u32).checked_ilog(&BigUint::one()), None);
+
+    // Primitive `ilog` is too new for our MSRV.
+    fn ilog(mut n: u32, base: u32) -> u64 {
+        let mut log = 0;
+        while n >= base {
+            n /= base;
+            log += 1;
+        }
+        log
+    }
+
+    for n in 1..5000u32 {
+        let big = BigUint::from(n);
+        assert_eq!(big.ilog2(), ilog(n, 2));
+        assert_eq!(big.ilog10(), ilog(n, 10));
+        for base in 2..40u32 {
+            assert_eq!(big.ilog(&BigUint::from(base)), ilog(n, base));
+        }
+    }
+
+    let ten = BigUint::from(10u32);
+    for exp in [1u32, 19, 20, 100, 1000, 5000] {
+        let x = Pow::pow(&ten, exp);
+        assert_eq!(x.ilog10(), u64::from(exp));
+        assert_eq!((&x - 1u32).ilog10(), u64::from(exp - 1));
+        assert_eq!((&x + 1u32).ilog10(), u64::from(exp));
+        assert_eq!(x.ilog2(), x.bits() - 1);
+    }
+
+    let base = BigUint::from(u64::MAX) * 12345u32;
+    let x = Pow::pow(&base, 37u32);
+    assert_eq!(x.ilog(&base), 37);
+    assert_eq!((&x - 1u32).ilog(&base), 36);
+    assert_eq!(base.ilog(&x), 0);
+}
+
+#[test]
+#[should_panic(expected = "argument of integer logarithm must be positive")]
+fn test_ilog10_zero() {
+    BigUint::zero().ilog10();
+}
+
+#[test]
+#[should_panic(expected = "base of integer logarithm must be at least 2")]
+fn test_ilog_base_one() {
+    BigUint::from(10u32).ilog(&BigUint::one());
+}
+
 #[test]
 fn test_iter_sum() {
     let result: BigUint = FromPrimitive::from_isize(1234567).unwrap();
@@ -1810,6 +3321,19 @@ fn test_iter_product() {
     assert_eq!(result, data.into_iter().product::<BigUint>());
 }
 
+#[test]
+fn test_iter_sum_product_empty() {
+    let data: Vec<BigUint> = Vec::new();
+    assert!(data.iter().sum::<BigUint>().is_zero());
+    assert!(data.iter().product::<BigUint>().is_one());
+    assert!(data.into_iter().sum::<BigUint>().is_zero());
+    assert!(Vec::<BigUint>::new()
+        .into_iter()
+        .product::<BigUint>()
+        .is_one());
+    assert!(std::iter::empty::<u32>().product::<BigUint>().is_one());
+}
+
 #[test]
 fn test_iter_sum_generic() {
     let result: BigUint = FromPrimitive::from_isize(1234567).unwrap();
@@ -1863,6 +3387,65 @@ fn test_pow() {
     assert!(manual_1e10000 == pow_1e10000);
 }
 
+#[test]
+fn test_pow_wide_exponents() {
+    let one = BigUint::one();
+    let zero = BigUint::zero();
+    assert_eq!(Pow::pow(&one, u64::MAX), one);
+    assert_eq!(Pow::pow(&one, u128::MAX), one);
+    assert_eq!(Pow::pow(&zero, u128::MAX), zero);
+    assert_eq!(Pow::pow(&zero, 0u128), one);
+    assert_eq!(
+        Pow::pow(BigUint::from(3u32), 100u64),
+        Pow::pow(BigUint::from(3u32), 100u32)
+    );
+    assert_eq!(Pow::pow(BigUint::from(2u32), 1000u128), one << 1000u32);
+}
+
+#[test]
+#[should_panic(expected = "memory overflow")]
+fn test_pow_u64_overflow() {
+    let _ = Pow::pow(BigUint::from(4u32), 1u64 << 63);
+}
+
+#[test]
+#[should_panic(expected = "memory overflow")]
+fn test_pow_u128_overflow() {
+    let _ = Pow::pow(&BigUint::from(2u32), 1u128 << 70);
+}
+
+#[test]
+fn test_pow_big() {
+    for base in [0u32, 1, 2, 3, 10, u32::MAX] {
+        let base = BigUint::from(base);
+        for exp in [0u32, 1, 2, 7, 64, 65, 300] {
+            let expected = BigUint::pow(&base, exp);
+            let exponent = BigUint::from(exp);
+            assert_eq!(base.pow_big(&exponent), expected);
+
+            let bits = expected.bits();
+            assert_eq!(
+                base.checked_pow_big(&exponent, bits),
+                Some(expected.clone())
+            );
+            assert_eq!(base.checked_pow_big(&exponent, u64::MAX), Some(expected));
+            if bits > 0 {
+                assert_eq!(base.checked_pow_big(&exponent, bits - 1), None);
+            }
+        }
+    }
+
+    let huge = BigUint::one() << 200u32;
+    assert!(BigUint::zero().checked_pow_big(&huge, 0).unwrap().is_zero());
+    assert!(BigUint::one().checked_pow_big(&huge, 1).unwrap().is_one());
+    assert_eq!(BigUint::from(2u32).checked_pow_big(&huge, u64::MAX), None);
+    let big_exponent = BigUint::from(u64::MAX / 2 + 1);
+    assert_eq!(
+        BigUint::from(5u32).checked_pow_big(&big_exponent, u64::MAX),
+        None
+    );
+}
+
 #[test]
 fn test_trailing_zeros() {
     assert!(BigUint::from(0u8).trailing_zeros().is_none());
@@ -1884,6 +3467,26 @@ fn test_trailing_ones() {
     assert_eq!(x.trailing_ones(), 128);
 }
 
+#[test]
+fn test_leading_zeros() {
+    // The width of the internal digits, 32 or 64
+    let digit_bits = BigUint::one().leading_zeros() + 1;
+    assert!(digit_bits == 32 || digit_bits == 64);
+
+    assert_eq!(BigUint::zero().leading_zeros(), 0);
+    assert_eq!(BigUint::from(u64::MAX).leading_zeros(), 0);
+    assert_eq!(BigUint::from(u64::MAX >> 1).leading_zeros(), 1);
+    for shift in 0..200u32 {
+        let x = BigUint::one() << shift;
+        let bits = u64::from(shift) + 1;
+        assert_eq!(
+            x.leading_zeros(),
+            (digit_bits - bits % digit_bits) % digit_bits
+        );
+        assert_eq!(x.bit_len(), bits);
+    }
+}
+
 #[test]
 fn test_count_ones() {
     assert_eq!(BigUint::from(0u8).count_ones(), 0);
@@ -1894,6 +3497,73 @@ fn test_count_ones() {
     assert_eq!(x.count_ones(), 4);
 }
 
+#[test]
+fn test_bitset() {
+    let values: Vec<BigUint> = [
+        BigUint::zero(),
+        BigUint::from(0b1011u32),
+        BigUint::from(u64::MAX),
+        BigUint::from(0xf0f0_f0f0_f0f0_f0f0_f0f0_f0f0u128),
+        (BigUint::one() << 200u32) - 1u32,
+        (BigUint::one() << 200u32) | BigUint::from(0b1011u32),
+    ]
+    .into();
+    for a in &values {
+        assert!(a.is_subset_of(a));
+        assert!(BigUint::zero().is_subset_of(a));
+        assert!(a.difference(a).is_zero());
+        assert_eq!(a.hamming_distance(a), 0);
+        assert_eq!(a.count_shared_bits(a), a.count_ones());
+
+        for b in &values {
+            let and = a & b;
+            let xor = a ^ b;
+            assert_eq!(a.is_subset_of(b), &and == a);
+            assert_eq!(a.is_superset_of(b), &and == b);
+            assert_eq!(a.count_shared_bits(b), and.count_ones());
+            assert_eq!(a.hamming_distance(b), xor.count_ones());
+            assert_eq!(a.difference(b), a ^ &and);
+            assert_eq!(a.difference(b) | b.difference(a), xor);
+        }
+    }
+}
+
+#[test]
+fn test_count_zeros() {
+    assert_eq!(BigUint::from(0u8).count_zeros(), 0);
+    assert_eq!(BigUint::from(1u8).count_zeros(), 0);
+    assert_eq!(BigUint::from(2u8).count_zeros(), 1);
+    assert_eq!(BigUint::from(0b10100u8).count_zeros(), 3);
+    let x: BigUint = (BigUint::from(3u8) << 128) | BigUint::from(3u8);
+    assert_eq!(x.count_zeros(), 126);
+}
+
+#[test]
+fn test_power_of_two() {
+    let pow2 = |k: u32| BigUint::one() << k;
+    let cases = [
+        (BigUint::zero(), false, BigUint::one()),
+        (BigUint::from(1u32), true, BigUint::one()),
+        (BigUint::from(2u32), true, BigUint::from(2u32)),
+        (BigUint::from(3u32), false, BigUint::from(4u32)),
+        (BigUint::from(1024u32), true, BigUint::from(1024u32)),
+        (BigUint::from(1025u32), false, BigUint::from(2048u32)),
+        (BigUint::from(u64::MAX), false, pow2(64)),
+        (pow2(1000), true, pow2(1000)),
+        (pow2(1000) - 1u32, false, pow2(1000)),
+        (pow2(1000) + 1u32, false, pow2(1001)),
+        (pow2(1000) + pow2(999), false, pow2(1001)),
+    ];
+    for (n, is_pow2, next) in cases {
+        assert_eq!(n.is_power_of_two(), is_pow2, "{}", n);
+        assert_eq!(n.next_power_of_two(), next, "{}", n);
+        assert!(next.is_power_of_two());
+        if let Some(x) = n.to_u64() {
+            assert_eq!(is_pow2, x.is_power_of_two());
+        }
+    }
+}
+
 #[test]
 fn test_bit() {
     assert!(!BigUint::from(0u8).bit(0));
@@ -1919,3 +3589,306 @@ fn test_set_bit() {
     x.set_bit(1, false);
     assert_eq!(x, BigUint::zero());
 }
+
+#[test]
+fn test_clear_flip_test_bit() {
+    let mut x = BigUint::from(3u8);
+    x.flip_bit(128);
+    x.flip_bit(129);
+    assert_eq!(x, (BigUint::from(3u8) << 128) | BigUint::from(3u8));
+    assert!(x.test_bit(129));
+    assert!(!x.test_bit(130));
+    assert!(!x.test_bit(u64::MAX));
+
+    x.flip_bit(0);
+    x.clear_bit(128);
+    x.clear_bit(130);
+    x.clear_bit(u64::MAX);
+    assert_eq!(x, (BigUint::from(2u8) << 128) | BigUint::from(2u8));
+    assert!(!x.test_bit(0));
+
+    // Flipping the top bit must normalize
+    x.flip_bit(129);
+    assert_eq!(x, BigUint::from(2u8));
+    assert_eq!(x.bits(), 2);
+    x.flip_bit(1);
+    assert!(x.is_zero());
+    assert_eq!(x.to_u32_digits(), Vec::<u32>::new());
+}
+
+#[test]
+fn test_conditional_swap() {
+    let small = BigUint::from(7u8);
+    let big: BigUint = BigUint::from(5u8) << 200;
+
+    let (mut a, mut b) = (small.clone(), big.clone());
+    a.conditional_swap(&mut b, false);
+    assert_eq!((&a, &b), (&small, &big));
+    // The padding digits must be trimmed again.
+    assert_eq!(a.to_u32_digits(), vec![7]);
+
+    a.conditional_swap(&mut b, true);
+    assert_eq!((&a, &b), (&big, &small));
+    assert_eq!(b.to_u32_digits(), vec![7]);
+
+    let mut zero = BigUint::zero();
+    zero.conditional_swap(&mut a, true);
+    assert_eq!((&zero, &a), (&big, &BigUint::zero()));
+    assert_eq!(a.to_u32_digits(), Vec::<u32>::new());
+}
+
+#[test]
+fn test_capacity() {
+    let mut n = BigUint::with_capacity(4096);
+    let cap = n.capacity();
+    assert!(cap >= 4096);
+    assert!(n.is_zero());
+
+    n.reserve(0);
+    assert_eq!(n.capacity(), cap);
+
+    n.shrink_to_fit();
+    assert_eq!(n.capacity(), 0);
+
+    let mut n = BigUint::from(1u32) << 1000u32;
+    n.reserve(1000);
+    assert!(n.capacity() >= 2001);
+    assert_eq!(n, BigUint::from(1u32) << 1000u32);
+    n.shrink_to_fit();
+    assert!(n.capacity() < 1100);
+}
+
+#[test]
+fn test_wrapping_arithmetic() {
+    use num_bigint::WrappingBigUint;
+
+    // Compare with the primitive wrapping operations at each width.
+    let values = [0u64, 1, 2, 0x7f, 0xff, 0x1234_5678, u64::MAX - 1, u64::MAX];
+    for &bits in &[8u64, 32, 64] {
+        let mask = if bits == 64 {
+            u64::MAX
+        } else {
+            (1 << bits) - 1
+        };
+        for &a in &values {
+            for &b in &values {
+                let (x, y) = (BigUint::from(a), BigUint::from(b));
+                let add = a.wrapping_add(b) & mask;
+                let sub = a.wrapping_sub(b) & mask;
+                let mul = a.wrapping_mul(b) & mask;
+                assert_eq!(x.wrapping_add(&y, bits), BigUint::from(add));
+                assert_eq!(x.wrapping_sub(&y, bits), BigUint::from(sub));
+                assert_eq!(x.wrapping_mul(&y, bits), BigUint::from(mul));
+
+                let wx = WrappingBigUint::new(x.clone(), bits);
+                let wy = WrappingBigUint::new(y.clone(), bits);
+                assert_eq!(wx.value(), &BigUint::from(a & mask));
+                assert_eq!((&wx + &wy).into_inner(), BigUint::from(add));
+                assert_eq!((&wx - &wy).into_inner(), BigUint::from(sub));
+                assert_eq!((wx * wy).into_inner(), BigUint::from(mul));
+            }
+            for shift in 0..bits + 2 {
+                let shl = a.checked_shl(shift as u32).unwrap_or(0) & mask;
+                let x = BigUint::from(a);
+                assert_eq!(x.wrapping_shl(shift, bits), BigUint::from(shl));
+                let wx = WrappingBigUint::new(x, bits);
+                assert_eq!((&wx << shift).into_inner(), BigUint::from(shl));
+                assert_eq!(
+                    (wx >> shift).into_inner(),
+                    BigUint::from((a & mask).checked_shr(shift as u32).unwrap_or(0))
+                );
+            }
+        }
+    }
+
+    // Widths that aren't a multiple of the digit size.
+    let bits = 100u64;
+    let modulus = BigUint::from(1u32) << bits;
+    let a = &modulus - 3u32;
+    let b = BigUint::from(5u32) << 98u32;
+    assert_eq!(a.wrapping_add(&b, bits), (&a + &b) % &modulus);
+    assert_eq!(b.wrapping_sub(&a, bits), (&b + &modulus - &a) % &modulus);
+    assert_eq!(a.wrapping_mul(&b, bits), (&a * &b) % &modulus);
+
+    let mut counter = WrappingBigUint::new(modulus - 1u32, bits);
+    counter += WrappingBigUint::new(BigUint::from(1u32), bits);
+    assert_eq!(counter.value(), &BigUint::ZERO);
+    assert_eq!(counter.bits(), 100);
+}
+
+#[test]
+#[should_panic(expected = "different widths")]
+fn test_wrapping_width_mismatch() {
+    use num_bigint::WrappingBigUint;
+
+    let a = WrappingBigUint::new(BigUint::from(1u32), 8);
+    let b = WrappingBigUint::new(BigUint::from(1u32), 16);
+    let _ = a + b;
+}
+
+#[test]
+fn test_barrett_reducer() {
+    use num_bigint::BarrettReducer;
+
+    let mut random = random_u32s();
+    let mut random_below = |bound: &BigUint| {
+        let digits: Vec<u32> = random
+            .by_ref()
+            .take(bound.bits() as usize / 32 + 1)
+            .collect();
+        BigUint::new(digits) % bound
+    };
+
+    let moduli = [
+        BigUint::one(),
+        BigUint::from(2u32),
+        BigUint::from(1_000_000_007u32),
+        BigUint::from(u64::MAX),
+        BigUint::one() << 127u32,
+        (BigUint::one() << 521u32) - 1u32,
+        BigUint::from_str_radix(&"9".repeat(700), 10).unwrap(),
+    ];
+    for modulus in &moduli {
+        let reducer = BarrettReducer::new(modulus.clone());
+        assert_eq!(reducer.modulus(), modulus);
+
+        // Up to `2 * modulus^2`, which can have one more bit than the square
+        let limit = modulus * modulus * 2u32 + 1u32;
+        let mut values = vec![
+            BigUint::zero(),
+            modulus - 1u32,
+            modulus.clone(),
+            &limit - 1u32,
+        ];
+        values.extend((0..20).map(|_| random_below(&limit)));
+        values.extend((0..5).map(|_| random_below(modulus)));
+        // Values far beyond the square are reduced in pieces.
+        values.push(&limit << 1000u32);
+        values.push(random_below(&(&limit << 3000u32)));
+        for n in &values {
+            assert_eq!(reducer.reduce(n), n % modulus, "{} mod {}", n, modulus);
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "attempt to calculate with zero modulus!")]
+fn test_barrett_reducer_zero() {
+    let _ = num_bigint::BarrettReducer::new(BigUint::zero());
+}
+
+#[test]
+fn test_modular_arithmetic() {
+    use num_bigint::ModularArithmetic;
+
+    let moduli = [
+        BigUint::one(),
+        BigUint::from(2u32),
+        BigUint::from(17u32),
+        BigUint::from(u64::MAX),
+        (BigUint::one() << 521u32) - 1u32,
+    ];
+    for modulus in &moduli {
+        let m = ModularArithmetic::new(modulus.clone());
+        assert_eq!(m.modulus(), modulus);
+
+        let values = [
+            BigUint::zero(),
+            BigUint::one(),
+            BigUint::from(16u32),
+            modulus - 1u32,
+            modulus.clone(),
+            modulus + 1u32,
+            modulus * 3u32 + 5u32,
+            BigUint::one() << 600u32,
+        ];
+        let signed = BigInt::from(modulus.clone());
+        for a in &values {
+            assert_eq!(m.reduce(a), a % modulus);
+            let neg = (-BigInt::from(a.clone())).mod_floor(&signed);
+            assert_eq!(BigInt::from(m.neg(a)), neg, "-{} mod {}", a, modulus);
+            for b in &values {
+                assert_eq!(m.add(a, b), (a + b) % modulus);
+                assert_eq!(m.mul(a, b), (a * b) % modulus);
+                let diff = (BigInt::from(a.clone()) - BigInt::from(b.clone())).mod_floor(&signed);
+                assert_eq!(
+                    BigInt::from(m.sub(a, b)),
+                    diff,
+                    "{} - {} mod {}",
+                    a,
+                    b,
+                    modulus
+                );
+            }
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "attempt to calculate with zero modulus!")]
+fn test_modular_arithmetic_zero() {
+    let _ = num_bigint::ModularArithmetic::new(BigUint::zero());
+}
+
+#[test]
+fn test_range() {
+    use num_bigint::BigUintRange;
+
+    let big = BigUint::from(u64::MAX) - 2u32;
+    let range = BigUintRange::new(big.clone(), &big + 5u32);
+    assert_eq!(range.size_hint(), (5, Some(5)));
+    let values: Vec<BigUint> = range.clone().collect();
+    let expected: Vec<BigUint> = (0u32..5).map(|i| &big + i).collect();
+    assert_eq!(values, expected);
+
+    let mut reversed: Vec<BigUint> = range.clone().rev().collect();
+    reversed.reverse();
+    assert_eq!(reversed, expected);
+
+    let mut range = range;
+    assert_eq!(range.nth(3), Some(&big + 3u32));
+    assert_eq!(range.next_back(), Some(&big + 4u32));
+    assert_eq!(range.next(), None);
+    assert_eq!(range.next_back(), None);
+
+    assert_eq!(BigUintRange::from(big.clone()..big.clone()).next(), None);
+    assert_eq!(
+        BigUintRange::from(&big + 1u32..big.clone()).size_hint(),
+        (0, Some(0))
+    );
+
+    let huge = BigUintRange::new(BigUint::zero(), BigUint::one() << 100u32);
+    assert_eq!(huge.size_hint(), (usize::MAX, None));
+}
+
+#[test]
+fn test_step_range() {
+    for (start, end, step) in [
+        (0u32, 10u32, 1u32),
+        (0, 10, 3),
+        (1, 10, 3),
+        (5, 5, 2),
+        (7, 2, 1),
+    ] {
+        let expected: Vec<BigUint> = (start..end)
+            .step_by(step as usize)
+            .map(BigUint::from)
+            .collect();
+        let range = BigUint::range(start.into(), end.into(), step.into());
+        assert_eq!(range.size_hint(), (expected.len(), Some(expected.len())));
+        assert_eq!(range.clone().collect::<Vec<_>>(), expected);
+        assert_eq!(range.clone().nth(2), expected.get(2).cloned());
+    }
+
+    let step = BigUint::one() << 70u32;
+    let mut range = BigUint::range(BigUint::zero(), BigUint::one() << 72u32, step.clone());
+    assert_eq!(range.size_hint(), (4, Some(4)));
+    assert_eq!(range.nth(3), Some(&step * 3u32));
+    assert_eq!(range.next(), None);
+}
+
+#[test]
+#[should_panic(expected = "step must not be zero")]
+fn test_step_range_zero() {
+    BigUint::range(BigUint::zero(), BigUint::one(), BigUint::zero());
+}
@@ -1,5 +1,17 @@
 #![allow(unused)]
 
+/// Returns an endless xorshift sequence, so tests get deterministic pseudorandom digits without
+/// pulling in `rand`.
+pub fn random_u32s() -> impl Iterator<Item = u32> {
+    let mut state = 0x2545_f491_4f6c_dd1du64;
+    std::iter::repeat_with(move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state as u32
+    })
+}
+
 pub const N1: u32 = -1i32 as u32;
 pub const N2: u32 = -2i32 as u32;
 
@@ -0,0 +1,251 @@
+#![cfg(feature = "decimal")]
+
+use num_bigint::{BigDecimal, BigInt, ParseBigIntErrorKind};
+use num_traits::{FromPrimitive, One, ToPrimitive, Zero};
+
+use std::collections::HashSet;
+
+fn dec(s: &str) -> BigDecimal {
+    s.parse().unwrap()
+}
+
+#[test]
+fn test_parse_display() {
+    let cases = [
+        ("0", 0, 0, "0"),
+        ("-0", 0, 0, "0"),
+        ("+12", 12, 0, "12"),
+        ("1.5", 15, -1, "1.5"),
+        ("-0.001", -1, -3, "-0.001"),
+        ("0.00", 0, -2, "0.00"),
+        (".5", 5, -1, "0.5"),
+        ("5.", 5, 0, "5"),
+        ("1.25e3", 125, 1, "1250"),
+        ("-1.25E-3", -125, -5, "-0.00125"),
+        ("7e+2", 7, 2, "700"),
+        ("123456789012345678901234567890", 0, 0, ""),
+    ];
+    for (s, mantissa, scale, display) in cases {
+        let x = dec(s);
+        if display.is_empty() {
+            assert_eq!(x.to_string(), s);
+            continue;
+        }
+        assert_eq!(x.mantissa(), &BigInt::from(mantissa), "{}", s);
+        assert_eq!(x.scale(), scale, "{}", s);
+        assert_eq!(x.to_string(), display);
+        assert_eq!(dec(display), x);
+    }
+
+    assert_eq!(dec("1e1000000").scale(), 1_000_000);
+    assert_eq!(dec("-1.5e-1000000").scale(), -1_000_001);
+
+    for (s, kind, position) in [
+        ("", ParseBigIntErrorKind::Empty, 0),
+        ("-", ParseBigIntErrorKind::Empty, 1),
+        (".", ParseBigIntErrorKind::Empty, 1),
+        ("e5", ParseBigIntErrorKind::Empty, 0),
+        ("1e", ParseBigIntErrorKind::Empty, 2),
+        ("1e-", ParseBigIntErrorKind::Empty, 3),
+        ("1.2.3", ParseBigIntErrorKind::InvalidDigit, 3),
+        ("--1", ParseBigIntErrorKind::InvalidDigit, 1),
+        ("1_000", ParseBigIntErrorKind::InvalidDigit, 1),
+        ("1ex", ParseBigIntErrorKind::InvalidDigit, 2),
+        (
+            "1e99999999999999999999",
+            ParseBigIntErrorKind::InvalidDigit,
+            2,
+        ),
+        ("1e1000001", ParseBigIntErrorKind::InvalidDigit, 2),
+        (
+            "1e-4000000000000000000",
+            ParseBigIntErrorKind::InvalidDigit,
+            2,
+        ),
+    ] {
+        let err = s.parse::<BigDecimal>().unwrap_err();
+        assert_eq!(*err.kind(), kind, "{:?}", s);
+        assert_eq!(err.position(), position, "{:?}", s);
+    }
+}
+
+#[test]
+fn test_format_options() {
+    let x = dec("-3.14159");
+    assert_eq!(format!("{:.2}", x), "-3.14");
+    assert_eq!(format!("{:.0}", x), "-3");
+    assert_eq!(format!("{:.7}", x), "-3.1415900");
+    assert_eq!(format!("{:>10.3}", x), "    -3.142");
+    assert_eq!(format!("{:+}", dec("2.5")), "+2.5");
+    assert_eq!(format!("{:08.1}", dec("2.25")), "000002.2");
+    assert_eq!(format!("{:?}", dec("1.50")), "1.50");
+}
+
+#[test]
+fn test_add_sub() {
+    assert_eq!(dec("0.1") + dec("0.2"), dec("0.3"));
+    assert_eq!((dec("0.1") + dec("0.2")).to_string(), "0.3");
+    assert_eq!(dec("1.5") - dec("0.25"), dec("1.25"));
+    assert_eq!((dec("1.5") - dec("0.25")).scale(), -2);
+    assert_eq!(dec("-1") + dec("1.000"), BigDecimal::zero());
+
+    // Negative scales, for multiples of powers of ten.
+    let a = BigDecimal::new(BigInt::from(3), 5);
+    let b = BigDecimal::new(BigInt::from(-2), 3);
+    assert_eq!((&a + &b).to_string(), "298000");
+    assert_eq!((&a + &b).scale(), 3);
+    assert_eq!((&b - &a).to_string(), "-302000");
+    assert_eq!(a.clone() + dec("0.5"), dec("300000.5"));
+
+    let mut x = dec("10");
+    x += dec("0.01");
+    x -= &dec("0.001");
+    assert_eq!(x, dec("10.009"));
+    assert_eq!(-x, dec("-10.009"));
+
+    let total: BigDecimal = ["0.1", "0.2", "0.3", "0.4"].iter().map(|s| dec(s)).sum();
+    assert_eq!(total, BigDecimal::one());
+}
+
+#[test]
+fn test_mul_div() {
+    assert_eq!(dec("1.5") * dec("-0.02"), dec("-0.03"));
+    assert_eq!((dec("1.5") * dec("-0.02")).scale(), -3);
+    let big = BigDecimal::new(BigInt::from(7), 20);
+    assert_eq!(
+        (&big * dec("0.5")).to_string(),
+        format!("35{}", "0".repeat(19))
+    );
+    let product: BigDecimal = ["1.5", "2", "0.1"].iter().map(|s| dec(s)).product();
+    assert_eq!(product, dec("0.3"));
+
+    assert_eq!(dec("1").div_with_scale(&dec("8"), -3), dec("0.125"));
+    assert_eq!(dec("1").div_with_scale(&dec("8"), -2).to_string(), "0.12");
+    assert_eq!(dec("3").div_with_scale(&dec("8"), -2).to_string(), "0.38");
+    assert_eq!(dec("-3").div_with_scale(&dec("8"), -2).to_string(), "-0.38");
+    assert_eq!(dec("3").div_with_scale(&dec("-0.08"), 0).to_string(), "-38");
+    assert_eq!(
+        dec("1e6").div_with_scale(&dec("3"), 3).to_string(),
+        "333000"
+    );
+    assert_eq!(dec("0").div_with_scale(&dec("3"), -2).to_string(), "0.00");
+
+    let third = BigDecimal::one() / BigDecimal::from(3);
+    assert_eq!(third.to_string(), format!("0.{}", "3".repeat(32)));
+    let big_third = BigDecimal::new(BigInt::from(1), 40) / dec("3");
+    assert_eq!(
+        big_third.to_string(),
+        format!("{}{}", "3".repeat(32), "0".repeat(8))
+    );
+    let mut x = dec("2");
+    x /= dec("0.5");
+    assert_eq!(x, dec("4"));
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide by zero")]
+fn test_div_zero() {
+    let _ = dec("1") / dec("0.0");
+}
+
+#[test]
+fn test_round() {
+    for (s, scale, rounded) in [
+        ("2.5", 0, "2"),
+        ("3.5", 0, "4"),
+        ("-2.5", 0, "-2"),
+        ("-3.5", 0, "-4"),
+        ("2.51", 0, "3"),
+        ("-2.49", 0, "-2"),
+        ("0.004", -2, "0.00"),
+        ("0.005", -2, "0.00"),
+        ("0.015", -2, "0.02"),
+        ("1234", 2, "1200"),
+        ("1250", 2, "1200"),
+        ("1350", 2, "1400"),
+        ("49", 2, "0"),
+        ("1.5", -3, "1.500"),
+        ("0.001", 100, "0"),
+    ] {
+        let x = dec(s).round(scale);
+        assert_eq!(x.scale(), scale, "{}", s);
+        assert_eq!(x.to_string(), rounded, "{} rounded to {}", s, scale);
+    }
+}
+
+#[test]
+fn test_cmp_hash() {
+    let values = [
+        "-10", "-1.5", "-0.001", "0", "0.00", "1e-30", "0.5", "1", "1.0", "2e3",
+    ];
+    for (i, a) in values.iter().enumerate() {
+        for (j, b) in values.iter().enumerate() {
+            let (x, y) = (dec(a), dec(b));
+            let expected = a.parse::<f64>().unwrap().partial_cmp(&b.parse().unwrap());
+            assert_eq!(x.partial_cmp(&y), expected, "{} vs {} ({}, {})", a, b, i, j);
+        }
+    }
+
+    let set: HashSet<BigDecimal> = ["1", "1.0", "1.00", "10e-1", "0", "-0.0"]
+        .iter()
+        .map(|s| dec(s))
+        .collect();
+    assert_eq!(set.len(), 2);
+
+    let x = dec("-12.3400e5").normalized();
+    assert_eq!((x.mantissa(), x.scale()), (&BigInt::from(-1234), 3));
+    assert_eq!(dec("0.000").normalized().scale(), 0);
+}
+
+#[test]
+fn test_f64() {
+    for f in [
+        0.0,
+        1.0,
+        -2.5,
+        0.1,
+        1e-300,
+        5e-324,
+        1.7976931348623157e308,
+        123456.789,
+    ] {
+        let x = BigDecimal::from_f64(f).unwrap();
+        assert_eq!(x.to_f64(), f);
+        assert_eq!(ToPrimitive::to_f64(&x), Some(f));
+    }
+    assert_eq!(BigDecimal::from_f64(0.5).unwrap(), dec("0.5"));
+    assert_eq!(BigDecimal::from_f64(-1024.0).unwrap(), dec("-1024"));
+    assert_eq!(BigDecimal::from_f64(f64::NAN), None);
+    assert_eq!(BigDecimal::from_f64(f64::INFINITY), None);
+    assert_eq!(
+        <BigDecimal as FromPrimitive>::from_f64(0.25),
+        Some(dec("0.25"))
+    );
+
+    assert_eq!(dec("0.3").to_f64(), 0.3);
+    assert_eq!(dec("2e308").to_f64(), f64::INFINITY);
+    assert_eq!(dec("-1e-400").to_f64(), -0.0);
+    // Just above halfway between 1 and the next f64, which needs every digit to round up.
+    let halfway = format!(
+        "1.00000000000000011102230246251565404236316680908203125{}1",
+        "0".repeat(50)
+    );
+    assert_eq!(dec(&halfway).to_f64(), 1.0 + f64::EPSILON);
+}
+
+#[test]
+fn test_to_integer() {
+    assert_eq!(dec("12.9").to_i64(), Some(12));
+    assert_eq!(dec("-12.9").to_i64(), Some(-12));
+    assert_eq!(dec("-12.9").to_u64(), None);
+    assert_eq!(dec("-0.9").to_u64(), Some(0));
+    assert_eq!(dec("1e-1000").to_u64(), Some(0));
+    assert_eq!(dec("1.5e20").to_u128(), Some(150_000_000_000_000_000_000));
+    assert_eq!(dec("1.5e20").to_u64(), None);
+    assert_eq!(dec("1e1000").to_u128(), None);
+    assert_eq!(
+        BigDecimal::from_u64(u64::MAX).unwrap().to_u64(),
+        Some(u64::MAX)
+    );
+    assert_eq!(BigDecimal::from(i128::MIN).to_i128(), Some(i128::MIN));
+}
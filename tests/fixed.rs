@@ -0,0 +1,191 @@
+use num_bigint::{BigUint, FixedBigUint, Overflow};
+use num_traits::{
+    CheckedAdd, CheckedDiv, CheckedMul, CheckedRem, CheckedSub, FromPrimitive, One, ToPrimitive,
+    Zero,
+};
+
+mod consts;
+
+/// Returns interesting values of `N` digits: small ones, powers of two, all ones, and some
+/// pseudorandom ones of every length.
+fn values<const N: usize>() -> Vec<FixedBigUint<N>> {
+    let mut values = vec![
+        FixedBigUint::ZERO,
+        FixedBigUint::one(),
+        FixedBigUint::from_u32(2).unwrap(),
+        FixedBigUint::from_u32(u32::MAX).unwrap(),
+        FixedBigUint::MAX,
+    ];
+    let mut random = consts::random_u32s();
+    for len in 1..=N {
+        for top in [1, u32::MAX] {
+            let mut digits = [0u32; N];
+            digits[len - 1] = top;
+            values.push(FixedBigUint::from_u32_digits(digits));
+
+            for (d, r) in digits[..len - 1].iter_mut().zip(&mut random) {
+                *d = r;
+            }
+            values.push(FixedBigUint::from_u32_digits(digits));
+        }
+    }
+    values
+}
+
+fn check_arithmetic<const N: usize>() {
+    let limit = BigUint::one() << (32 * N);
+    let fits = |x: &BigUint| *x < limit;
+    for a in values::<N>() {
+        let x = BigUint::from(a);
+        assert_eq!(FixedBigUint::<N>::try_from(&x), Ok(a));
+        assert_eq!(a.bits(), x.bits());
+        check_format(&a, &x);
+
+        for b in values::<N>() {
+            let y = BigUint::from(b);
+            assert_eq!(a.cmp(&b), x.cmp(&y));
+
+            let sum = &x + &y;
+            assert_eq!(
+                a.checked_add(&b).map(BigUint::from),
+                Some(sum.clone()).filter(fits)
+            );
+            let (wrapped, overflow) = a.overflowing_add(&b);
+            assert_eq!(BigUint::from(wrapped), &sum % &limit);
+            assert_eq!(overflow, !fits(&sum));
+
+            let difference = if x >= y { Some(&x - &y) } else { None };
+            assert_eq!(a.checked_sub(&b).map(BigUint::from), difference);
+
+            let product = &x * &y;
+            assert_eq!(
+                a.checked_mul(&b).map(BigUint::from),
+                Some(product).filter(fits)
+            );
+            assert_eq!(a.try_add(&b).ok(), a.checked_add(&b));
+            assert_eq!(a.try_sub(&b).ok(), a.checked_sub(&b));
+            assert_eq!(a.try_mul(&b).ok(), a.checked_mul(&b));
+
+            if b.is_zero() {
+                assert_eq!(a.checked_div(&b), None);
+                assert_eq!(a.checked_rem(&b), None);
+            } else {
+                let (q, r) = a.divmod(&b);
+                assert_eq!(BigUint::from(q), &x / &y);
+                assert_eq!(BigUint::from(r), &x % &y);
+                assert_eq!(a / b, q);
+                assert_eq!(a % b, r);
+            }
+        }
+    }
+}
+
+/// Checks that `a` formats like the `BigUint` `x`, with any flags.
+fn check_format<const N: usize>(a: &FixedBigUint<N>, x: &BigUint) {
+    macro_rules! check {
+        ($($fmt:literal),*) => {$(
+            assert_eq!(format!($fmt, a), format!($fmt, x), "{}", $fmt);
+        )*};
+    }
+    check!("{}", "{:?}", "{:b}", "{:o}", "{:x}", "{:X}", "{:#b}", "{:#o}", "{:#x}", "{:#X}");
+    check!("{:+}", "{:>60}", "{:<60}", "{:^61}", "{:*^7}", "{:060}", "{:+060}", "{:#018x}");
+}
+
+#[test]
+fn test_arithmetic() {
+    check_arithmetic::<1>();
+    check_arithmetic::<2>();
+    check_arithmetic::<3>();
+    check_arithmetic::<4>();
+    check_arithmetic::<8>();
+}
+
+#[test]
+fn test_operators() {
+    type U96 = FixedBigUint<3>;
+
+    let mut a = U96::from_u64(1 << 40).unwrap();
+    a *= U96::from_u32(3).unwrap();
+    a += &U96::one();
+    a -= U96::from_u32(2).unwrap();
+    assert_eq!(a.to_u64(), Some((3 << 40) - 1));
+    assert_eq!(
+        (a / U96::from_u32(1 << 20).unwrap()).to_u64(),
+        Some((3 << 20) - 1)
+    );
+    a %= U96::from_u32(1 << 20).unwrap();
+    assert_eq!(a.to_u32(), Some((1 << 20) - 1));
+    assert_eq!(U96::default(), U96::zero());
+}
+
+#[test]
+fn test_conversions() {
+    type U64 = FixedBigUint<2>;
+
+    assert_eq!(U64::from_u64(u64::MAX), Some(U64::MAX));
+    assert_eq!(U64::from_u128(1 << 64), None);
+    assert_eq!(U64::from_i64(-1), None);
+    assert_eq!(U64::MAX.to_u64(), Some(u64::MAX));
+    assert_eq!(U64::MAX.to_i64(), None);
+    assert_eq!(
+        U64::from_f64(1e19).and_then(|n| n.to_u64()),
+        Some(10_000_000_000_000_000_000)
+    );
+    assert_eq!(U64::from_f64(1e20), None);
+    assert_eq!(U64::BITS, 64);
+
+    let big = BigUint::from(u64::MAX) + 1u32;
+    assert!(U64::try_from(&big).is_err());
+    assert_eq!(
+        U64::try_from(big).unwrap_err().into_original(),
+        BigUint::from(1u128 << 64)
+    );
+    assert_eq!(
+        FixedBigUint::<3>::from_u128(1 << 64)
+            .unwrap()
+            .as_u32_digits(),
+        &[0, 0, 1]
+    );
+
+    let n = FixedBigUint::<4>::from_u128(0xdead_beef << 64).unwrap();
+    assert_eq!(format!("{}", n), (0xdead_beefu128 << 64).to_string());
+    assert_eq!(format!("{:x}", n), "deadbeef0000000000000000");
+    assert_eq!(format!("{:?}", n), format!("{}", n));
+}
+
+#[test]
+fn test_try_ops() {
+    type U64 = FixedBigUint<2>;
+
+    let two = U64::from_u32(2).unwrap();
+    assert_eq!(U64::MAX.try_add(&U64::one()), Err(Overflow));
+    assert_eq!(U64::ZERO.try_sub(&U64::one()), Err(Overflow));
+    assert_eq!(U64::MAX.try_mul(&two), Err(Overflow));
+    assert_eq!(two.try_mul(&two), U64::from_u32(4).ok_or(Overflow));
+    assert_eq!(Overflow.to_string(), "integer overflow");
+}
+
+#[test]
+#[should_panic(expected = "attempt to add with overflow")]
+fn test_add_overflow() {
+    let _ = FixedBigUint::<2>::MAX + FixedBigUint::one();
+}
+
+#[test]
+#[should_panic(expected = "attempt to subtract with overflow")]
+fn test_sub_overflow() {
+    let _ = FixedBigUint::<2>::ZERO - FixedBigUint::one();
+}
+
+#[test]
+#[should_panic(expected = "attempt to multiply with overflow")]
+fn test_mul_overflow() {
+    let x = FixedBigUint::<2>::from_u64(1 << 32).unwrap();
+    let _ = x * x;
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide by zero")]
+fn test_div_zero() {
+    let _ = FixedBigUint::<2>::one() / FixedBigUint::zero();
+}
@@ -52,8 +52,76 @@ static BIG_R: &str = "\
                       f88a7b04_900227c9_f6e6d06b_7856b4bb_4e354d61_060db6c8\
                       109c4735_6e7db425_7b5d74c7_0b709508";
 
+// A 2048-bit RSA key with public exponent 65537, with a message `RSA_M` and its ciphertext
+// `RSA_C`. `RSA_EVEN_R` is `RSA_C^RSA_D mod (RSA_N << 64)`, for an even modulus of the same size.
+// These were all checked against Python's `pow(base, exp, mod)`.
+static RSA_N: &str = "\
+                      f96d022b_fbdbee49_d22bd640_dc51abd3_45cafba9_157358ad\
+                      d45d04ab_64dd9a18_62c6c381_bf9bcc0d_6a07913c_70fcd411\
+                      3a86868a_61895e4a_62bd4975_030a2f41_a89202be_76fb4cf3\
+                      599f2ca9_1636281e_8e391735_db349323_70921e18_acf3fd5f\
+                      09a6b5fa_7306ba16_88fe5626_580b198b_7e410327_82406ca3\
+                      90dd43ca_d0392d59_2da2b414_25fa7c9b_bf3a53cb_f4cbc8f6\
+                      7add12f8_d910ef2f_739c034e_a2ea4f1f_818c5469_9d174adb\
+                      c4b8adf1_dda1a380_8760230e_a9c31765_5e40e281_2acbb2ee\
+                      6cd3a801_c6fc3900_7c68a188_acec59a8_df66027b_f8c4e4ab\
+                      bd5360f2_ff810b04_2619e728_f0ab6f4e_b52db65b_4853d7fa\
+                      589a9bf1_0461b2b7_ace75a02_ce39d77b";
+
+static RSA_D: &str = "\
+                      598328ed_deffb137_15f17c7d_1f7441ed_5ad53b56_9567a552\
+                      10ede6f0_998cbbb1_f7b4ec51_e291477c_22ea9f51_291d5aff\
+                      e9d8806d_260ed5e3_6d271037_29859caa_fc13965f_8334b18b\
+                      7dc4d592_c2f16199_81a1f475_f3fa9cb9_71bb4688_c57cf497\
+                      884fa76c_3f9e90e6_e8781a33_bd921233_50f684ea_c760567c\
+                      a6cef976_ce1e4eea_c649cd7d_ef121d92_f1a552f1_a77cb06f\
+                      21af9da9_5dba261b_9ed992a8_2f22f01b_c4395c63_6a378f33\
+                      a9e25dfe_f167a908_7f73a011_9ef6bc91_6a9db9e9_965e873d\
+                      e8413eb4_0985bb38_3eac964c_d868447c_d746927a_c843c854\
+                      c098a65f_8702fb2c_ff9887a2_75eaa62c_57f2a000_9fc58e35\
+                      eabeb752_537ccd10_7117bea1_e018f5ad";
+
+static RSA_M: &str = "\
+                      000cbae6_1ac61133_49f0b35f_dc403f7b_e2609011_6697b417\
+                      336975f4_b8251587_59eb379b_7030b900_fb351f41_5da602b6\
+                      84051a6a_e459a2de_9d3b3c0f_c7c72444_413bd432_9600c51f\
+                      f7e6b802_53df503c_1659b79f_cb059435_bea4cc42_f810d45f\
+                      938200f0_5170f993_996bab49_aa8fc24b_d95f0993_7a4e7040\
+                      125b286d_c465c40b_452d94ca_0fefae33_8741a2bd_f670ea9e\
+                      591725e8_fd084085_c4b44717_82db002c_ca22e2f1_2f8f019a\
+                      2cc1592f_be53162f_0f2fe03c_dfd14e0c_d8b2cc11_1f92cf9c\
+                      c4d9f601_23635ec4_20a5e144_3dbb0b46_f2e07771_874361f6\
+                      5f0bdea0_2ff93126_ded1b65f_5db5ed15_3306a03e_b8bf94be\
+                      6a55d3d6_1a89ed6d_f51b74b0_2f057f16";
+
+static RSA_C: &str = "\
+                      ecf0dae0_802632c3_441677e9_f6bcb4fd_fdc8b42d_db9e0608\
+                      b5061987_02fd049a_9610dada_ac395e65_9809854c_bb14e80e\
+                      40c06ca3_20f7b33f_25cf84e4_b046e7cb_41dc007b_08e2fcb7\
+                      b0404856_4e4adbf3_a29c9c24_1c63e859_9daf0465_78c4c162\
+                      ac555812_dbd64c9f_4aff4527_2d62fbcb_7214fafd_68fae44d\
+                      b82d79d9_a1990d5f_95c6d192_e2b6cde4_7bebe11d_c7499bd0\
+                      fb21b7d8_c490dde4_a5525157_8a937c48_fd046c88_dd7c551b\
+                      e1cd31af_a804bac5_2e6667e7_375006d8_0f5da9e3_16bebbba\
+                      7520b5a2_1825ca25_6c8ab551_a0cc7e5d_62365154_247d24fe\
+                      dd70dbfa_a8c9b6df_eee470e9_770ff411_952d3210_6dafd168\
+                      7a8aa8d1_bb53825b_c64609e8_488b83ff";
+
+static RSA_EVEN_R: &str = "\
+                      82fdec0a_cea36e53_13197842_4de0cbe8_2167c894_d2377aaf\
+                      62b4442a_9dd51b2b_5c4360f2_80620a63_88b3bbb5_c984962f\
+                      c4462bfc_46aae502_bd751319_ecd85ae1_62ed21ed_f2d6bf7d\
+                      ad793505_e1a51374_276b41d4_832128c0_a07b599e_47ffe1cb\
+                      e85eb3d3_2db3058b_0fae7b4a_59e1ac31_ed946650_4300aa01\
+                      d5e45c8e_e4535c43_d25ee9f1_b5389a11_e99b5314_a766fa09\
+                      6a8abfa0_502daf78_262fe15f_f2a33cef_f29300d6_8dbc4bc1\
+                      173f1bf5_f74c1325_c4e67cda_7baca64c_9641fc13_c5ddab6e\
+                      cc1a51c8_1d9ecc26_f4825e7a_c088f0cb_1057277d_23f15684\
+                      a0368144_19bd7b5a_1882cc8a_75dcc8c4_c9b421ac_6019320b\
+                      d04987e5_76fcc731_70494f35_85a7c8dd_026e1b09_19bc33ff";
+
 mod biguint {
-    use num_bigint::BigUint;
+    use num_bigint::{BigUint, MontgomeryForm};
     use num_integer::Integer;
     use num_traits::Num;
 
@@ -64,11 +132,17 @@ mod biguint {
         let r: BigUint = r.into();
 
         assert_eq!(b.modpow(&e, &m), r);
+        assert_eq!(b.modpow_ct(&e, &m), r);
+        if m.is_odd() {
+            let form = MontgomeryForm::new(m.clone());
+            assert_eq!(form.exit(&form.pow(&form.enter(&b), &e)), r);
+        }
 
         let even_m = &m << 1;
         let even_modpow = b.modpow(&e, &even_m);
         assert!(even_modpow < even_m);
         assert_eq!(even_modpow.mod_floor(&m), r);
+        assert_eq!(b.modpow_ct(&e, &even_m), even_modpow);
     }
 
     #[test]
@@ -87,9 +161,15 @@ mod biguint {
             for e in 0u64..11 {
                 for m in 1..11 {
                     check_modpow::<u64>(b, e, m, b.pow(e as u32) % m);
+                    let (b, m) = (BigUint::from(b), BigUint::from(m));
+                    assert_eq!(b.modpow_u64(e, &m), b.modpow(&e.into(), &m));
                 }
             }
         }
+
+        let m = BigUint::from(1_000_000_007u32);
+        let b = BigUint::from(123_456_789u32);
+        assert_eq!(b.modpow_u64(u64::MAX, &m), b.modpow(&u64::MAX.into(), &m));
     }
 
     #[test]
@@ -106,6 +186,74 @@ mod biguint {
         assert!(even_modpow < even_m);
         assert_eq!(even_modpow % m, r);
     }
+
+    #[test]
+    fn test_modpow_rsa() {
+        let n = BigUint::from_str_radix(super::RSA_N, 16).unwrap();
+        let d = BigUint::from_str_radix(super::RSA_D, 16).unwrap();
+        let m = BigUint::from_str_radix(super::RSA_M, 16).unwrap();
+        let c = BigUint::from_str_radix(super::RSA_C, 16).unwrap();
+        let even_r = BigUint::from_str_radix(super::RSA_EVEN_R, 16).unwrap();
+
+        assert_eq!(m.modpow(&BigUint::from(65537u32), &n), c);
+        assert_eq!(c.modpow(&d, &n), m);
+        assert_eq!(c.modpow_ct(&d, &n), m);
+
+        let form = MontgomeryForm::new(n.clone());
+        let mc = form.enter(&c);
+        assert_eq!(form.exit(&mc), c);
+        assert_eq!(form.exit(&form.pow(&mc, &d)), m);
+        assert_eq!(form.exit(&form.mul(&mc, &mc)), &c * &c % &n);
+
+        let even_n = &n << 64;
+        assert_eq!(c.modpow(&d, &even_n), even_r);
+        assert_eq!(c.modpow_ct(&d, &even_n), even_r);
+        assert_eq!(even_r % n, m);
+    }
+
+    #[test]
+    fn test_montgomery_form() {
+        let m = BigUint::from_str_radix(super::BIG_M, 16).unwrap();
+        let form = MontgomeryForm::new(m.clone());
+        assert_eq!(form.modulus(), &m);
+
+        let values = [
+            BigUint::from(0u32),
+            BigUint::from(1u32),
+            BigUint::from(2u32),
+            &m - 1u32,
+            m.clone(),
+            BigUint::from_str_radix(super::BIG_B, 16).unwrap(),
+            BigUint::from_str_radix(super::BIG_E, 16).unwrap(),
+        ];
+        for a in &values {
+            let ma = form.enter(a);
+            assert_eq!(form.exit(&ma), a % &m);
+            for b in &values {
+                let product = form.mul(&ma, &form.enter(b));
+                assert_eq!(form.exit(&product), a * b % &m);
+            }
+        }
+
+        // A single-digit modulus, and the trivial modulus
+        let form = MontgomeryForm::new(BigUint::from(11u32));
+        let three = form.enter(&BigUint::from(3u32));
+        assert_eq!(
+            form.exit(&form.pow(&three, &BigUint::from(7u32))),
+            BigUint::from(9u32)
+        );
+        let form = MontgomeryForm::new(BigUint::from(1u32));
+        assert_eq!(
+            form.exit(&form.enter(&BigUint::from(5u32))),
+            BigUint::from(0u32)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Montgomery form requires an odd modulus")]
+    fn test_montgomery_form_even() {
+        let _ = MontgomeryForm::new(BigUint::from(10u32));
+    }
 }
 
 mod bigint {
@@ -178,4 +326,13 @@ mod bigint {
 
         check_modpow(b, e, m, r);
     }
+
+    #[test]
+    fn test_modpow_rsa() {
+        let n = BigInt::from_str_radix(super::RSA_N, 16).unwrap();
+        let m = BigInt::from_str_radix(super::RSA_M, 16).unwrap();
+        let c = BigInt::from_str_radix(super::RSA_C, 16).unwrap();
+
+        check_modpow(m, BigInt::from(65537), n, c);
+    }
 }
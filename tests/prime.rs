@@ -0,0 +1,169 @@
+use num_bigint::BigUint;
+use num_traits::One;
+
+use std::str::FromStr;
+
+fn mersenne(p: u32) -> BigUint {
+    (BigUint::one() << p) - 1u32
+}
+
+#[test]
+fn test_is_prime_small() {
+    let primes: Vec<u32> = (0..1000u32)
+        .filter(|&n| n >= 2 && (2..n).take_while(|d| d * d <= n).all(|d| n % d != 0))
+        .collect();
+    for n in 0..1000u32 {
+        let big = BigUint::from(n);
+        assert_eq!(big.is_prime(), primes.contains(&n), "{}", n);
+        assert_eq!(big.is_prime_miller_rabin(0), primes.contains(&n), "{}", n);
+    }
+}
+
+#[test]
+#[cfg(feature = "number-theory")]
+fn test_prime_sieve() {
+    fn reference(limit: usize) -> Vec<BigUint> {
+        let mut sieve = vec![true; limit + 1];
+        (2..=limit)
+            .filter(|&n| {
+                if sieve[n] {
+                    for m in (n * n..=limit).step_by(n) {
+                        sieve[m] = false;
+                    }
+                }
+                sieve[n]
+            })
+            .map(BigUint::from)
+            .collect()
+    }
+
+    // Small limits, and those where the last segment has one number or is full
+    let limits = (0..200).chain([1023, 1024, 1055, 1056, 65535, 65536, 200_000]);
+    for limit in limits {
+        let primes = BigUint::prime_sieve(&BigUint::from(limit));
+        assert_eq!(primes, reference(limit), "{}", limit);
+    }
+
+    for (limit, count) in [
+        (10u32, 4),
+        (100, 25),
+        (1000, 168),
+        (10_000, 1229),
+        (100_000, 9592),
+        (1_000_000, 78498),
+        (4_000_000, 283_146),
+    ] {
+        let primes = BigUint::prime_sieve(&BigUint::from(limit));
+        assert_eq!(primes.len(), count, "{}", limit);
+    }
+}
+
+#[test]
+#[cfg(feature = "number-theory")]
+#[should_panic(expected = "the sieve limit is too large")]
+fn test_prime_sieve_too_large() {
+    BigUint::prime_sieve(&(BigUint::one() << 64u32));
+}
+
+#[test]
+fn test_is_prime_mersenne() {
+    // Exponents of the Mersenne primes below 2^1000
+    let exponents = [2, 3, 5, 7, 13, 17, 19, 31, 61, 89, 107, 127, 521, 607];
+    for p in 2..700 {
+        let expected = exponents.contains(&p);
+        assert_eq!(
+            mersenne(p).is_prime_miller_rabin(8),
+            expected,
+            "2^{} - 1",
+            p
+        );
+    }
+}
+
+#[test]
+fn test_is_prime_composites() {
+    // Carmichael numbers and strong pseudoprimes to small bases
+    for &n in &[561u64, 1105, 1729, 2465, 2821, 6601, 8911, 3_215_031_751] {
+        assert!(!BigUint::from(n).is_prime(), "{}", n);
+    }
+
+    // Arnault's 397-digit strong pseudoprime to all prime bases below 307
+    let arnault = BigUint::from_str(
+        "2887148238050771212671429597130393991977609459279722700926516024197432303799152733116328983144639225941977803110929349655578418949441740933805615113979999421542416933972905423711002751042080134966731755152859226962916775325475044445856101949404200039904432116776619949629539250452698719329070373564032273701278453899126120309244841494728976885406024976768122077071687938121709811322297802059565867",
+    )
+    .unwrap();
+    assert!(!arnault.is_prime());
+
+    // The product of two large primes
+    assert!(!(mersenne(89) * mersenne(107)).is_prime());
+}
+
+#[test]
+fn test_is_prime_large() {
+    // The prime from the 2048-bit MODP DH group: 2^2048 - 2^1984 - 1 + 2^64 * (⌊2^1918 π⌋ + 124476)
+    let p = BigUint::parse_bytes(
+        b"FFFFFFFFFFFFFFFFC90FDAA22168C234C4C6628B80DC1CD1\
+          29024E088A67CC74020BBEA63B139B22514A08798E3404DD\
+          EF9519B3CD3A431B302B0A6DF25F14374FE1356D6D51C245\
+          E485B576625E7EC6F44C42E9A637ED6B0BFF5CB6F406B7ED\
+          EE386BFB5A899FA5AE9F24117C4B1FE649286651ECE45B3D\
+          C2007CB8A163BF0598DA48361C55D39A69163FA8FD24CF5F\
+          83655D23DCA3AD961C62F356208552BB9ED529077096966D\
+          670C354E4ABC9804F1746C08CA18217C32905E462E36CE3B\
+          E39E772C180E86039B2783A2EC07A28FB5C55DF06F4C52C9\
+          DE2BCBF6955817183995497CEA956AE515D2261898FA0510\
+          15728E5A8AACAA68FFFFFFFFFFFFFFFF",
+        16,
+    )
+    .unwrap();
+    assert!(p.is_prime_miller_rabin(4));
+    assert!(!(&p + 2u32).is_prime_miller_rabin(4));
+}
+
+/// Checks that `sqrt_mod_prime` recovers the y-coordinate of a point on the short Weierstrass
+/// curve `y^2 = x^3 - 3x + b` over the prime field `p`, as in point decompression. Returns
+/// `y^2`.
+fn check_decompress(p: &BigUint, b: &str, gx: &str, gy: &str) -> BigUint {
+    let b = BigUint::parse_bytes(b.as_bytes(), 16).unwrap();
+    let gx = BigUint::parse_bytes(gx.as_bytes(), 16).unwrap();
+    let gy = BigUint::parse_bytes(gy.as_bytes(), 16).unwrap();
+
+    let rhs = (gx.modpow(&BigUint::from(3u32), p) + b + p * 3u32 - gx * 3u32) % p;
+    let y = rhs.sqrt_mod_prime(p).unwrap();
+    assert!(y == gy || p - &y == gy);
+    assert_eq!((&y * &y) % p, rhs);
+    rhs
+}
+
+#[test]
+fn test_sqrt_mod_prime_p256() {
+    // p = 2^256 - 2^224 + 2^192 + 2^96 - 1, which is 3 (mod 4)
+    let p = (BigUint::one() << 256u32) - (BigUint::one() << 224u32)
+        + (BigUint::one() << 192u32)
+        + (BigUint::one() << 96u32)
+        - 1u32;
+    let rhs = check_decompress(
+        &p,
+        "5ac635d8aa3a93e7b3ebbd55769886bc651d06b0cc53b0f63bce3c3e27d2604b",
+        "6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296",
+        "4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5",
+    );
+
+    // -1 is not a square modulo p, so -y^2 has no root.
+    assert_eq!((&p - rhs).sqrt_mod_prime(&p), None);
+}
+
+#[test]
+fn test_sqrt_mod_prime_p224() {
+    // p = 2^224 - 2^96 + 1, so 2^96 divides p - 1 and Tonelli-Shanks takes many steps
+    let p = (BigUint::one() << 224u32) - (BigUint::one() << 96u32) + 1u32;
+    check_decompress(
+        &p,
+        "b4050a850c04b3abf54132565044b0b7d7bfd8ba270b39432355ffb4",
+        "b70e0cbd6bb4bf7f321390b94a03c1d356c21122343280d6115c1d21",
+        "bd376388b5f723fb4c22dfe6cd4375a05a07476444d5819985007e34",
+    );
+
+    // 11 is not a square modulo p.
+    assert_eq!(BigUint::from(11u32).sqrt_mod_prime(&p), None);
+}
@@ -1,6 +1,6 @@
 mod biguint {
     use num_bigint::BigUint;
-    use num_traits::{One, Zero};
+    use num_traits::One;
 
     fn check<T: Into<BigUint>>(x: T, n: u32) {
         let x: BigUint = x.into();
@@ -109,6 +109,78 @@ mod biguint {
         check(x.clone(), 10);
         check(x, 100);
     }
+
+    #[test]
+    fn test_nth_root_exact() {
+        for n in 1..20 {
+            for base in 0u32..50 {
+                let x = BigUint::from(base).pow(n);
+                assert_eq!(x.nth_root_exact(n), Some(BigUint::from(base)));
+                if base > 1 && n > 1 {
+                    assert_eq!((&x + 1u32).nth_root_exact(n), None);
+                    assert_eq!((&x - 1u32).nth_root_exact(n), None);
+                }
+            }
+        }
+
+        let googol = BigUint::from(10u32).pow(100u32);
+        assert_eq!(googol.nth_root_exact(25), Some(BigUint::from(10_000u32)));
+        assert_eq!(googol.nth_root_exact(3), None);
+    }
+
+    #[test]
+    fn test_is_perfect_square() {
+        // Squares up to 10^30, and their neighbors
+        let mut k = 10u64;
+        while k <= 1_000_000_000_000_000 {
+            for root in [k - 1, k, k + 1, 3 * k + 7] {
+                let x = BigUint::from(root).pow(2u32);
+                assert!(x.is_perfect_square(), "{}", x);
+                assert!(!(&x - 1u32).is_perfect_square(), "{} - 1", x);
+                assert!(!(&x + 1u32).is_perfect_square(), "{} + 1", x);
+                assert!(!(&x << 1u32).is_perfect_square(), "2 * {}", x);
+            }
+            k *= 10;
+        }
+
+        // Against the square root for everything in a range that includes squares
+        let start = BigUint::from(10u32).pow(30u32) - 100_000u32;
+        for i in 0..200_000u32 {
+            let n = &start + i;
+            assert_eq!(n.is_perfect_square(), n.sqrt().pow(2u32) == n, "{}", n);
+        }
+        assert!(BigUint::from(10u32).pow(30u32).is_perfect_square());
+    }
+
+    #[test]
+    fn test_is_perfect_power() {
+        for x in 0u32..5000 {
+            // The largest exponent gives the smallest base.
+            let expected = (2..x).find_map(|a| {
+                let mut k = 1;
+                let mut power = a;
+                while power < x {
+                    power *= a;
+                    k += 1;
+                }
+                (power == x && k > 1).then(|| (BigUint::from(a), k))
+            });
+            assert_eq!(BigUint::from(x).is_perfect_power(), expected, "{}", x);
+        }
+
+        let m127 = (BigUint::one() << 127u32) - 1u32;
+        assert_eq!(m127.is_perfect_power(), None);
+        assert_eq!(m127.pow(6).is_perfect_power(), Some((m127.clone(), 6)));
+        let x = (BigUint::one() << 96u32) * m127.pow(12);
+        assert_eq!(
+            x.is_perfect_power(),
+            Some(((BigUint::one() << 8u32) * m127, 12))
+        );
+        assert_eq!(
+            (BigUint::one() << 1000u32).is_perfect_power(),
+            Some((BigUint::from(2u32), 1000))
+        );
+    }
 }
 
 mod bigint {
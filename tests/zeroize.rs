@@ -0,0 +1,48 @@
+#![cfg(feature = "zeroize")]
+
+use num_bigint::{BigInt, BigUint, Sign, ZeroizingBigInt, ZeroizingBigUint};
+use num_traits::Zero;
+use zeroize::Zeroize;
+
+/// Checks that zeroizing leaves zero in the same allocation, without freeing it.
+fn check_zeroized(mut x: BigUint) {
+    let capacity = x.capacity();
+    assert!(capacity > 0);
+
+    x.zeroize();
+    assert!(x.is_zero());
+    assert_eq!(x.capacity(), capacity);
+}
+
+#[test]
+fn test_biguint_zeroize() {
+    check_zeroized(BigUint::from(0xdead_beef_u32));
+    check_zeroized(BigUint::from_bytes_le(&[0xa5; 100]));
+
+    let mut zero = BigUint::zero();
+    zero.zeroize();
+    assert!(zero.is_zero());
+}
+
+#[test]
+fn test_bigint_zeroize() {
+    let mut x = BigInt::from_bytes_le(Sign::Minus, &[0xa5; 100]);
+    x.zeroize();
+    assert!(x.is_zero());
+    assert_eq!(x.sign(), Sign::NoSign);
+    check_zeroized(
+        BigInt::from_bytes_le(Sign::Minus, &[0x5a; 100])
+            .into_parts()
+            .1,
+    );
+}
+
+#[test]
+fn test_zeroizing() {
+    let secret = ZeroizingBigUint::new(BigUint::from(7u32));
+    assert_eq!(&*secret * 6u32, BigUint::from(42u32));
+
+    let mut secret = ZeroizingBigInt::new(BigInt::from(-7));
+    *secret *= 6;
+    assert_eq!(*secret, BigInt::from(-42));
+}